@@ -4,7 +4,6 @@ use criterion::criterion_main;
 use criterion::Criterion;
 use criterion::Fun;
 
-use unic_langid_impl::subtags;
 use unic_langid_impl::LanguageIdentifier;
 
 static STRINGS: &[&str] = &[
@@ -54,29 +53,36 @@ fn language_identifier_construct_bench(c: &mut Criterion) {
             })
         }),
         Fun::new("from_parts", |b, langids: &Vec<LanguageIdentifier>| {
-            let entries: Vec<(
-                subtags::Language,
-                Option<subtags::Script>,
-                Option<subtags::Region>,
-                Vec<subtags::Variant>,
-            )> = langids
+            let entries: Vec<_> = langids
                 .iter()
                 .cloned()
                 .map(|langid| langid.into_parts())
                 .collect();
             b.iter(|| {
                 for (language, script, region, variants) in &entries {
-                    let _ = LanguageIdentifier::from_parts(
-                        language.clone(),
-                        script.clone(),
-                        region.clone(),
-                        variants,
-                    );
+                    let _ = LanguageIdentifier::from_parts(*language, *script, *region, variants);
+                }
+            })
+        }),
+        Fun::new("matches", |b, langids: &Vec<LanguageIdentifier>| {
+            b.iter(|| {
+                for langid in langids {
+                    for other in langids {
+                        let _ = black_box(langid).matches(other, false, false);
+                    }
+                }
+            })
+        }),
+        Fun::new("to_string", |b, langids: &Vec<LanguageIdentifier>| {
+            b.iter(|| {
+                for langid in langids {
+                    let _ = black_box(langid).to_string();
                 }
             })
         }),
     ];
 
+    #[allow(deprecated)]
     c.bench_functions("language_identifier_construct", funcs, langids);
 }
 
@@ -0,0 +1,137 @@
+//! Optionally embeds only a subset of the likely subtags data.
+//!
+//! When the `likelysubtags` feature is enabled and the
+//! `UNIC_LANGID_LIKELYSUBTAGS_LANGUAGES` environment variable is set to a
+//! comma separated list of language subtags (e.g. `en,fr,de`), only the entries
+//! resolving to those languages are compiled into the crate.
+use std::env;
+use std::fs;
+use std::path::Path;
+
+const LANGUAGES_VAR: &str = "UNIC_LANGID_LIKELYSUBTAGS_LANGUAGES";
+const TABLES_PATH: &str = "src/likelysubtags/tables.rs";
+
+// Tables keyed by language, filtered on the key.
+const LANGUAGE_KEYED: &[&str] = &["LANG_ONLY", "LANG_REGION", "LANG_SCRIPT"];
+
+/// Encodes a language subtag the same way `TinyStr8` does.
+fn encode_language(lang: &str) -> u64 {
+    let mut bytes = [0u8; 8];
+    bytes[..lang.len()].copy_from_slice(lang.as_bytes());
+    u64::from_le_bytes(bytes)
+}
+
+/// Splits the body of a table into its entries, ignoring all whitespace.
+fn split_entries(body: &str) -> Vec<String> {
+    let body: String = body.chars().filter(|c| !c.is_whitespace()).collect();
+    let mut entries = vec![];
+    let mut depth = 0;
+    let mut current = String::new();
+    for c in body.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' if depth == 0 => {
+                entries.push(std::mem::take(&mut current));
+                continue;
+            }
+            _ => {}
+        }
+        current.push(c);
+    }
+    if !current.is_empty() {
+        entries.push(current);
+    }
+    entries
+}
+
+/// Returns the language the entry is keyed by, or resolves to.
+fn entry_language(entry: &str, language_keyed: bool) -> Option<u64> {
+    let inner = &entry[1..entry.len() - 1];
+    if language_keyed {
+        inner.split(',').next()?.parse().ok()
+    } else {
+        let value = &inner[inner.find('(')? + 1..];
+        let value = value.strip_prefix("Some(")?;
+        value.split(')').next()?.parse().ok()
+    }
+}
+
+fn main() {
+    println!("cargo:rerun-if-env-changed={}", LANGUAGES_VAR);
+    println!("cargo:rerun-if-changed={}", TABLES_PATH);
+    println!("cargo:rustc-check-cfg=cfg(likelysubtags_subset)");
+
+    if env::var_os("CARGO_FEATURE_LIKELYSUBTAGS").is_none() {
+        return;
+    }
+    let languages = match env::var(LANGUAGES_VAR) {
+        Ok(languages) => languages,
+        Err(_) => return,
+    };
+
+    let mut keep: Vec<u64> = languages
+        .split(',')
+        .map(str::trim)
+        .filter(|l| !l.is_empty())
+        .map(|l| {
+            let l = l.to_ascii_lowercase();
+            assert!(
+                (2..=8).contains(&l.len()) && l.bytes().all(|b| b.is_ascii_alphabetic()),
+                "Invalid language subtag in {}: {}",
+                LANGUAGES_VAR,
+                l
+            );
+            encode_language(&l)
+        })
+        .collect();
+    keep.push(encode_language("und"));
+
+    let source =
+        fs::read_to_string(TABLES_PATH).expect("Failed to read the likely subtags tables.");
+    let mut output = String::new();
+    let mut rest = source.as_str();
+
+    while let Some(start) = rest.find("pub static ") {
+        let header_end = start + rest[start..].find('\n').expect("Malformed table header.");
+        let header = &rest[start..header_end];
+        let name = header["pub static ".len()..]
+            .split(':')
+            .next()
+            .expect("Malformed table header.");
+
+        if !header.ends_with("= [") {
+            output.push_str(header);
+            output.push('\n');
+            rest = &rest[header_end + 1..];
+            continue;
+        }
+
+        let body_end = header_end + rest[header_end..].find("\n];").expect("Malformed table.");
+        let language_keyed = LANGUAGE_KEYED.contains(&name);
+        let entries: Vec<String> = split_entries(&rest[header_end..body_end])
+            .into_iter()
+            .filter(|entry| {
+                entry_language(entry, language_keyed).is_some_and(|l| keep.contains(&l))
+            })
+            .collect();
+
+        let type_start = header.find('[').expect("Malformed table header.");
+        let type_end = header.rfind(';').expect("Malformed table header.");
+        output.push_str(&format!(
+            "{}{}; {}] = [\n",
+            &header[..type_start],
+            &header[type_start..type_end],
+            entries.len()
+        ));
+        for entry in entries {
+            output.push_str(&format!("    {},\n", entry));
+        }
+        output.push_str("];\n");
+        rest = &rest[body_end + 3..];
+    }
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("likelysubtags_tables.rs");
+    fs::write(out_path, output).expect("Failed to write the likely subtags tables.");
+    println!("cargo:rustc-cfg=likelysubtags_subset");
+}
@@ -0,0 +1,66 @@
+//! Replacements of deprecated subtags and legacy tags, from the CLDR `aliases.json`
+//! supplemental metadata.
+//!
+//! The aliases replacing a single language, script or region subtag are included,
+//! along with the legacy tags of BCP47 and other aliases of several subtags which
+//! have a modern replacement. Variant aliases are not included.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_langid_impl::{aliases, LanguageIdentifier};
+//!
+//! assert_eq!(aliases::legacy_tag("i-klingon"), Some("tlh"));
+//!
+//! let mut li: LanguageIdentifier = "iw-BU".parse().unwrap();
+//! assert!(li.canonicalize_aliases());
+//! assert_eq!(li.to_string(), "he-MM");
+//! ```
+pub use crate::aliases_table::CLDR_VERSION;
+use crate::aliases_table::{LANGUAGE_ALIASES, LEGACY_TAGS, REGION_ALIASES, SCRIPT_ALIASES};
+use crate::provider::Subtags;
+use crate::subtags::{Language, Region, Script};
+
+fn lookup(table: &'static [(&str, &str)], key: &str) -> Option<&'static str> {
+    table
+        .binary_search_by_key(&key, |(k, _)| k)
+        .ok()
+        .map(|idx| table[idx].1)
+}
+
+/// Returns the replacement of a deprecated language subtag, such as `he` for `iw`,
+/// which may add a script or region, as `sr-Latn` for `sh`.
+pub fn language_alias(language: Language) -> Option<Subtags> {
+    let replacement = lookup(LANGUAGE_ALIASES, language.as_str())?;
+    let li: crate::LanguageIdentifier = replacement.parse().expect("Aliases are well-formed.");
+    Some((li.language, li.script, li.region))
+}
+
+/// Returns the replacement of a deprecated script subtag, such as `Zinh` for `Qaai`.
+pub fn script_alias(script: Script) -> Option<Script> {
+    lookup(SCRIPT_ALIASES, script.as_str()).map(|s| s.parse().expect("Aliases are well-formed."))
+}
+
+/// Returns the successors of a deprecated region subtag, such as `MM` for `BU`.
+///
+/// A region which was split into several, such as `SU`, has all of its successors,
+/// the default one first.
+pub fn region_alias(region: Region) -> Option<Vec<Region>> {
+    let successors = lookup(REGION_ALIASES, region.as_str())?;
+    Some(
+        successors
+            .split(' ')
+            .map(|r| r.parse().expect("Aliases are well-formed."))
+            .collect(),
+    )
+}
+
+/// Returns the replacement of a legacy tag, such as `tlh` for `i-klingon`,
+/// ignoring case and separators.
+///
+/// Most legacy tags, such as `i-klingon` or `zh-min-nan`, are not well-formed
+/// language identifiers, and are replaced before parsing.
+pub fn legacy_tag(tag: &str) -> Option<&'static str> {
+    let tag = tag.to_ascii_lowercase().replace('_', "-");
+    lookup(LEGACY_TAGS, &tag)
+}
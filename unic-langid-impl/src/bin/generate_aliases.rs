@@ -0,0 +1,106 @@
+use serde_json::Value;
+use std::fs;
+use unic_langid_impl::{subtags, LanguageIdentifier};
+
+// The widest line of a table, indentation included.
+const WIDTH: usize = 100;
+
+fn replacement(value: &Value) -> &str {
+    value["_replacement"].as_str().unwrap()
+}
+
+fn print_table(comment: &str, name: &str, mut pairs: Vec<(String, String)>) {
+    pairs.sort();
+    pairs.dedup_by(|a, b| a.0 == b.0);
+
+    println!();
+    println!("// {}", comment);
+    println!("#[rustfmt::skip]");
+    println!("pub static {}: &[(&str, &str)] = &[", name);
+    let mut line = String::from("   ");
+    for (key, value) in pairs {
+        let entry = format!(" (\"{}\", \"{}\"),", key, value);
+        if line.len() + entry.len() > WIDTH {
+            println!("{}", line);
+            line = String::from("   ");
+        }
+        line.push_str(&entry);
+    }
+    println!("{}", line);
+    println!("];");
+}
+
+fn main() {
+    let contents =
+        fs::read_to_string("./data/aliases.json").expect("Something went wrong reading the file");
+    let v: Value = serde_json::from_str(&contents).unwrap();
+    let alias = &v["supplemental"]["metadata"]["alias"];
+
+    let mut languages = vec![];
+    let mut legacy_tags = vec![];
+    for (key, value) in alias["languageAlias"].as_object().unwrap() {
+        let value = replacement(value);
+        // Tags without a modern replacement, such as `i-default`, are replaced with
+        // private use tags.
+        if value.contains("-x-") {
+            continue;
+        }
+        value
+            .parse::<LanguageIdentifier>()
+            .expect("Failed to parse a replacement.");
+        if subtags::Language::from_bytes(key.as_bytes()).is_ok() {
+            languages.push((key.clone(), value.to_string()));
+        } else if !key.starts_with("und-") {
+            // Variant aliases, such as `und-hepburn-heploc`, are not supported.
+            legacy_tags.push((key.to_ascii_lowercase(), value.to_string()));
+        }
+    }
+
+    let mut scripts = vec![];
+    for (key, value) in alias["scriptAlias"].as_object().unwrap() {
+        let value = replacement(value);
+        subtags::Script::from_bytes(value.as_bytes()).unwrap();
+        if subtags::Script::from_bytes(key.as_bytes()).is_ok() {
+            scripts.push((key.clone(), value.to_string()));
+        }
+    }
+
+    let mut regions = vec![];
+    for (key, value) in alias["territoryAlias"].as_object().unwrap() {
+        let value = replacement(value);
+        for successor in value.split(' ') {
+            subtags::Region::from_bytes(successor.as_bytes()).unwrap();
+        }
+        // Three-letter codes, such as `SUN`, are not region subtags.
+        if subtags::Region::from_bytes(key.as_bytes()).is_ok() {
+            regions.push((key.clone(), value.to_string()));
+        }
+    }
+
+    println!(
+        "pub static CLDR_VERSION: &str = \"{}\";",
+        v["supplemental"]["version"]["_cldrVersion"]
+            .as_str()
+            .unwrap()
+    );
+    print_table(
+        "Deprecated, overlong and macrolanguage-encompassed languages, sorted by the former.",
+        "LANGUAGE_ALIASES",
+        languages,
+    );
+    print_table(
+        "Deprecated scripts, sorted by the former.",
+        "SCRIPT_ALIASES",
+        scripts,
+    );
+    print_table(
+        "Deprecated regions and their space-separated successors, sorted by the former.",
+        "REGION_ALIASES",
+        regions,
+    );
+    print_table(
+        "Legacy tags and other aliases of several subtags, lowercase and sorted by the former.",
+        "LEGACY_TAGS",
+        legacy_tags,
+    );
+}
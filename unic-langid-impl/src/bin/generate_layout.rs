@@ -15,7 +15,7 @@ fn langid_to_direction_map(path: &str) -> HashMap<LanguageIdentifier, CharacterD
         let contents = fs::read_to_string(path).expect("Something went wrong reading the file");
         let v: Value = serde_json::from_str(&contents).unwrap();
 
-        let langid_key = v["main"].as_object().unwrap().keys().nth(0).unwrap();
+        let langid_key = v["main"].as_object().unwrap().keys().next().unwrap();
 
         if langid_key == "root" {
             continue;
@@ -59,11 +59,36 @@ fn check_all_variants_rtl(
     true
 }
 
+/// Scripts marked as right-to-left in CLDR `scriptMetadata`.
+///
+/// Most of them have no locale in `cldr-misc-full`, so they can't be
+/// derived from the layout data, but may still appear as an explicit
+/// script subtag (e.g. `yi-Hebr`, `ms-Arab`).
+const RTL_SCRIPTS: &[&str] = &[
+    "Adlm", "Arab", "Aran", "Armi", "Avst", "Chrs", "Cprt", "Elym", "Hatr", "Hebr", "Hung", "Khar",
+    "Lydi", "Mand", "Mani", "Mend", "Merc", "Mero", "Narb", "Nbat", "Nkoo", "Orkh", "Ougr", "Palm",
+    "Phli", "Phlp", "Phnx", "Prti", "Rohg", "Samr", "Sarb", "Sogd", "Sogo", "Syrc", "Syre", "Syrj",
+    "Syrn", "Thaa", "Yezi",
+];
+
+fn cldr_version(path: &str) -> String {
+    let contents = fs::read_to_string(format!("{}root/layout.json", path))
+        .expect("Something went wrong reading the file");
+    let v: Value = serde_json::from_str(&contents).unwrap();
+    v["main"]["root"]["identity"]["version"]["_cldrVersion"]
+        .as_str()
+        .unwrap()
+        .to_string()
+}
+
 fn main() {
     let path = "./data/cldr-misc-full/main/";
     let map = langid_to_direction_map(path);
 
-    let mut scripts = HashSet::new();
+    let mut scripts: HashSet<Script> = RTL_SCRIPTS
+        .iter()
+        .map(|s| s.parse().expect("Failed to parse a script."))
+        .collect();
     let mut langs = HashSet::new();
 
     for (langid, dir) in map.iter() {
@@ -116,6 +141,11 @@ fn main() {
         .collect();
     langs.sort();
 
+    println!(
+        "pub static CLDR_VERSION: &str = \"{}\";",
+        cldr_version(path)
+    );
+
     println!(
         "pub const SCRIPTS_CHARACTER_DIRECTION_RTL: [u32; {}] = [{}];",
         scripts.len(),
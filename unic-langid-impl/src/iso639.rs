@@ -0,0 +1,131 @@
+//! Mapping between the two-letter ISO 639-1 and the three-letter ISO 639-3
+//! language codes.
+//!
+//! BCP47 requires the shortest code of a language, so a three-letter code with
+//! a two-letter equivalent, such as `eng`, is not canonical. Neither are the
+//! ISO 639-2/B bibliographic codes, such as `ger`, used by library data.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_langid_impl::iso639;
+//!
+//! let eng = "eng".parse().unwrap();
+//! assert_eq!(iso639::to_iso639_1(eng), Some("en".parse().unwrap()));
+//!
+//! let en = "en".parse().unwrap();
+//! assert_eq!(iso639::to_iso639_3(en), Some(eng));
+//! ```
+use crate::subtags::Language;
+
+// ISO 639-1 codes and their ISO 639-3 equivalents, sorted by the former.
+#[rustfmt::skip]
+static ISO639_1_TO_3: &[(&str, &str)] = &[
+    ("aa", "aar"), ("ab", "abk"), ("ae", "ave"), ("af", "afr"), ("ak", "aka"), ("am", "amh"),
+    ("an", "arg"), ("ar", "ara"), ("as", "asm"), ("av", "ava"), ("ay", "aym"), ("az", "aze"),
+    ("ba", "bak"), ("be", "bel"), ("bg", "bul"), ("bi", "bis"), ("bm", "bam"), ("bn", "ben"),
+    ("bo", "bod"), ("br", "bre"), ("bs", "bos"), ("ca", "cat"), ("ce", "che"), ("ch", "cha"),
+    ("co", "cos"), ("cr", "cre"), ("cs", "ces"), ("cu", "chu"), ("cv", "chv"), ("cy", "cym"),
+    ("da", "dan"), ("de", "deu"), ("dv", "div"), ("dz", "dzo"), ("ee", "ewe"), ("el", "ell"),
+    ("en", "eng"), ("eo", "epo"), ("es", "spa"), ("et", "est"), ("eu", "eus"), ("fa", "fas"),
+    ("ff", "ful"), ("fi", "fin"), ("fj", "fij"), ("fo", "fao"), ("fr", "fra"), ("fy", "fry"),
+    ("ga", "gle"), ("gd", "gla"), ("gl", "glg"), ("gn", "grn"), ("gu", "guj"), ("gv", "glv"),
+    ("ha", "hau"), ("he", "heb"), ("hi", "hin"), ("ho", "hmo"), ("hr", "hrv"), ("ht", "hat"),
+    ("hu", "hun"), ("hy", "hye"), ("hz", "her"), ("ia", "ina"), ("id", "ind"), ("ie", "ile"),
+    ("ig", "ibo"), ("ii", "iii"), ("ik", "ipk"), ("io", "ido"), ("is", "isl"), ("it", "ita"),
+    ("iu", "iku"), ("ja", "jpn"), ("jv", "jav"), ("ka", "kat"), ("kg", "kon"), ("ki", "kik"),
+    ("kj", "kua"), ("kk", "kaz"), ("kl", "kal"), ("km", "khm"), ("kn", "kan"), ("ko", "kor"),
+    ("kr", "kau"), ("ks", "kas"), ("ku", "kur"), ("kv", "kom"), ("kw", "cor"), ("ky", "kir"),
+    ("la", "lat"), ("lb", "ltz"), ("lg", "lug"), ("li", "lim"), ("ln", "lin"), ("lo", "lao"),
+    ("lt", "lit"), ("lu", "lub"), ("lv", "lav"), ("mg", "mlg"), ("mh", "mah"), ("mi", "mri"),
+    ("mk", "mkd"), ("ml", "mal"), ("mn", "mon"), ("mr", "mar"), ("ms", "msa"), ("mt", "mlt"),
+    ("my", "mya"), ("na", "nau"), ("nb", "nob"), ("nd", "nde"), ("ne", "nep"), ("ng", "ndo"),
+    ("nl", "nld"), ("nn", "nno"), ("no", "nor"), ("nr", "nbl"), ("nv", "nav"), ("ny", "nya"),
+    ("oc", "oci"), ("oj", "oji"), ("om", "orm"), ("or", "ori"), ("os", "oss"), ("pa", "pan"),
+    ("pi", "pli"), ("pl", "pol"), ("ps", "pus"), ("pt", "por"), ("qu", "que"), ("rm", "roh"),
+    ("rn", "run"), ("ro", "ron"), ("ru", "rus"), ("rw", "kin"), ("sa", "san"), ("sc", "srd"),
+    ("sd", "snd"), ("se", "sme"), ("sg", "sag"), ("si", "sin"), ("sk", "slk"), ("sl", "slv"),
+    ("sm", "smo"), ("sn", "sna"), ("so", "som"), ("sq", "sqi"), ("sr", "srp"), ("ss", "ssw"),
+    ("st", "sot"), ("su", "sun"), ("sv", "swe"), ("sw", "swa"), ("ta", "tam"), ("te", "tel"),
+    ("tg", "tgk"), ("th", "tha"), ("ti", "tir"), ("tk", "tuk"), ("tl", "tgl"), ("tn", "tsn"),
+    ("to", "ton"), ("tr", "tur"), ("ts", "tso"), ("tt", "tat"), ("tw", "twi"), ("ty", "tah"),
+    ("ug", "uig"), ("uk", "ukr"), ("ur", "urd"), ("uz", "uzb"), ("ve", "ven"), ("vi", "vie"),
+    ("vo", "vol"), ("wa", "wln"), ("wo", "wol"), ("xh", "xho"), ("yi", "yid"), ("yo", "yor"),
+    ("za", "zha"), ("zh", "zho"), ("zu", "zul"),
+];
+
+// ISO 639-2/B codes and the ISO 639-2/T codes they differ from, sorted by the former.
+#[rustfmt::skip]
+static ISO639_2B_TO_T: &[(&str, &str)] = &[
+    ("alb", "sqi"), ("arm", "hye"), ("baq", "eus"), ("bur", "mya"), ("chi", "zho"),
+    ("cze", "ces"), ("dut", "nld"), ("fre", "fra"), ("geo", "kat"), ("ger", "deu"),
+    ("gre", "ell"), ("ice", "isl"), ("mac", "mkd"), ("mao", "mri"), ("may", "msa"),
+    ("per", "fas"), ("rum", "ron"), ("slo", "slk"), ("tib", "bod"), ("wel", "cym"),
+];
+
+fn language(code: &str) -> Language {
+    Language::from_bytes(code.as_bytes()).expect("Table entries are well-formed.")
+}
+
+/// Returns the two-letter code of a three-letter language code, if it has one.
+pub fn to_iso639_1(language: Language) -> Option<Language> {
+    let code = language.as_opt_str()?;
+    ISO639_1_TO_3
+        .iter()
+        .find(|(_, alpha3)| *alpha3 == code)
+        .map(|(alpha2, _)| self::language(alpha2))
+}
+
+/// Returns the ISO 639-3 code of a language.
+///
+/// Three-letter codes are returned as they are, while `und` and codes longer
+/// than three letters have none.
+pub fn to_iso639_3(language: Language) -> Option<Language> {
+    let code = language.as_opt_str()?;
+    match code.len() {
+        2 => ISO639_1_TO_3
+            .binary_search_by_key(&code, |(alpha2, _)| alpha2)
+            .ok()
+            .map(|idx| self::language(ISO639_1_TO_3[idx].1)),
+        3 => Some(language),
+        _ => None,
+    }
+}
+
+/// Returns the canonical code of an ISO 639-2/B bibliographic language code,
+/// such as `de` for `ger`.
+pub fn from_bibliographic(language: Language) -> Option<Language> {
+    let code = language.as_opt_str()?;
+    let idx = ISO639_2B_TO_T
+        .binary_search_by_key(&code, |(bibliographic, _)| bibliographic)
+        .ok()?;
+    let terminological = self::language(ISO639_2B_TO_T[idx].1);
+    Some(to_iso639_1(terminological).unwrap_or(terminological))
+}
+
+/// Replaces an ISO 639-2/B bibliographic language code with its canonical code.
+///
+/// Returns `true` if the language was replaced.
+pub fn canonicalize_bibliographic(language: &mut Language) -> bool {
+    match from_bibliographic(*language) {
+        Some(canonical) => {
+            *language = canonical;
+            true
+        }
+        None => false,
+    }
+}
+
+/// Replaces a three-letter language code, including an ISO 639-2/B bibliographic
+/// one, with its two-letter equivalent.
+///
+/// Returns `true` if the language was replaced.
+pub fn canonicalize_language(language: &mut Language) -> bool {
+    match from_bibliographic(*language).or_else(|| to_iso639_1(*language)) {
+        Some(canonical) => {
+            *language = canonical;
+            true
+        }
+        None => false,
+    }
+}
@@ -1,4 +1,11 @@
-pub const SCRIPTS_CHARACTER_DIRECTION_RTL: [u32; 1] = [1650553409];
+pub static CLDR_VERSION: &str = "37";
+pub const SCRIPTS_CHARACTER_DIRECTION_RTL: [u32; 39] = [
+    1633773652, 1650553409, 1651663182, 1651663187, 1668441421, 1668446547, 1684500307, 1684955469,
+    1684956493, 1702000979, 1734897490, 1735292232, 1751872079, 1768192332, 1768712272, 1768780353,
+    1768841549, 1769239120, 1769629017, 1785887059, 1835819344, 1835820097, 1836674117, 1851880001,
+    1852995923, 1869049683, 1869572942, 1869768013, 1886152784, 1918986315, 1919051080, 1919382863,
+    1919770963, 1920229704, 1936877635, 1952539214, 1953656899, 1953723969, 2020501584,
+];
 pub const LANGS_CHARACTER_DIRECTION_RTL: [u64; 12] = [
     24934, 25715, 25960, 26485, 27001, 29281, 29301, 29547, 29552, 6450019, 6517356, 7240301,
 ];
@@ -1,19 +1,34 @@
+#[cfg(feature = "aliases")]
+pub mod aliases;
+#[cfg(feature = "aliases")]
+mod aliases_table;
 mod errors;
+#[cfg(feature = "iso3166")]
+pub mod iso3166;
+#[cfg(feature = "iso639")]
+pub mod iso639;
+#[cfg(feature = "character_direction")]
 mod layout_table;
 #[cfg(feature = "likelysubtags")]
 pub mod likelysubtags;
 #[doc(hidden)]
 pub mod parser;
+pub mod provider;
 #[cfg(feature = "serde")]
 mod serde;
 pub mod subtags;
 
 pub use crate::errors::LanguageIdentifierError;
+/// The CLDR version of the data used by `LanguageIdentifier::character_direction`.
+#[cfg(feature = "character_direction")]
+pub use crate::layout_table::CLDR_VERSION as LAYOUT_CLDR_VERSION;
+pub use crate::parser::ParserOptions;
 use std::fmt::Write;
 use std::iter::Peekable;
 use std::str::FromStr;
 
 /// Enum representing available character direction orientations.
+#[cfg(feature = "character_direction")]
 #[derive(Debug, PartialEq)]
 pub enum CharacterDirection {
     /// Right To Left
@@ -102,6 +117,31 @@ impl LanguageIdentifier {
         Ok(parser::parse_language_identifier(v)?)
     }
 
+    /// A constructor which takes a utf8 slice and parses it with
+    /// the given `ParserOptions`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::{LanguageIdentifier, ParserOptions};
+    ///
+    /// let options = ParserOptions::default().allow_reserved_language(true);
+    ///
+    /// assert!(LanguageIdentifier::from_bytes(b"abcd-US").is_err());
+    ///
+    /// let li = LanguageIdentifier::from_bytes_with_options(b"abcd-US", options)
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.to_string(), "abcd-US");
+    /// assert!(li.language.is_reserved());
+    /// ```
+    pub fn from_bytes_with_options(
+        v: &[u8],
+        options: ParserOptions,
+    ) -> Result<Self, LanguageIdentifierError> {
+        Ok(parser::parse_language_identifier_with_options(v, options)?)
+    }
+
     /// A constructor which takes optional subtags as `AsRef<[u8]>`, parses them and
     /// produces a well-formed `LanguageIdentifier`.
     ///
@@ -175,6 +215,23 @@ impl LanguageIdentifier {
         )?)
     }
 
+    #[doc(hidden)]
+    /// This method is used by `unic-locale` to handle partial
+    /// subtag iterator with custom parser options.
+    ///
+    /// Not stable.
+    pub fn try_from_iter_with_options<'a>(
+        iter: &mut Peekable<impl Iterator<Item = &'a [u8]>>,
+        allow_extension: bool,
+        options: parser::ParserOptions,
+    ) -> Result<LanguageIdentifier, LanguageIdentifierError> {
+        Ok(parser::parse_language_identifier_from_iter_with_options(
+            iter,
+            allow_extension,
+            options,
+        )?)
+    }
+
     /// Consumes `LanguageIdentifier` and produces raw internal representations
     /// of all subtags in form of `u64`/`u32`.
     ///
@@ -242,7 +299,7 @@ impl LanguageIdentifier {
     ) -> bool {
         let other = other.as_ref();
         self.language
-            .matches(&other.language, self_as_range, other_as_range)
+            .matches(other.language, self_as_range, other_as_range)
             && subtag_matches(&self.script, &other.script, self_as_range, other_as_range)
             && subtag_matches(&self.region, &other.region, self_as_range, other_as_range)
             && subtags_match(
@@ -253,6 +310,66 @@ impl LanguageIdentifier {
             )
     }
 
+    /// Returns a bitmask of the optional subtags present in the `LanguageIdentifier`,
+    /// with `PRESENCE_SCRIPT`, `PRESENCE_REGION` and `PRESENCE_VARIANTS` set.
+    ///
+    /// Identifiers with different masks are never equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "sr-Latn".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.presence(), LanguageIdentifier::PRESENCE_SCRIPT);
+    /// ```
+    #[inline]
+    pub fn presence(&self) -> u8 {
+        let mut result = 0;
+        if self.script.is_some() {
+            result |= Self::PRESENCE_SCRIPT;
+        }
+        if self.region.is_some() {
+            result |= Self::PRESENCE_REGION;
+        }
+        if !is_option_empty(&self.variants) {
+            result |= Self::PRESENCE_VARIANTS;
+        }
+        result
+    }
+
+    /// The bit set in `presence` when there is a script subtag.
+    pub const PRESENCE_SCRIPT: u8 = 1;
+    /// The bit set in `presence` when there is a region subtag.
+    pub const PRESENCE_REGION: u8 = 1 << 1;
+    /// The bit set in `presence` when there are variant subtags.
+    pub const PRESENCE_VARIANTS: u8 = 1 << 2;
+
+    /// Returns the language subtag of the `LanguageIdentifier`, or `None`
+    /// if the language is undefined (`und`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li1: LanguageIdentifier = "de-AT".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li1.language_opt(), Some("de"));
+    ///
+    /// let li2: LanguageIdentifier = "und-AT".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li2.language_opt(), None);
+    /// assert_eq!(li2.language.as_str(), "und");
+    /// ```
+    pub fn language_opt(&self) -> Option<&str> {
+        self.language.as_opt_str()
+    }
+
     /// Returns a vector of variants subtags of the `LanguageIdentifier`.
     ///
     /// # Examples
@@ -272,13 +389,35 @@ impl LanguageIdentifier {
     /// ```
     pub fn variants(&self) -> impl ExactSizeIterator<Item = &subtags::Variant> {
         let variants: &[_] = match self.variants {
-            Some(ref v) => &**v,
+            Some(ref v) => v,
             None => &[],
         };
 
         variants.iter()
     }
 
+    /// Returns the variant subtags of the `LanguageIdentifier` as a slice, sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li: LanguageIdentifier = "de-DE-1996-1901".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// match li.variants_slice() {
+    ///     [first, second] => assert_eq!((first.as_str(), second.as_str()), ("1901", "1996")),
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn variants_slice(&self) -> &[subtags::Variant] {
+        match self.variants {
+            Some(ref v) => v,
+            None => &[],
+        }
+    }
+
     /// Sets variant subtags of the `LanguageIdentifier`.
     ///
     /// # Examples
@@ -373,6 +512,12 @@ impl LanguageIdentifier {
     /// Extends the `LanguageIdentifier` removing likely subtags based
     /// on tables provided by CLDR.
     ///
+    /// Only the language, script and region are considered. Variants are
+    /// preserved and do not affect the result. When either the script or the
+    /// region alone is enough, the region is kept.
+    ///
+    /// See [`minimize_with`] for control over both.
+    ///
     /// # Examples
     ///
     /// ```
@@ -383,10 +528,54 @@ impl LanguageIdentifier {
     ///
     /// assert_eq!(li.minimize(), true);
     /// assert_eq!(li.to_string(), "en");
+    ///
+    /// let mut li: LanguageIdentifier = "zh-Hant-TW-pinyin".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.minimize(), true);
+    /// assert_eq!(li.to_string(), "zh-TW-pinyin");
     /// ```
+    ///
+    /// [`minimize_with`]: #method.minimize_with
     #[cfg(feature = "likelysubtags")]
     pub fn minimize(&mut self) -> bool {
-        if let Some(new_li) = likelysubtags::minimize(self.language, self.script, self.region) {
+        self.minimize_with(likelysubtags::MinimizeOptions::default())
+    }
+
+    /// Extends the `LanguageIdentifier` removing likely subtags based
+    /// on tables provided by CLDR, using the given [`MinimizeOptions`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    /// use unic_langid_impl::likelysubtags::MinimizeOptions;
+    ///
+    /// let mut li: LanguageIdentifier = "sr-Cyrl-RS-ekavsk".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let options = MinimizeOptions {
+    ///     variants_block: true,
+    ///     ..Default::default()
+    /// };
+    ///
+    /// assert_eq!(li.minimize_with(options), false);
+    /// assert_eq!(li.to_string(), "sr-Cyrl-RS-ekavsk");
+    /// ```
+    ///
+    /// [`MinimizeOptions`]: ./likelysubtags/struct.MinimizeOptions.html
+    #[cfg(feature = "likelysubtags")]
+    pub fn minimize_with(&mut self, options: likelysubtags::MinimizeOptions) -> bool {
+        if options.variants_block && self.variants.is_some() {
+            return false;
+        }
+
+        if let Some(new_li) = likelysubtags::minimize_with(
+            self.language,
+            self.script,
+            self.region,
+            options.favor_script,
+        ) {
             self.language = new_li.0;
             self.script = new_li.1;
             self.region = new_li.2;
@@ -396,8 +585,166 @@ impl LanguageIdentifier {
         }
     }
 
+    /// Returns `true` if the likely subtags tables provided by CLDR cover
+    /// the language of the `LanguageIdentifier`.
+    ///
+    /// If the language is undefined, the script and region are looked up
+    /// instead. When this returns `false`, [`maximize`] and [`minimize`]
+    /// will not modify the `LanguageIdentifier`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let li1: LanguageIdentifier = "en-Latn-US".parse()
+    ///     .expect("Parsing failed.");
+    /// let li2: LanguageIdentifier = "qaa-US".parse()
+    ///     .expect("Parsing failed.");
+    /// let li3: LanguageIdentifier = "und-PL".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li1.has_likely_subtags(), true);
+    /// assert_eq!(li2.has_likely_subtags(), false);
+    /// assert_eq!(li3.has_likely_subtags(), true);
+    /// ```
+    ///
+    /// [`maximize`]: #method.maximize
+    /// [`minimize`]: #method.minimize
+    #[cfg(feature = "likelysubtags")]
+    pub fn has_likely_subtags(&self) -> bool {
+        likelysubtags::has_likely_subtags(self.language, self.script, self.region)
+    }
+
+    /// Extends the `LanguageIdentifier` adding the likely subtags from the given
+    /// [`DataProvider`], as [`maximize`] does with the compiled-in data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::{subtags, LanguageIdentifier};
+    /// use unic_langid_impl::provider::{DataProvider, Subtags};
+    ///
+    /// struct Latin;
+    ///
+    /// impl DataProvider for Latin {
+    ///     fn maximize(
+    ///         &self,
+    ///         lang: subtags::Language,
+    ///         script: Option<subtags::Script>,
+    ///         region: Option<subtags::Region>,
+    ///     ) -> Option<Subtags> {
+    ///         Some((lang, script.or_else(|| "Latn".parse().ok()), region))
+    ///     }
+    /// }
+    ///
+    /// let mut li: LanguageIdentifier = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.maximize_with_provider(&Latin), true);
+    /// assert_eq!(li.to_string(), "en-Latn-US");
+    /// ```
+    ///
+    /// [`DataProvider`]: ./provider/trait.DataProvider.html
+    /// [`maximize`]: #method.maximize
+    pub fn maximize_with_provider(&mut self, provider: &impl provider::DataProvider) -> bool {
+        if let Some(new_li) = provider.maximize(self.language, self.script, self.region) {
+            self.language = new_li.0;
+            self.script = new_li.1;
+            self.region = new_li.2;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Replaces deprecated language, script and region subtags with the aliases
+    /// from the given [`DataProvider`].
+    ///
+    /// A language alias with a script or region, such as `sr-Latn` for `sh`, only
+    /// fills in the subtags the `LanguageIdentifier` does not have. A region which
+    /// was split into several, such as `SU`, is replaced with the likely region of
+    /// the language and script when it is one of the successors, as `AM` in `hy-SU`,
+    /// and with the first successor otherwise. Returns `true` if any subtag was
+    /// replaced.
+    ///
+    /// [`DataProvider`]: ./provider/trait.DataProvider.html
+    pub fn canonicalize_with_provider(&mut self, provider: &impl provider::DataProvider) -> bool {
+        let mut changed = false;
+        if let Some((language, script, region)) = provider.language_alias(self.language) {
+            self.language = language;
+            self.script = self.script.or(script);
+            self.region = self.region.or(region);
+            changed = true;
+        }
+        if let Some(script) = self.script.and_then(|s| provider.script_alias(s)) {
+            self.script = Some(script);
+            changed = true;
+        }
+        if let Some(successors) = self.region.and_then(|r| provider.region_alias(r)) {
+            // A region which was split into several is replaced with the likely
+            // region of the language and script, if it is one of the successors.
+            let likely = match successors.len() {
+                1 => None,
+                _ => provider
+                    .maximize(self.language, self.script, None)
+                    .and_then(|(_, _, region)| region)
+                    .filter(|region| successors.contains(region)),
+            };
+            if let Some(region) = likely.or_else(|| successors.first().copied()) {
+                self.region = Some(region);
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    /// Replaces deprecated language, script and region subtags and legacy tags
+    /// with their aliases from CLDR, as [`canonicalize_with_provider`] does with
+    /// the [`aliases`] data.
+    ///
+    /// The likely region which replaces a region split into several needs the
+    /// `likelysubtags` feature; without it, the first successor is used.
+    ///
+    /// Returns `true` if any subtag was replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::LanguageIdentifier;
+    ///
+    /// let mut li: LanguageIdentifier = "sh-YU".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.canonicalize_aliases(), true);
+    /// assert_eq!(li.to_string(), "sr-Latn-RS");
+    ///
+    /// let mut li: LanguageIdentifier = "art-lojban".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(li.canonicalize_aliases(), true);
+    /// assert_eq!(li.to_string(), "jbo");
+    /// ```
+    ///
+    /// [`canonicalize_with_provider`]: #method.canonicalize_with_provider
+    /// [`aliases`]: ./aliases/index.html
+    #[cfg(feature = "aliases")]
+    pub fn canonicalize_aliases(&mut self) -> bool {
+        let mut changed = false;
+        if self.script.is_none() && self.variants().len() <= 1 {
+            if let Some(replacement) = aliases::legacy_tag(&self.to_string()) {
+                *self = replacement.parse().expect("Aliases are well-formed.");
+                changed = true;
+            }
+        }
+        self.canonicalize_with_provider(&provider::CompiledData) || changed
+    }
+
     /// Returns character direction of the `LanguageIdentifier`.
     ///
+    /// An explicit script subtag takes precedence over the default
+    /// direction of the language.
+    ///
     /// # Examples
     ///
     /// ```
@@ -407,18 +754,27 @@ impl LanguageIdentifier {
     ///     .expect("Parsing failed.");
     /// let li2: LanguageIdentifier = "fa".parse()
     ///     .expect("Parsing failed.");
+    /// let li3: LanguageIdentifier = "az-Arab".parse()
+    ///     .expect("Parsing failed.");
+    /// let li4: LanguageIdentifier = "ur-Latn".parse()
+    ///     .expect("Parsing failed.");
     ///
     /// assert_eq!(li1.character_direction(), CharacterDirection::LTR);
     /// assert_eq!(li2.character_direction(), CharacterDirection::RTL);
+    /// assert_eq!(li3.character_direction(), CharacterDirection::RTL);
+    /// assert_eq!(li4.character_direction(), CharacterDirection::LTR);
     /// ```
+    #[cfg(feature = "character_direction")]
     pub fn character_direction(&self) -> CharacterDirection {
         match (self.language.into(), self.script) {
-            (_, Some(script))
-                if layout_table::SCRIPTS_CHARACTER_DIRECTION_RTL.contains(&script.into()) =>
-            {
-                CharacterDirection::RTL
+            (_, Some(script)) => {
+                if layout_table::SCRIPTS_CHARACTER_DIRECTION_RTL.contains(&script.into()) {
+                    CharacterDirection::RTL
+                } else {
+                    CharacterDirection::LTR
+                }
             }
-            (Some(lang), _) if layout_table::LANGS_CHARACTER_DIRECTION_RTL.contains(&lang) => {
+            (Some(lang), None) if layout_table::LANGS_CHARACTER_DIRECTION_RTL.contains(&lang) => {
                 CharacterDirection::RTL
             }
             _ => CharacterDirection::LTR,
@@ -441,8 +797,45 @@ impl AsRef<LanguageIdentifier> for LanguageIdentifier {
     }
 }
 
+/// A stack buffer fitting the longest language identifier without variants,
+/// such as `abcdefgh-Latn-419`.
+struct ShortBuffer {
+    bytes: [u8; 17],
+    len: usize,
+}
+
+impl ShortBuffer {
+    fn push(&mut self, s: &str) {
+        self.bytes[self.len..self.len + s.len()].copy_from_slice(s.as_bytes());
+        self.len += s.len();
+    }
+
+    fn as_str(&self) -> &str {
+        std::str::from_utf8(&self.bytes[..self.len]).expect("Subtags are ASCII.")
+    }
+}
+
 impl std::fmt::Display for LanguageIdentifier {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        // Most identifiers have no variants, and are written at once rather
+        // than subtag by subtag.
+        if self.variants.is_none() {
+            let mut buffer = ShortBuffer {
+                bytes: [0; 17],
+                len: 0,
+            };
+            buffer.push(self.language.as_str());
+            if let Some(ref script) = self.script {
+                buffer.push("-");
+                buffer.push(script.as_str());
+            }
+            if let Some(ref region) = self.region {
+                buffer.push("-");
+                buffer.push(region.as_str());
+            }
+            return f.write_str(buffer.as_str());
+        }
+
         self.language.fmt(f)?;
         if let Some(ref script) = self.script {
             f.write_char('-')?;
@@ -495,8 +888,9 @@ fn subtags_match<P: PartialEq>(
 
 /// This is a best-effort operation that performs all available levels of canonicalization.
 ///
-/// At the moment the operation will normalize casing and the separator, but in the future
-/// it may also validate and update from deprecated subtags to canonical ones.
+/// At the moment the operation will normalize casing and the separator, and with the
+/// `iso639` feature replace three-letter language codes with their two-letter equivalents,
+/// but in the future it may also validate and update from deprecated subtags to canonical ones.
 ///
 /// # Examples
 ///
@@ -506,7 +900,10 @@ fn subtags_match<P: PartialEq>(
 /// assert_eq!(canonicalize("pL_latn_pl"), Ok("pl-Latn-PL".to_string()));
 /// ```
 pub fn canonicalize<S: AsRef<[u8]>>(input: S) -> Result<String, LanguageIdentifierError> {
-    let lang_id = LanguageIdentifier::from_bytes(input.as_ref())?;
+    #[cfg_attr(not(feature = "iso639"), allow(unused_mut))]
+    let mut lang_id = LanguageIdentifier::from_bytes(input.as_ref())?;
+    #[cfg(feature = "iso639")]
+    iso639::canonicalize_language(&mut lang_id.language);
     Ok(lang_id.to_string())
 }
 
@@ -1,8 +1,15 @@
+#[cfg(not(likelysubtags_subset))]
 mod tables;
+#[cfg(likelysubtags_subset)]
+#[allow(clippy::type_complexity, clippy::unreadable_literal)]
+mod tables {
+    include!(concat!(env!("OUT_DIR"), "/likelysubtags_tables.rs"));
+}
 
 pub use tables::CLDR_VERSION;
 
 use crate::subtags;
+use crate::LanguageIdentifier;
 
 unsafe fn lang_from_parts(
     input: (Option<u64>, Option<u32>, Option<u32>),
@@ -38,7 +45,7 @@ pub fn maximize(
     if let Some(l) = Into::<Option<u64>>::into(lang) {
         if let Some(r) = region {
             let result = tables::LANG_REGION
-                .binary_search_by_key(&(&l.into(), &r.into()), |(key_l, key_r, _)| (key_l, key_r))
+                .binary_search_by_key(&(&l, &r.into()), |(key_l, key_r, _)| (key_l, key_r))
                 .ok();
             if let Some(r) = result {
                 // safe because all table entries are well formed.
@@ -48,7 +55,7 @@ pub fn maximize(
 
         if let Some(s) = script {
             let result = tables::LANG_SCRIPT
-                .binary_search_by_key(&(&l.into(), &s.into()), |(key_l, key_s, _)| (key_l, key_s))
+                .binary_search_by_key(&(&l, &s.into()), |(key_l, key_s, _)| (key_l, key_s))
                 .ok();
             if let Some(r) = result {
                 // safe because all table entries are well formed.
@@ -57,7 +64,7 @@ pub fn maximize(
         }
 
         let result = tables::LANG_ONLY
-            .binary_search_by_key(&(&l.into()), |(key_l, _)| key_l)
+            .binary_search_by_key(&&l, |(key_l, _)| key_l)
             .ok();
         if let Some(r) = result {
             // safe because all table entries are well formed.
@@ -94,6 +101,68 @@ pub fn maximize(
     None
 }
 
+/// Returns `true` if the compiled-in likely subtags data has an entry for the
+/// language, or, if the language is undefined, for the script and/or region.
+///
+/// When this returns `false`, `maximize` and `minimize` leave the subtags unchanged.
+pub fn has_likely_subtags(
+    lang: subtags::Language,
+    script: Option<subtags::Script>,
+    region: Option<subtags::Region>,
+) -> bool {
+    if lang.is_empty() {
+        maximize(lang, script, region).is_some()
+    } else {
+        maximize(lang, None, None).is_some()
+    }
+}
+
+/// Returns the most likely language identifier for a region, with all of the
+/// language, script and region subtags filled in.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::likelysubtags::likely_for_region;
+///
+/// let li = likely_for_region("DE".parse().unwrap())
+///     .expect("The region has likely subtags.");
+///
+/// assert_eq!(li.to_string(), "de-Latn-DE");
+/// ```
+pub fn likely_for_region(region: subtags::Region) -> Option<LanguageIdentifier> {
+    let (lang, script, region) = maximize(subtags::Language::default(), None, Some(region))?;
+    Some(LanguageIdentifier::from_parts(lang, script, region, &[]))
+}
+
+/// Options controlling how `LanguageIdentifier::minimize_with` removes likely subtags.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::LanguageIdentifier;
+/// use unic_langid_impl::likelysubtags::MinimizeOptions;
+///
+/// let mut li: LanguageIdentifier = "zh-Hant-TW".parse()
+///     .expect("Parsing failed.");
+///
+/// let options = MinimizeOptions {
+///     favor_script: true,
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(li.minimize_with(options), true);
+/// assert_eq!(li.to_string(), "zh-Hant");
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MinimizeOptions {
+    /// When both the script and the region alone are enough to restore the
+    /// maximized identifier, keep the script instead of the region.
+    pub favor_script: bool,
+    /// Leave identifiers with variant subtags unchanged.
+    pub variants_block: bool,
+}
+
 pub fn minimize(
     lang: subtags::Language,
     script: Option<subtags::Script>,
@@ -102,6 +171,21 @@ pub fn minimize(
     subtags::Language,
     Option<subtags::Script>,
     Option<subtags::Region>,
+)> {
+    minimize_with(lang, script, region, false)
+}
+
+/// Like `minimize`, but if `favor_script` is `true`, the script is kept instead
+/// of the region when either alone is enough to restore the maximized subtags.
+pub fn minimize_with(
+    lang: subtags::Language,
+    script: Option<subtags::Script>,
+    region: Option<subtags::Region>,
+    favor_script: bool,
+) -> Option<(
+    subtags::Language,
+    Option<subtags::Script>,
+    Option<subtags::Region>,
 )> {
     // maximize returns None when all 3 components are
     // already filled so don't call it in that case.
@@ -117,18 +201,21 @@ pub fn minimize(
         }
     }
 
-    if max_langid.2.is_some() {
-        if let Some(trial) = maximize(max_langid.0, None, max_langid.2) {
-            if trial == max_langid {
-                return Some((max_langid.0, None, max_langid.2));
-            }
-        }
-    }
+    let with_region = (max_langid.0, None, max_langid.2);
+    let with_script = (max_langid.0, max_langid.1, None);
+    let candidates = if favor_script {
+        [with_script, with_region]
+    } else {
+        [with_region, with_script]
+    };
 
-    if max_langid.1.is_some() {
-        if let Some(trial) = maximize(max_langid.0, max_langid.1, None) {
+    for candidate in &candidates {
+        if candidate.1.is_none() && candidate.2.is_none() {
+            continue;
+        }
+        if let Some(trial) = maximize(candidate.0, candidate.1, candidate.2) {
             if trial == max_langid {
-                return Some((max_langid.0, max_langid.1, None));
+                return Some(*candidate);
             }
         }
     }
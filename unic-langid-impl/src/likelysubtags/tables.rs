@@ -1,8 +1,8 @@
 #![allow(clippy::type_complexity)]
 #![allow(clippy::unreadable_literal)]
 
-pub static CLDR_VERSION: &str = "36";
-pub static LANG_ONLY: [(u64, (Option<u64>, Option<u32>, Option<u32>)); 1333] = [
+pub static CLDR_VERSION: &str = "37";
+pub static LANG_ONLY: [(u64, (Option<u64>, Option<u32>, Option<u32>)); 1336] = [
     (24929, (Some(24929), Some(1853120844), Some(21573))),
     (24930, (Some(24930), Some(1819441475), Some(21842))),
     (24931, (Some(24931), Some(1853120844), Some(21317))),
@@ -341,6 +341,7 @@ pub static LANG_ONLY: [(u64, (Option<u64>, Option<u32>, Option<u32>)); 1333] = [
     (6518643, (Some(6518643), Some(1853120844), Some(17475))),
     (6518891, (Some(6518891), Some(1768453189), None)),
     (6518893, (Some(6518893), Some(1853120844), Some(22362))),
+    (6578531, (Some(6578531), Some(1853120844), Some(21333))),
     (6578532, (Some(6578532), Some(1853120844), None)),
     (6578539, (Some(6578539), Some(1853120844), None)),
     (6578540, (Some(6578540), Some(1919051080), Some(19529))),
@@ -860,6 +861,7 @@ pub static LANG_ONLY: [(u64, (Option<u64>, Option<u32>, Option<u32>)); 1333] = [
     (7299938, (Some(7299938), Some(1853120844), None)),
     (7299950, (Some(7299950), Some(1853120844), None)),
     (7299955, (Some(7299955), Some(1853120844), Some(16967))),
+    (7299960, (Some(7299960), Some(1936877635), Some(23125))),
     (7300464, (Some(7300464), Some(1868918872), Some(21065))),
     (7300468, (Some(7300468), Some(1853120844), Some(18261))),
     (7300715, (Some(7300715), Some(1853120844), Some(18755))),
@@ -1101,7 +1103,7 @@ pub static LANG_ONLY: [(u64, (Option<u64>, Option<u32>, Option<u32>)); 1333] = [
     (7566951, (Some(7566951), Some(1853120844), None)),
     (7567209, (Some(7567209), Some(1853120844), None)),
     (7567714, (Some(7567714), Some(1853120844), None)),
-    (7627123, (Some(7627123), Some(1853120844), Some(20041))),
+    (7627123, (Some(7627123), Some(1801677903), Some(20041))),
     (7627129, (Some(7627129), Some(1853120844), None)),
     (7627361, (Some(7627361), Some(1853120844), None)),
     (7627627, (Some(7627627), Some(1853120844), None)),
@@ -1118,6 +1120,7 @@ pub static LANG_ONLY: [(u64, (Option<u64>, Option<u32>, Option<u32>)); 1333] = [
     (7629676, (Some(7629676), Some(1853120844), Some(21333))),
     (7629682, (Some(7629682), Some(1735288130), Some(17474))),
     (7629684, (Some(7629684), Some(1635149124), Some(20558))),
+    (7629690, (Some(7629690), Some(1937008971), Some(20035))),
     (7629921, (Some(7629921), Some(1819441475), Some(21842))),
     (7629922, (Some(7629922), Some(1953915220), Some(20054))),
     (7629931, (Some(7629931), Some(1853120844), None)),
@@ -1370,7 +1373,7 @@ pub static LANG_REGION: [(u64, u32, (Option<u64>, Option<u32>, Option<u32>)); 45
     (29299, 21076, (Some(29299), Some(1853120844), Some(21076))),
     (29299, 21842, (Some(29299), Some(1853120844), Some(21842))),
     (29549, 17219, (Some(29549), Some(1650553409), Some(17219))),
-    (29549, 17481, (Some(29549), Some(1650553409), Some(17481))),
+    (29549, 17481, (Some(29549), Some(1853120844), Some(17481))),
     (30059, 16972, (Some(30059), Some(1650553409), Some(16972))),
     (31083, 20035, (Some(31083), Some(1650553409), Some(20035))),
     (31083, 21076, (Some(31083), Some(1853120844), Some(21076))),
@@ -1400,7 +1403,7 @@ pub static LANG_REGION: [(u64, u32, (Option<u64>, Option<u32>, Option<u32>)); 45
         (Some(7499381), Some(1635149124), Some(20558)),
     ),
 ];
-pub static LANG_SCRIPT: [(u64, u32, (Option<u64>, Option<u32>, Option<u32>)); 28] = [
+pub static LANG_SCRIPT: [(u64, u32, (Option<u64>, Option<u32>, Option<u32>)); 31] = [
     (
         24944,
         1650553409,
@@ -1451,6 +1454,11 @@ pub static LANG_SCRIPT: [(u64, u32, (Option<u64>, Option<u32>, Option<u32>)); 28
         1953390920,
         (Some(26746), Some(1953390920), Some(22356)),
     ),
+    (
+        26984,
+        1853120844,
+        (Some(26984), Some(1853120844), Some(20041)),
+    ),
     (
         27499,
         1650553409,
@@ -1466,6 +1474,11 @@ pub static LANG_SCRIPT: [(u64, u32, (Option<u64>, Option<u32>, Option<u32>)); 28
         1735290701,
         (Some(28269), Some(1735290701), Some(20035)),
     ),
+    (
+        29547,
+        1635149124,
+        (Some(29547), Some(1635149124), Some(20041)),
+    ),
     (
         30051,
         1734437959,
@@ -1476,6 +1489,11 @@ pub static LANG_SCRIPT: [(u64, u32, (Option<u64>, Option<u32>, Option<u32>)); 28
         1650553409,
         (Some(30059), Some(1650553409), Some(20809)),
     ),
+    (
+        30059,
+        1769629017,
+        (Some(30059), Some(1769629017), Some(17735)),
+    ),
     (
         31083,
         1650553409,
@@ -1839,7 +1857,7 @@ pub static SCRIPT_REGION: [(u32, u32, (Option<u64>, Option<u32>, Option<u32>));
         (Some(7628907), Some(1919777101), Some(20041)),
     ),
 ];
-pub static SCRIPT_ONLY: [(u32, (Option<u64>, Option<u32>, Option<u32>)); 154] = [
+pub static SCRIPT_ONLY: [(u32, (Option<u64>, Option<u32>, Option<u32>)); 158] = [
     (1633773652, (Some(30308), Some(1633773652), Some(22093))),
     (1633840197, (Some(29043), Some(1633840197), Some(19521))),
     (1633971787, (Some(28267), Some(1633971787), Some(20041))),
@@ -1920,8 +1938,10 @@ pub static SCRIPT_ONLY: [(u32, (Option<u64>, Option<u32>, Option<u32>)); 154] =
     (1768841549, (Some(7236984), Some(1768841549), Some(20035))),
     (1769103683, (Some(7496568), Some(1769103683), Some(21076))),
     (1769239120, (Some(7499896), Some(1769239120), Some(21065))),
+    (1769629017, (Some(30059), Some(1769629017), Some(17735))),
     (1785225549, (Some(26984), Some(1785225549), Some(20041))),
     (1785686091, (Some(25715), Some(1785686091), Some(20041))),
+    (1801546052, (Some(30308), Some(1801546052), Some(22093))),
     (1801677903, (Some(7627123), Some(1801677903), Some(20041))),
     (1801810503, (Some(27749), Some(1801810503), Some(21063))),
     (1802789186, (Some(6513250), Some(1802789186), Some(17481))),
@@ -1974,7 +1994,9 @@ pub static SCRIPT_ONLY: [(u32, (Option<u64>, Option<u32>, Option<u32>)); 154] =
     (1936418882, (Some(24947), Some(1936418882), Some(20041))),
     (1936613699, (Some(29283), Some(1936613699), Some(16707))),
     (1936613704, (Some(26746), Some(1936613704), Some(20035))),
+    (1936877635, (Some(7299960), Some(1936877635), Some(23125))),
     (1936941378, (Some(7435106), Some(1936941378), Some(21068))),
+    (1937008971, (Some(7629690), Some(1937008971), Some(20035))),
     (1952539214, (Some(6517345), Some(1952539214), Some(20298))),
     (1952606548, (Some(28514), Some(1952606548), Some(20035))),
     (1953264973, (Some(7498611), Some(1953264973), Some(19280))),
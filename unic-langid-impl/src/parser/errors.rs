@@ -2,7 +2,10 @@ use std::error::Error;
 use std::fmt::{self, Display};
 
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ParserError {
+    /// The input is empty.
+    EmptyInput,
     InvalidLanguage,
     InvalidSubtag,
 }
@@ -12,6 +15,7 @@ impl Error for ParserError {}
 impl Display for ParserError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let value = match self {
+            ParserError::EmptyInput => "The input is empty",
             ParserError::InvalidLanguage => "The given language subtag is invalid",
             ParserError::InvalidSubtag => "Invalid subtag",
         };
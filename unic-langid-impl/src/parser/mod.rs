@@ -6,19 +6,121 @@ pub use self::errors::ParserError;
 use crate::subtags;
 use crate::LanguageIdentifier;
 
+/// Options controlling the behavior of [`parse_language_identifier_with_options`].
+///
+/// New options may be added in minor releases, so the options are built from
+/// `ParserOptions::default()`, such as `ParserOptions::default().lenient(true)`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ParserOptions {
+    /// Accept the 4-letter primary language subtags reserved by BCP47
+    /// for future use, instead of rejecting them.
+    pub allow_reserved_language: bool,
+    /// Accept common non-canonical codes and replace them with the canonical ones.
+    ///
+    /// With the `iso639` feature, ISO 639-2/B language codes, such as `ger`, are
+    /// replaced with their canonical equivalents. With the `iso3166` feature,
+    /// ISO 3166-1 alpha-3 region codes, such as `USA`, are replaced with the
+    /// alpha-2 ones.
+    ///
+    /// A three-digit subtag in the script position which is an ISO 15924 numeric
+    /// script code, such as `215` for `Latn`, is parsed as that script when a region
+    /// subtag follows it, as in `sr-215-RS`, and it is not one of the UN M49
+    /// macro-region codes used by CLDR, such as `419`. Otherwise it is a UN M49
+    /// region, as in `fr-250`.
+    pub lenient: bool,
+}
+
+impl ParserOptions {
+    /// Sets `allow_reserved_language`.
+    pub const fn allow_reserved_language(mut self, allow_reserved_language: bool) -> Self {
+        self.allow_reserved_language = allow_reserved_language;
+        self
+    }
+
+    /// Sets `lenient`.
+    pub const fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+}
+
+// The UN M49 macro-region codes used by CLDR, which are never taken
+// for ISO 15924 numeric script codes.
+static MACRO_REGIONS: &[&[u8]] = &[
+    b"001", b"002", b"003", b"005", b"009", b"011", b"013", b"014", b"015", b"017", b"018", b"019",
+    b"021", b"029", b"030", b"034", b"035", b"039", b"053", b"054", b"057", b"061", b"142", b"143",
+    b"145", b"150", b"151", b"154", b"155", b"202", b"419",
+];
+
+fn parse_numeric_script(subtag: &[u8], options: ParserOptions) -> Option<subtags::Script> {
+    if !options.lenient
+        || subtag.len() != 3
+        || !subtag.iter().all(u8::is_ascii_digit)
+        || MACRO_REGIONS.contains(&subtag)
+    {
+        return None;
+    }
+    std::str::from_utf8(subtag)
+        .ok()?
+        .parse()
+        .ok()
+        .and_then(subtags::Script::from_numeric)
+}
+
+#[cfg(feature = "iso3166")]
+fn parse_region(subtag: &[u8], options: ParserOptions) -> Result<subtags::Region, ParserError> {
+    subtags::Region::from_bytes(subtag).or_else(|err| {
+        if options.lenient {
+            crate::iso3166::from_alpha3(subtag).ok_or(err)
+        } else {
+            Err(err)
+        }
+    })
+}
+
+#[cfg(not(feature = "iso3166"))]
+fn parse_region(subtag: &[u8], _options: ParserOptions) -> Result<subtags::Region, ParserError> {
+    subtags::Region::from_bytes(subtag)
+}
+
 pub fn parse_language_identifier_from_iter<'a>(
     iter: &mut Peekable<impl Iterator<Item = &'a [u8]>>,
     allow_extension: bool,
 ) -> Result<LanguageIdentifier, ParserError> {
-    let language = if let Some(subtag) = iter.next() {
-        subtags::Language::from_bytes(subtag)?
+    parse_language_identifier_from_iter_with_options(
+        iter,
+        allow_extension,
+        ParserOptions::default(),
+    )
+}
+
+pub fn parse_language_identifier_from_iter_with_options<'a>(
+    iter: &mut Peekable<impl Iterator<Item = &'a [u8]>>,
+    allow_extension: bool,
+    options: ParserOptions,
+) -> Result<LanguageIdentifier, ParserError> {
+    #[cfg_attr(not(feature = "iso639"), allow(unused_mut))]
+    let mut language = if let Some(subtag) = iter.next() {
+        if options.allow_reserved_language {
+            subtags::Language::from_bytes_allow_reserved(subtag)?
+        } else {
+            subtags::Language::from_bytes(subtag)?
+        }
     } else {
         subtags::Language::default()
     };
+    #[cfg(feature = "iso639")]
+    if options.lenient {
+        crate::iso639::canonicalize_bibliographic(&mut language);
+    }
 
     let mut script = None;
     let mut region = None;
     let mut variants = vec![];
+    // A numeric script code in the script position, which is only taken for
+    // the script if a region follows it.
+    let mut numeric_script = None;
 
     let mut position = 1;
 
@@ -27,8 +129,9 @@ pub fn parse_language_identifier_from_iter<'a>(
             if let Ok(s) = subtags::Script::from_bytes(subtag) {
                 script = Some(s);
                 position = 2;
-            } else if let Ok(s) = subtags::Region::from_bytes(subtag) {
+            } else if let Ok(s) = parse_region(subtag, options) {
                 region = Some(s);
+                numeric_script = parse_numeric_script(subtag, options);
                 position = 3;
             } else if let Ok(v) = subtags::Variant::from_bytes(subtag) {
                 variants.push(v);
@@ -37,7 +140,7 @@ pub fn parse_language_identifier_from_iter<'a>(
                 break;
             }
         } else if position == 2 {
-            if let Ok(s) = subtags::Region::from_bytes(subtag) {
+            if let Ok(s) = parse_region(subtag, options) {
                 region = Some(s);
                 position = 3;
             } else if let Ok(v) = subtags::Variant::from_bytes(subtag) {
@@ -46,6 +149,12 @@ pub fn parse_language_identifier_from_iter<'a>(
             } else {
                 break;
             }
+        } else if let Some((s, r)) = numeric_script
+            .take()
+            .and_then(|s| Some((s, parse_region(subtag, options).ok()?)))
+        {
+            script = Some(s);
+            region = Some(r);
         } else {
             // Variants
             if let Ok(v) = subtags::Variant::from_bytes(subtag) {
@@ -78,6 +187,20 @@ pub fn parse_language_identifier_from_iter<'a>(
 }
 
 pub fn parse_language_identifier(t: &[u8]) -> Result<LanguageIdentifier, ParserError> {
+    if t.is_empty() {
+        return Err(ParserError::EmptyInput);
+    }
     let mut iter = t.split(|c| *c == b'-' || *c == b'_').peekable();
     parse_language_identifier_from_iter(&mut iter, false)
 }
+
+pub fn parse_language_identifier_with_options(
+    t: &[u8],
+    options: ParserOptions,
+) -> Result<LanguageIdentifier, ParserError> {
+    if t.is_empty() {
+        return Err(ParserError::EmptyInput);
+    }
+    let mut iter = t.split(|c| *c == b'-' || *c == b'_').peekable();
+    parse_language_identifier_from_iter_with_options(&mut iter, false, options)
+}
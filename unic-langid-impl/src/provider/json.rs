@@ -0,0 +1,233 @@
+use super::{DataProvider, Subtags};
+use crate::{subtags, LanguageIdentifier};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{self, Display};
+
+/// Errors returned when loading cldr-json data into `JsonData`.
+#[derive(Debug)]
+pub enum DataError {
+    /// The input is not valid JSON.
+    Json(serde_json::Error),
+    /// The input lacks the named object, and is not the expected cldr-json file.
+    MissingField(&'static str),
+    /// A key or replacement which is not a well-formed language identifier.
+    InvalidEntry(String),
+}
+
+impl From<serde_json::Error> for DataError {
+    fn from(error: serde_json::Error) -> Self {
+        DataError::Json(error)
+    }
+}
+
+impl Error for DataError {}
+
+impl Display for DataError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Json(e) => write!(f, "Invalid JSON: {}", e),
+            Self::MissingField(name) => write!(f, "Missing \"{}\" object", name),
+            Self::InvalidEntry(entry) => write!(f, "Invalid entry \"{}\"", entry),
+        }
+    }
+}
+
+/// Likely subtags and alias data loaded at runtime from the cldr-json
+/// `likelySubtags.json` and `aliases.json` supplemental files.
+///
+/// Each file may be loaded at startup, replacing the compiled-in data without
+/// recompiling the binary. Tables which were not loaded are empty.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::LanguageIdentifier;
+/// use unic_langid_impl::provider::JsonData;
+///
+/// let mut data = JsonData::new();
+/// data.load_likely_subtags(r#"{
+///     "supplemental": {
+///         "version": { "_cldrVersion": "44" },
+///         "likelySubtags": { "sr": "sr-Cyrl-RS", "und-Latn-RS": "sr-Latn-RS" }
+///     }
+/// }"#).expect("Loading failed.");
+///
+/// assert_eq!(data.cldr_version(), Some("44"));
+///
+/// let mut li: LanguageIdentifier = "sr".parse()
+///     .expect("Parsing failed.");
+/// assert_eq!(li.maximize_with_provider(&data), true);
+/// assert_eq!(li.to_string(), "sr-Cyrl-RS");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct JsonData {
+    likely_subtags: HashMap<Subtags, Subtags>,
+    language_aliases: HashMap<subtags::Language, Subtags>,
+    script_aliases: HashMap<subtags::Script, subtags::Script>,
+    region_aliases: HashMap<subtags::Region, Vec<subtags::Region>>,
+    cldr_version: Option<String>,
+}
+
+fn get<'a>(value: &'a Value, name: &'static str) -> Result<&'a Value, DataError> {
+    value.get(name).ok_or(DataError::MissingField(name))
+}
+
+fn get_object<'a>(
+    value: &'a Value,
+    name: &'static str,
+) -> Result<&'a serde_json::Map<String, Value>, DataError> {
+    get(value, name)?
+        .as_object()
+        .ok_or(DataError::MissingField(name))
+}
+
+fn parse_subtags(input: &str) -> Result<Subtags, DataError> {
+    let li = LanguageIdentifier::from_bytes(input.as_bytes())
+        .map_err(|_| DataError::InvalidEntry(input.to_string()))?;
+    Ok((li.language, li.script, li.region))
+}
+
+fn replacement(value: &Value) -> Option<&str> {
+    value.get("_replacement")?.as_str()
+}
+
+impl JsonData {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads the likely subtags from the contents of `likelySubtags.json`,
+    /// replacing any previously loaded ones.
+    pub fn load_likely_subtags(&mut self, json: &str) -> Result<(), DataError> {
+        let value: Value = serde_json::from_str(json)?;
+        let supplemental = get(&value, "supplemental")?;
+
+        let mut likely_subtags = HashMap::new();
+        for (key, value) in get_object(supplemental, "likelySubtags")? {
+            let value = value
+                .as_str()
+                .ok_or_else(|| DataError::InvalidEntry(key.clone()))?;
+            likely_subtags.insert(parse_subtags(key)?, parse_subtags(value)?);
+        }
+
+        self.likely_subtags = likely_subtags;
+        self.cldr_version = supplemental
+            .get("version")
+            .and_then(|v| v.get("_cldrVersion"))
+            .and_then(Value::as_str)
+            .map(String::from);
+        Ok(())
+    }
+
+    /// Loads the language, script and region aliases from the contents of
+    /// `aliases.json`, replacing any previously loaded ones.
+    ///
+    /// Aliases of multiple subtags, such as `sgn-BR`, are skipped, and regions which
+    /// were split into several keep all of their successors, such as `RU` and `AM`
+    /// for `SU`.
+    pub fn load_aliases(&mut self, json: &str) -> Result<(), DataError> {
+        let value: Value = serde_json::from_str(json)?;
+        let alias = get(get(get(&value, "supplemental")?, "metadata")?, "alias")?;
+
+        let mut language_aliases = HashMap::new();
+        for (key, value) in get_object(alias, "languageAlias")? {
+            if let (Ok(lang), Some(replacement)) = (
+                subtags::Language::from_bytes(key.as_bytes()),
+                replacement(value),
+            ) {
+                language_aliases.insert(lang, parse_subtags(replacement)?);
+            }
+        }
+
+        let mut script_aliases = HashMap::new();
+        for (key, value) in get_object(alias, "scriptAlias")? {
+            if let (Ok(script), Some(replacement)) = (
+                subtags::Script::from_bytes(key.as_bytes()),
+                replacement(value),
+            ) {
+                let replacement = subtags::Script::from_bytes(replacement.as_bytes())
+                    .map_err(|_| DataError::InvalidEntry(replacement.to_string()))?;
+                script_aliases.insert(script, replacement);
+            }
+        }
+
+        let mut region_aliases = HashMap::new();
+        for (key, value) in get_object(alias, "territoryAlias")? {
+            if let (Ok(region), Some(replacement)) = (
+                subtags::Region::from_bytes(key.as_bytes()),
+                replacement(value),
+            ) {
+                let successors = replacement
+                    .split(' ')
+                    .map(|r| subtags::Region::from_bytes(r.as_bytes()))
+                    .collect::<Result<_, _>>()
+                    .map_err(|_| DataError::InvalidEntry(replacement.to_string()))?;
+                region_aliases.insert(region, successors);
+            }
+        }
+
+        self.language_aliases = language_aliases;
+        self.script_aliases = script_aliases;
+        self.region_aliases = region_aliases;
+        Ok(())
+    }
+
+    /// Returns the CLDR version of the loaded likely subtags, if the file had one.
+    pub fn cldr_version(&self) -> Option<&str> {
+        self.cldr_version.as_deref()
+    }
+}
+
+impl DataProvider for JsonData {
+    fn maximize(
+        &self,
+        lang: subtags::Language,
+        script: Option<subtags::Script>,
+        region: Option<subtags::Region>,
+    ) -> Option<Subtags> {
+        if !lang.is_empty() && script.is_some() && region.is_some() {
+            return None;
+        }
+        let und = subtags::Language::default();
+        let get = |key: Subtags| self.likely_subtags.get(&key).copied();
+
+        if !lang.is_empty() {
+            if let Some(r) = region {
+                if let Some(result) = get((lang, None, Some(r))) {
+                    return Some(result);
+                }
+            }
+            if let Some(s) = script {
+                if let Some(result) = get((lang, Some(s), None)) {
+                    return Some(result);
+                }
+            }
+            let (l, s, r) = get((lang, None, None))?;
+            Some((l, script.or(s), region.or(r)))
+        } else if let Some(s) = script {
+            if let Some(r) = region {
+                if let Some(result) = get((und, Some(s), Some(r))) {
+                    return Some(result);
+                }
+            }
+            let (l, s, r) = get((und, Some(s), None))?;
+            Some((l, s, region.or(r)))
+        } else {
+            get((und, None, Some(region?)))
+        }
+    }
+
+    fn language_alias(&self, lang: subtags::Language) -> Option<Subtags> {
+        self.language_aliases.get(&lang).copied()
+    }
+
+    fn script_alias(&self, script: subtags::Script) -> Option<subtags::Script> {
+        self.script_aliases.get(&script).copied()
+    }
+
+    fn region_alias(&self, region: subtags::Region) -> Option<Vec<subtags::Region>> {
+        self.region_aliases.get(&region).cloned()
+    }
+}
@@ -0,0 +1,92 @@
+//! Sources of the locale data used by `LanguageIdentifier::maximize_with_provider`
+//! and `LanguageIdentifier::canonicalize_with_provider`.
+//!
+//! The data compiled into the crate with the `likelysubtags` and `aliases` features
+//! is available as [`CompiledData`], while the `runtime_data` feature adds [`JsonData`], which
+//! loads the tables from cldr-json files at runtime.
+#[cfg(feature = "runtime_data")]
+mod json;
+
+#[cfg(feature = "runtime_data")]
+pub use json::{DataError, JsonData};
+
+use crate::subtags;
+
+/// The language, script and region subtags of a language identifier.
+pub type Subtags = (
+    subtags::Language,
+    Option<subtags::Script>,
+    Option<subtags::Region>,
+);
+
+/// A source of likely subtags and alias data.
+pub trait DataProvider {
+    /// Returns the subtags with the likely ones added, following the same lookup
+    /// as `likelysubtags::maximize`, or `None` if there is no data for them.
+    fn maximize(
+        &self,
+        lang: subtags::Language,
+        script: Option<subtags::Script>,
+        region: Option<subtags::Region>,
+    ) -> Option<Subtags>;
+
+    /// Returns the replacement of a deprecated language subtag, such as `he` for `iw`.
+    ///
+    /// The replacement may add a script or region, as `sr-Latn` for `sh`.
+    fn language_alias(&self, _lang: subtags::Language) -> Option<Subtags> {
+        None
+    }
+
+    /// Returns the replacement of a deprecated script subtag, such as `Zinh` for `Qaai`.
+    fn script_alias(&self, _script: subtags::Script) -> Option<subtags::Script> {
+        None
+    }
+
+    /// Returns the successors of a deprecated region subtag, such as `MM` for `BU`.
+    ///
+    /// A region which was split into several, such as `SU`, has all of its
+    /// successors, the default one first.
+    fn region_alias(&self, _region: subtags::Region) -> Option<Vec<subtags::Region>> {
+        None
+    }
+}
+
+/// The likely subtags and alias data compiled into the crate.
+///
+/// The likely subtags are only available with the `likelysubtags` feature, and
+/// the aliases with the `aliases` feature. Without them, nothing is maximized or
+/// replaced by aliases.
+#[cfg(any(feature = "likelysubtags", feature = "aliases"))]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct CompiledData;
+
+#[cfg(any(feature = "likelysubtags", feature = "aliases"))]
+impl DataProvider for CompiledData {
+    #[cfg_attr(not(feature = "likelysubtags"), allow(unused_variables))]
+    fn maximize(
+        &self,
+        lang: subtags::Language,
+        script: Option<subtags::Script>,
+        region: Option<subtags::Region>,
+    ) -> Option<Subtags> {
+        #[cfg(feature = "likelysubtags")]
+        return crate::likelysubtags::maximize(lang, script, region);
+        #[cfg(not(feature = "likelysubtags"))]
+        None
+    }
+
+    #[cfg(feature = "aliases")]
+    fn language_alias(&self, lang: subtags::Language) -> Option<Subtags> {
+        crate::aliases::language_alias(lang)
+    }
+
+    #[cfg(feature = "aliases")]
+    fn script_alias(&self, script: subtags::Script) -> Option<subtags::Script> {
+        crate::aliases::script_alias(script)
+    }
+
+    #[cfg(feature = "aliases")]
+    fn region_alias(&self, region: subtags::Region) -> Option<Vec<subtags::Region>> {
+        crate::aliases::region_alias(region)
+    }
+}
@@ -9,10 +9,20 @@ pub struct Language(Option<TinyStr8>);
 
 impl Language {
     pub fn from_bytes(v: &[u8]) -> Result<Self, ParserError> {
+        Self::parse(v, false)
+    }
+
+    /// Like `from_bytes`, but also accepts the 4-letter primary language
+    /// subtags reserved by BCP47 for future use.
+    pub fn from_bytes_allow_reserved(v: &[u8]) -> Result<Self, ParserError> {
+        Self::parse(v, true)
+    }
+
+    fn parse(v: &[u8], allow_reserved: bool) -> Result<Self, ParserError> {
         let slen = v.len();
 
         let s = TinyStr8::from_bytes(v).map_err(|_| ParserError::InvalidLanguage)?;
-        if slen < 2 || slen > 8 || slen == 4 || !s.is_ascii_alphabetic() {
+        if !(2..=8).contains(&slen) || (slen == 4 && !allow_reserved) || !s.is_ascii_alphabetic() {
             return Err(ParserError::InvalidLanguage);
         }
 
@@ -25,10 +35,33 @@ impl Language {
         }
     }
 
+    /// Returns the input in the canonical casing of this subtag, without validating it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Language;
+    ///
+    /// assert_eq!(Language::canonical_case("EN"), "en");
+    /// ```
+    pub fn canonical_case(v: &str) -> String {
+        super::to_language_case(v)
+    }
+
     pub fn as_str(&self) -> &str {
         self.0.as_deref().unwrap_or("und")
     }
 
+    pub fn as_opt_str(&self) -> Option<&str> {
+        self.0.as_deref()
+    }
+
+    /// Returns `true` for a 4-letter primary language subtag, which is
+    /// well-formed but reserved by BCP47 for future use.
+    pub fn is_reserved(&self) -> bool {
+        self.0.is_some_and(|l| l.len() == 4)
+    }
+
     /// # Safety
     ///
     /// This function accepts any u64 that is exected to be a valid
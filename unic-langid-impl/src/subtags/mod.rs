@@ -1,3 +1,4 @@
+mod iso15924;
 mod language;
 mod region;
 mod script;
@@ -7,3 +8,127 @@ pub use language::Language;
 pub use region::Region;
 pub use script::Script;
 pub use variant::Variant;
+
+/// Returns `true` if the bytes are a well-formed language subtag, such as `en` or `und`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::subtags::is_valid_language;
+///
+/// assert!(is_valid_language(b"EN"));
+/// assert!(!is_valid_language(b"e1"));
+/// ```
+pub fn is_valid_language(v: &[u8]) -> bool {
+    Language::from_bytes(v).is_ok()
+}
+
+/// Returns `true` if the bytes are a well-formed script subtag, such as `Latn`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::subtags::is_valid_script;
+///
+/// assert!(is_valid_script(b"latn"));
+/// assert!(!is_valid_script(b"Lat"));
+/// ```
+pub fn is_valid_script(v: &[u8]) -> bool {
+    Script::from_bytes(v).is_ok()
+}
+
+/// Returns `true` if the bytes are a well-formed region subtag, such as `US` or `419`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::subtags::is_valid_region;
+///
+/// assert!(is_valid_region(b"us"));
+/// assert!(is_valid_region(b"419"));
+/// assert!(!is_valid_region(b"U5"));
+/// ```
+pub fn is_valid_region(v: &[u8]) -> bool {
+    Region::from_bytes(v).is_ok()
+}
+
+/// Returns `true` if the bytes are a well-formed variant subtag, such as `valencia`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::subtags::is_valid_variant;
+///
+/// assert!(is_valid_variant(b"valencia"));
+/// assert!(is_valid_variant(b"1996"));
+/// assert!(!is_valid_variant(b"foo"));
+/// ```
+pub fn is_valid_variant(v: &[u8]) -> bool {
+    Variant::from_bytes(v).is_ok()
+}
+
+/// Returns the subtag in the canonical casing of a language subtag, lowercase.
+///
+/// The input is not validated, so the result is well-formed only if the input
+/// is a well-formed language subtag in any casing.
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::subtags::to_language_case;
+///
+/// assert_eq!(to_language_case("EN"), "en");
+/// ```
+pub fn to_language_case(v: &str) -> String {
+    v.to_ascii_lowercase()
+}
+
+/// Returns the subtag in the canonical casing of a script subtag, titlecase.
+///
+/// The input is not validated, as with [`to_language_case`].
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::subtags::to_script_case;
+///
+/// assert_eq!(to_script_case("lATN"), "Latn");
+/// ```
+pub fn to_script_case(v: &str) -> String {
+    let mut result = v.to_ascii_lowercase();
+    if let Some(first) = result.get_mut(..1) {
+        first.make_ascii_uppercase();
+    }
+    result
+}
+
+/// Returns the subtag in the canonical casing of a region subtag, uppercase.
+///
+/// The input is not validated, as with [`to_language_case`].
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::subtags::to_region_case;
+///
+/// assert_eq!(to_region_case("us"), "US");
+/// assert_eq!(to_region_case("419"), "419");
+/// ```
+pub fn to_region_case(v: &str) -> String {
+    v.to_ascii_uppercase()
+}
+
+/// Returns the subtag in the canonical casing of a variant subtag, lowercase.
+///
+/// The input is not validated, as with [`to_language_case`].
+///
+/// # Examples
+///
+/// ```
+/// use unic_langid_impl::subtags::to_variant_case;
+///
+/// assert_eq!(to_variant_case("MacOS"), "macos");
+/// ```
+pub fn to_variant_case(v: &str) -> String {
+    v.to_ascii_lowercase()
+}
@@ -28,6 +28,19 @@ impl Region {
         }
     }
 
+    /// Returns the input in the canonical casing of this subtag, without validating it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Region;
+    ///
+    /// assert_eq!(Region::canonical_case("us"), "US");
+    /// ```
+    pub fn canonical_case(v: &str) -> String {
+        super::to_region_case(v)
+    }
+
     pub fn as_str(&self) -> &str {
         self.0.as_str()
     }
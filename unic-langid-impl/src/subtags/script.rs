@@ -1,3 +1,4 @@
+use super::iso15924::SCRIPT_NUMERIC;
 use crate::parser::errors::ParserError;
 use std::str::FromStr;
 use tinystr::TinyStr4;
@@ -16,6 +17,49 @@ impl Script {
         Ok(Self(s.to_ascii_titlecase()))
     }
 
+    /// Returns the input in the canonical casing of this subtag, without validating it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Script;
+    ///
+    /// assert_eq!(Script::canonical_case("lATN"), "Latn");
+    /// ```
+    pub fn canonical_case(v: &str) -> String {
+        super::to_script_case(v)
+    }
+
+    /// Returns the script of an ISO 15924 numeric code, such as `Latn` for `215`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Script;
+    ///
+    /// let script = Script::from_numeric(215).expect("The code is known.");
+    /// assert_eq!(script.as_str(), "Latn");
+    /// assert_eq!(script.to_numeric(), Some(215));
+    ///
+    /// assert_eq!(Script::from_numeric(1), None);
+    /// ```
+    pub fn from_numeric(code: u16) -> Option<Self> {
+        SCRIPT_NUMERIC
+            .iter()
+            .find(|(_, numeric)| *numeric == code)
+            .map(|(alpha, _)| {
+                Self::from_bytes(alpha.as_bytes()).expect("Table entries are well-formed.")
+            })
+    }
+
+    /// Returns the ISO 15924 numeric code of the script, if it is known.
+    pub fn to_numeric(&self) -> Option<u16> {
+        SCRIPT_NUMERIC
+            .binary_search_by_key(&self.as_str(), |(alpha, _)| alpha)
+            .ok()
+            .map(|idx| SCRIPT_NUMERIC[idx].1)
+    }
+
     pub fn as_str(&self) -> &str {
         self.0.as_str()
     }
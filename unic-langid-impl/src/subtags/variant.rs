@@ -9,7 +9,7 @@ impl Variant {
     pub fn from_bytes(v: &[u8]) -> Result<Self, ParserError> {
         let slen = v.len();
 
-        if slen < 4 || slen > 8 {
+        if !(4..=8).contains(&slen) {
             return Err(ParserError::InvalidSubtag);
         }
 
@@ -26,6 +26,19 @@ impl Variant {
         Ok(Self(s.to_ascii_lowercase()))
     }
 
+    /// Returns the input in the canonical casing of this subtag, without validating it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_langid_impl::subtags::Variant;
+    ///
+    /// assert_eq!(Variant::canonical_case("MacOS"), "macos");
+    /// ```
+    pub fn canonical_case(v: &str) -> String {
+        super::to_variant_case(v)
+    }
+
     pub fn as_str(&self) -> &str {
         self.0.as_str()
     }
@@ -0,0 +1,86 @@
+use unic_langid_impl::aliases;
+use unic_langid_impl::LanguageIdentifier;
+
+fn canonicalized(input: &str) -> (String, bool) {
+    let mut li: LanguageIdentifier = input.parse().unwrap();
+    let changed = li.canonicalize_aliases();
+    (li.to_string(), changed)
+}
+
+#[test]
+fn test_canonicalize_aliases() {
+    for (input, expected, changed) in &[
+        ("iw", "he", true),
+        ("in-ID", "id-ID", true),
+        ("mo", "ro", true),
+        ("sh", "sr-Latn", true),
+        ("sh-Cyrl-BA", "sr-Cyrl-BA", true),
+        ("swc", "sw-CD", true),
+        ("prs-IR", "fa-IR", true),
+        ("my-BU", "my-MM", true),
+        ("de-DD", "de-DE", true),
+        ("und-Qaai", "und-Zinh", true),
+        ("en-UK", "en-GB", true),
+        ("zh-guoyu", "zh", true),
+        ("zh-hakka", "hak", true),
+        ("sgn-BR", "bzs", true),
+        ("aar", "aa", true),
+        ("fa-004", "fa-AF", true),
+        ("ru-SU", "ru-RU", true),
+        ("en-US", "en-US", false),
+        ("und", "und", false),
+        ("zh-TW-guoyu", "zh-TW-guoyu", false),
+    ] {
+        assert_eq!(
+            canonicalized(input),
+            (expected.to_string(), *changed),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_split_regions() {
+    // The likely region of the language is picked among the successors, and
+    // the first successor otherwise.
+    #[cfg(feature = "likelysubtags")]
+    let expected = [
+        ("hy-SU", "hy-AM"),
+        ("uk-SU", "uk-UA"),
+        ("sh-YU", "sr-Latn-RS"),
+        ("az-SU", "az-AZ"),
+        ("ja-SU", "ja-RU"),
+    ];
+    #[cfg(not(feature = "likelysubtags"))]
+    let expected = [("hy-SU", "hy-RU"), ("sh-YU", "sr-Latn-RS")];
+
+    for (input, expected) in &expected {
+        assert_eq!(
+            canonicalized(input),
+            (expected.to_string(), true),
+            "{}",
+            input
+        );
+    }
+
+    assert_eq!(
+        aliases::region_alias("CS".parse().unwrap()),
+        Some(vec!["RS".parse().unwrap(), "ME".parse().unwrap()])
+    );
+}
+
+#[test]
+fn test_legacy_tags() {
+    assert_eq!(aliases::legacy_tag("i-klingon"), Some("tlh"));
+    assert_eq!(aliases::legacy_tag("EN_gb-OED"), Some("en-GB-oxendict"));
+    assert_eq!(aliases::legacy_tag("zh-min-nan"), Some("nan"));
+    assert_eq!(aliases::legacy_tag("sgn-BR"), Some("bzs"));
+    assert_eq!(aliases::legacy_tag("en-US"), None);
+
+    // Every alias is well-formed.
+    for tag in &["art-lojban", "i-ami", "sgn-be-fr", "no-bok", "zh-cmn-hant"] {
+        let replacement = aliases::legacy_tag(tag).unwrap();
+        assert!(replacement.parse::<LanguageIdentifier>().is_ok(), "{}", tag);
+    }
+}
@@ -0,0 +1,45 @@
+use unic_langid_impl::iso3166;
+use unic_langid_impl::subtags::Region;
+use unic_langid_impl::{LanguageIdentifier, ParserOptions};
+
+fn region(s: &str) -> Region {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_alpha3() {
+    assert_eq!(iso3166::from_alpha3(b"USA"), Some(region("US")));
+    assert_eq!(iso3166::from_alpha3(b"deu"), Some(region("DE")));
+    assert_eq!(iso3166::from_alpha3(b"XYZ"), None);
+    assert_eq!(iso3166::from_alpha3(b"US"), None);
+
+    assert_eq!(iso3166::to_alpha3(region("GB")), Some("GBR"));
+    assert_eq!(iso3166::to_alpha3(region("ZW")), Some("ZWE"));
+    assert_eq!(iso3166::to_alpha3(region("419")), None);
+    assert_eq!(iso3166::to_alpha3(region("XK")), None);
+
+    assert_eq!(iso3166::entries().len(), 249);
+    assert!(iso3166::entries()
+        .all(|(alpha2, alpha3)| iso3166::from_alpha3(alpha3.as_bytes()) == Some(alpha2)));
+}
+
+#[test]
+fn test_lenient_alpha3() {
+    let lenient = ParserOptions::default().lenient(true);
+
+    assert!(LanguageIdentifier::from_bytes(b"en-USA").is_err());
+
+    for (input, expected) in &[
+        ("en-USA", "en-US"),
+        ("de_deu", "de-DE"),
+        ("sr-Latn-SRB", "sr-Latn-RS"),
+        ("es-419", "es-419"),
+        ("en-US", "en-US"),
+    ] {
+        let langid =
+            LanguageIdentifier::from_bytes_with_options(input.as_bytes(), lenient).expect(input);
+        assert_eq!(langid.to_string(), *expected);
+    }
+
+    assert!(LanguageIdentifier::from_bytes_with_options(b"en-XYZ", lenient).is_err());
+}
@@ -0,0 +1,67 @@
+use unic_langid_impl::canonicalize;
+use unic_langid_impl::iso639;
+use unic_langid_impl::subtags::Language;
+use unic_langid_impl::{LanguageIdentifier, ParserOptions};
+
+fn lang(s: &str) -> Language {
+    s.parse().unwrap()
+}
+
+#[test]
+fn test_to_iso639_1() {
+    assert_eq!(iso639::to_iso639_1(lang("eng")), Some(lang("en")));
+    assert_eq!(iso639::to_iso639_1(lang("deu")), Some(lang("de")));
+    assert_eq!(iso639::to_iso639_1(lang("zho")), Some(lang("zh")));
+    assert_eq!(iso639::to_iso639_1(lang("yue")), None);
+    assert_eq!(iso639::to_iso639_1(lang("en")), None);
+    assert_eq!(iso639::to_iso639_1(lang("und")), None);
+}
+
+#[test]
+fn test_to_iso639_3() {
+    assert_eq!(iso639::to_iso639_3(lang("en")), Some(lang("eng")));
+    assert_eq!(iso639::to_iso639_3(lang("kw")), Some(lang("cor")));
+    assert_eq!(iso639::to_iso639_3(lang("zu")), Some(lang("zul")));
+    assert_eq!(iso639::to_iso639_3(lang("yue")), Some(lang("yue")));
+    assert_eq!(iso639::to_iso639_3(lang("und")), None);
+    assert_eq!(iso639::to_iso639_3(lang("sgnusa")), None);
+}
+
+#[test]
+fn test_canonicalize_iso639() {
+    let mut language = lang("fra");
+    assert!(iso639::canonicalize_language(&mut language));
+    assert_eq!(language, lang("fr"));
+    assert!(!iso639::canonicalize_language(&mut language));
+
+    assert_eq!(canonicalize("eng-US"), Ok("en-US".to_string()));
+    assert_eq!(canonicalize("DEU_at"), Ok("de-AT".to_string()));
+    assert_eq!(canonicalize("yue-HK"), Ok("yue-HK".to_string()));
+}
+
+#[test]
+fn test_bibliographic() {
+    assert_eq!(iso639::from_bibliographic(lang("ger")), Some(lang("de")));
+    assert_eq!(iso639::from_bibliographic(lang("fre")), Some(lang("fr")));
+    assert_eq!(iso639::from_bibliographic(lang("chi")), Some(lang("zh")));
+    assert_eq!(iso639::from_bibliographic(lang("deu")), None);
+    assert_eq!(iso639::from_bibliographic(lang("de")), None);
+
+    assert_eq!(canonicalize("ger-AT"), Ok("de-AT".to_string()));
+    assert_eq!(canonicalize("CHI_hant_tw"), Ok("zh-Hant-TW".to_string()));
+}
+
+#[test]
+fn test_lenient_bibliographic() {
+    let lenient = ParserOptions::default().lenient(true);
+
+    let langid = LanguageIdentifier::from_bytes(b"fre-CA").unwrap();
+    assert_eq!(langid.to_string(), "fre-CA");
+
+    let langid = LanguageIdentifier::from_bytes_with_options(b"fre-CA", lenient).unwrap();
+    assert_eq!(langid.to_string(), "fr-CA");
+
+    // Only bibliographic codes are replaced when parsing.
+    let langid = LanguageIdentifier::from_bytes_with_options(b"fra-CA", lenient).unwrap();
+    assert_eq!(langid.to_string(), "fra-CA");
+}
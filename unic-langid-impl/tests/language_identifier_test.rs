@@ -1,7 +1,13 @@
-use unic_langid_impl::parser::parse_language_identifier;
+#![allow(clippy::bool_assert_comparison)]
+
+use unic_langid_impl::parser::{parse_language_identifier, ParserError};
 use unic_langid_impl::subtags;
+#[cfg(feature = "character_direction")]
 use unic_langid_impl::CharacterDirection;
 use unic_langid_impl::LanguageIdentifier;
+use unic_langid_impl::ParserOptions;
+#[cfg(feature = "character_direction")]
+use unic_langid_impl::LAYOUT_CLDR_VERSION;
 
 fn assert_language_identifier(
     loc: &LanguageIdentifier,
@@ -49,6 +55,23 @@ fn test_language_identifier_parser() {
     assert_parsed_language_identifier("sl-nedis", Some("sl"), None, None, Some(&["nedis"]));
 }
 
+#[test]
+fn test_empty_input() {
+    assert_eq!(parse_language_identifier(b""), Err(ParserError::EmptyInput));
+    assert_eq!(
+        LanguageIdentifier::from_bytes_with_options(b"", ParserOptions::default()),
+        Err(ParserError::EmptyInput.into())
+    );
+    assert_eq!(
+        "".parse::<LanguageIdentifier>().unwrap_err().to_string(),
+        "Parser error: The input is empty"
+    );
+    assert_eq!(
+        parse_language_identifier(b"-"),
+        Err(ParserError::InvalidLanguage)
+    );
+}
+
 #[test]
 fn test_language_casing() {
     assert_parsed_language_identifier("Pl", Some("pl"), None, None, None);
@@ -75,6 +98,11 @@ fn test_sorted_variants() {
         &["nedis".parse().unwrap(), "macos".parse().unwrap()],
     );
     assert_eq!(&langid.to_string(), "en-macos-nedis");
+    let variants: Vec<&str> = langid.variants_slice().iter().map(|v| v.as_str()).collect();
+    assert_eq!(variants, vec!["macos", "nedis"]);
+
+    let langid: LanguageIdentifier = "en".parse().unwrap();
+    assert!(langid.variants_slice().is_empty());
 }
 
 #[test]
@@ -102,6 +130,37 @@ fn test_matches() {
     assert_eq!(langid_en.matches(&langid_en_us, true, false), true);
 }
 
+#[test]
+fn test_presence() {
+    let und = LanguageIdentifier::default();
+    assert_eq!(und.presence(), 0);
+
+    let langid: LanguageIdentifier = "sr-Latn-RS-ekavsk".parse().unwrap();
+    assert_eq!(
+        langid.presence(),
+        LanguageIdentifier::PRESENCE_SCRIPT
+            | LanguageIdentifier::PRESENCE_REGION
+            | LanguageIdentifier::PRESENCE_VARIANTS
+    );
+
+    let mut langid2: LanguageIdentifier = "sr-RS-ekavsk".parse().unwrap();
+    assert_ne!(langid.presence(), langid2.presence());
+    assert_ne!(langid, langid2);
+    assert!(!langid.matches(&langid2, false, false));
+    assert!(langid2.matches(&langid, true, false));
+
+    langid2.script = langid.script;
+    assert_eq!(langid.presence(), langid2.presence());
+    assert_eq!(langid, langid2);
+
+    langid2.clear_variants();
+    assert_eq!(
+        langid2.presence() & LanguageIdentifier::PRESENCE_VARIANTS,
+        0
+    );
+    assert_ne!(langid, langid2);
+}
+
 #[test]
 fn test_set_fields() {
     let mut langid = LanguageIdentifier::default();
@@ -122,8 +181,11 @@ fn test_set_fields() {
     assert_eq!(langid.has_variant("macos".parse().unwrap()), true);
     assert_eq!(langid.has_variant("windows".parse().unwrap()), false);
 
+    assert_eq!(langid.language_opt(), Some("de"));
     langid.language.clear();
     assert_eq!(&langid.to_string(), "und-Latn-AT-macos");
+    assert_eq!(langid.language_opt(), None);
+    assert_eq!(langid.language.as_opt_str(), None);
     langid.region = None;
     assert_eq!(&langid.to_string(), "und-Latn-macos");
     langid.script = None;
@@ -134,6 +196,25 @@ fn test_set_fields() {
     assert_eq!(langid.has_variant("macos".parse().unwrap()), false);
 }
 
+#[test]
+fn test_reserved_language() {
+    let options = ParserOptions::default().allow_reserved_language(true);
+
+    assert!(parse_language_identifier(b"abcd").is_err());
+    assert!(subtags::Language::from_bytes(b"abcd").is_err());
+
+    let langid = LanguageIdentifier::from_bytes_with_options(b"ABCD-latn-us", options).unwrap();
+    assert_eq!(langid.language.as_str(), "abcd");
+    assert_eq!(&langid.to_string(), "abcd-Latn-US");
+    assert!(langid.language.is_reserved());
+
+    let langid = LanguageIdentifier::from_bytes_with_options(b"en-US", options).unwrap();
+    assert!(!langid.language.is_reserved());
+
+    assert!(LanguageIdentifier::from_bytes_with_options(b"abc1", options).is_err());
+    assert!(LanguageIdentifier::from_bytes_with_options(b"a", options).is_err());
+}
+
 #[test]
 fn test_matches_as_range() {
     let langid: LanguageIdentifier = "en-US".parse().unwrap();
@@ -145,11 +226,31 @@ fn test_matches_as_range() {
 }
 
 #[test]
+#[cfg(feature = "character_direction")]
 fn test_character_direction() {
+    assert_eq!(LAYOUT_CLDR_VERSION, "37");
+
     let langid: LanguageIdentifier = "en-US".parse().unwrap();
     let langid2: LanguageIdentifier = "ar-AF".parse().unwrap();
     assert_eq!(langid.character_direction(), CharacterDirection::LTR);
     assert_eq!(langid2.character_direction(), CharacterDirection::RTL);
+
+    for (input, expected) in &[
+        ("az-Arab", CharacterDirection::RTL),
+        ("pa-Arab", CharacterDirection::RTL),
+        ("yi-Hebr", CharacterDirection::RTL),
+        ("dv-Thaa", CharacterDirection::RTL),
+        ("ff-Adlm", CharacterDirection::RTL),
+        ("und-Syrc", CharacterDirection::RTL),
+        ("az", CharacterDirection::LTR),
+        ("pa", CharacterDirection::LTR),
+        ("ar-Latn", CharacterDirection::LTR),
+        ("uz-Cyrl", CharacterDirection::LTR),
+        ("he", CharacterDirection::RTL),
+    ] {
+        let langid: LanguageIdentifier = input.parse().unwrap();
+        assert_eq!(&langid.character_direction(), expected, "{}", input);
+    }
 }
 
 #[test]
@@ -190,3 +291,113 @@ fn test_langid_ord() {
         ]
     );
 }
+
+#[test]
+fn test_is_valid_subtag() {
+    assert!(subtags::is_valid_language(b"en"));
+    assert!(subtags::is_valid_language(b"und"));
+    assert!(!subtags::is_valid_language(b"e"));
+    assert!(!subtags::is_valid_language(b"en1"));
+
+    assert!(subtags::is_valid_script(b"Latn"));
+    assert!(subtags::is_valid_script(b"latn"));
+    assert!(!subtags::is_valid_script(b"Lat"));
+
+    assert!(subtags::is_valid_region(b"US"));
+    assert!(subtags::is_valid_region(b"419"));
+    assert!(!subtags::is_valid_region(b"U"));
+    assert!(!subtags::is_valid_region(b"41"));
+
+    assert!(subtags::is_valid_variant(b"macos"));
+    assert!(subtags::is_valid_variant(b"1996"));
+    assert!(!subtags::is_valid_variant(b"mac"));
+    assert!(!subtags::is_valid_variant(b""));
+}
+
+#[test]
+fn test_canonical_case() {
+    assert_eq!(subtags::to_language_case("SR"), "sr");
+    assert_eq!(subtags::to_script_case("latn"), "Latn");
+    assert_eq!(subtags::to_script_case("LATN"), "Latn");
+    assert_eq!(subtags::to_script_case(""), "");
+    assert_eq!(subtags::to_region_case("rs"), "RS");
+    assert_eq!(subtags::to_variant_case("EKAVSK"), "ekavsk");
+
+    assert_eq!(subtags::Language::canonical_case("Sr"), "sr");
+    assert_eq!(subtags::Script::canonical_case("cYRL"), "Cyrl");
+    assert_eq!(subtags::Region::canonical_case("Rs"), "RS");
+    assert_eq!(subtags::Variant::canonical_case("Ekavsk"), "ekavsk");
+
+    let id: LanguageIdentifier = "SR-lATN-rs-EKAVSK".parse().unwrap();
+    assert_eq!(
+        id.to_string(),
+        [
+            subtags::to_language_case("SR"),
+            subtags::to_script_case("lATN"),
+            subtags::to_region_case("rs"),
+            subtags::to_variant_case("EKAVSK"),
+        ]
+        .join("-")
+    );
+}
+
+#[test]
+fn test_script_numeric() {
+    for (alpha, numeric) in &[("Latn", 215), ("Cyrl", 220), ("Arab", 160), ("Hant", 502)] {
+        let script: subtags::Script = alpha.parse().unwrap();
+        assert_eq!(script.to_numeric(), Some(*numeric));
+        assert_eq!(subtags::Script::from_numeric(*numeric), Some(script));
+    }
+    assert_eq!(subtags::Script::from_numeric(0), None);
+    assert_eq!(subtags::Script::from_numeric(1000), None);
+    assert_eq!(
+        "Qaaa".parse::<subtags::Script>().unwrap().to_numeric(),
+        None
+    );
+}
+
+#[test]
+fn test_lenient_script_numeric() {
+    let lenient = ParserOptions::default().lenient(true);
+
+    for (input, strict, expected) in &[
+        ("sr-220-RS", None, "sr-Cyrl-RS"),
+        ("sr-215-RS", None, "sr-Latn-RS"),
+        ("sr-215", Some("sr-215"), "sr-215"),
+        ("es-419", Some("es-419"), "es-419"),
+        ("en-840", Some("en-840"), "en-840"),
+        ("fr-250", Some("fr-250"), "fr-250"),
+        ("ko-410", Some("ko-410"), "ko-410"),
+        ("de-276", Some("de-276"), "de-276"),
+        ("de-276-1996", Some("de-276-1996"), "de-276-1996"),
+        ("zh-502-TW", None, "zh-Hant-TW"),
+    ] {
+        assert_eq!(
+            LanguageIdentifier::from_bytes(input.as_bytes())
+                .ok()
+                .map(|l| l.to_string())
+                .as_deref(),
+            *strict
+        );
+        let langid =
+            LanguageIdentifier::from_bytes_with_options(input.as_bytes(), lenient).expect(input);
+        assert_eq!(langid.to_string(), *expected);
+    }
+}
+
+#[test]
+fn test_serialize_without_variants() {
+    for input in &[
+        "und",
+        "en",
+        "und-US",
+        "und-Latn",
+        "zh-Hant-TW",
+        "abcdefgh-Latn-419",
+        "abcdefgh-Latn-419-valencia",
+    ] {
+        let langid: LanguageIdentifier = input.parse().unwrap();
+        assert_eq!(&langid.to_string(), input);
+        assert_eq!(format!("<{}>", langid), format!("<{}>", input));
+    }
+}
@@ -1,5 +1,9 @@
-use unic_langid_impl::likelysubtags::{maximize, minimize, CLDR_VERSION};
+use unic_langid_impl::likelysubtags::{
+    has_likely_subtags, likely_for_region, maximize, minimize, minimize_with, MinimizeOptions,
+    CLDR_VERSION,
+};
 use unic_langid_impl::subtags;
+use unic_langid_impl::LanguageIdentifier;
 
 static STRINGS: &[(&str, Option<&str>)] = &[
     ("en-US", Some("en-Latn-US")),
@@ -95,7 +99,7 @@ fn maximize_test() {
 
 #[test]
 fn version_works() {
-    assert_eq!(CLDR_VERSION, "36");
+    assert_eq!(CLDR_VERSION, "37");
 }
 
 #[test]
@@ -111,3 +115,112 @@ fn minimize_test() {
     let result = minimize(lang, Some(script), Some(region));
     assert_eq!(result, Some(extract_input("en")));
 }
+
+#[test]
+fn has_likely_subtags_test() {
+    for i in STRINGS {
+        let chunks = extract_input(i.0);
+        assert!(has_likely_subtags(chunks.0, chunks.1, chunks.2), "{}", i.0);
+    }
+
+    for (input, expected) in &[
+        ("qaa", false),
+        ("qaa-Latn-US", false),
+        ("und", false),
+        ("und-ZZ", false),
+        ("und-Qaaa", false),
+        ("und-Cyrl", true),
+    ] {
+        let chunks = extract_input(input);
+        assert_eq!(
+            has_likely_subtags(chunks.0, chunks.1, chunks.2),
+            *expected,
+            "{}",
+            input
+        );
+    }
+
+    let mut langid: LanguageIdentifier = "qaa-US".parse().unwrap();
+    assert!(!langid.has_likely_subtags());
+    assert!(!langid.maximize());
+    assert!(!langid.minimize());
+}
+
+#[test]
+fn minimize_with_test() {
+    let lang = "zh".parse().unwrap();
+    let script = "Hant".parse().unwrap();
+    let region = "TW".parse().unwrap();
+    let result = minimize_with(lang, Some(script), Some(region), false);
+    assert_eq!(result, Some(extract_input("zh-TW")));
+    let result = minimize_with(lang, Some(script), Some(region), true);
+    assert_eq!(result, Some(extract_input("zh-Hant")));
+
+    // Only the region is enough, regardless of the preference.
+    let lang = "sr".parse().unwrap();
+    let script = "Latn".parse().unwrap();
+    let region = "ME".parse().unwrap();
+    let result = minimize_with(lang, Some(script), Some(region), true);
+    assert_eq!(result, Some(extract_input("sr-ME")));
+
+    for (input, options, expected) in &[
+        (
+            "zh-Hant-TW-pinyin",
+            MinimizeOptions::default(),
+            Some("zh-TW-pinyin"),
+        ),
+        (
+            "zh-Hant-TW-pinyin",
+            MinimizeOptions {
+                favor_script: true,
+                ..Default::default()
+            },
+            Some("zh-Hant-pinyin"),
+        ),
+        (
+            "zh-Hant-TW-pinyin",
+            MinimizeOptions {
+                variants_block: true,
+                ..Default::default()
+            },
+            None,
+        ),
+        (
+            "en-Latn-US",
+            MinimizeOptions {
+                variants_block: true,
+                ..Default::default()
+            },
+            Some("en"),
+        ),
+    ] {
+        let mut langid: LanguageIdentifier = input.parse().unwrap();
+        assert_eq!(
+            langid.minimize_with(*options),
+            expected.is_some(),
+            "{}",
+            input
+        );
+        assert_eq!(langid.to_string(), expected.unwrap_or(input), "{}", input);
+    }
+}
+
+#[test]
+fn test_likely_for_region() {
+    for (region, expected) in &[
+        ("DE", Some("de-Latn-DE")),
+        ("ch", Some("de-Latn-CH")),
+        ("RS", Some("sr-Cyrl-RS")),
+        ("TW", Some("zh-Hant-TW")),
+        ("419", Some("es-Latn-419")),
+        ("AA", None),
+    ] {
+        let region: subtags::Region = region.parse().unwrap();
+        assert_eq!(
+            likely_for_region(region).map(|li| li.to_string()),
+            expected.map(String::from),
+            "{}",
+            region
+        );
+    }
+}
@@ -0,0 +1,57 @@
+//! Run with the likely subtags data limited to English and French:
+//!
+//! ```sh
+//! UNIC_LANGID_LIKELYSUBTAGS_LANGUAGES=en,fr cargo test --features likelysubtags --test likelysubtags_subset
+//! ```
+//!
+//! Without the environment variable the full data is embedded, and there is nothing to check.
+use unic_langid_impl::likelysubtags::{has_likely_subtags, likely_for_region};
+use unic_langid_impl::LanguageIdentifier;
+
+const LANGUAGES: Option<&str> = option_env!("UNIC_LANGID_LIKELYSUBTAGS_LANGUAGES");
+
+#[test]
+fn test_subset() {
+    let languages = match LANGUAGES {
+        Some(languages) => languages,
+        None => return,
+    };
+    assert_eq!(
+        languages, "en,fr",
+        "The test expects the data limited to en,fr."
+    );
+
+    for (input, expected) in &[
+        ("en", true),
+        ("fr", true),
+        ("de", false),
+        ("ja", false),
+        ("zh", false),
+    ] {
+        let li: LanguageIdentifier = input.parse().unwrap();
+        assert_eq!(
+            has_likely_subtags(li.language, None, None),
+            *expected,
+            "{}",
+            input
+        );
+    }
+
+    for (input, expected) in &[
+        ("MC", Some("fr-Latn-MC")),
+        ("FR", Some("fr-Latn-FR")),
+        ("DE", None),
+        ("JP", None),
+    ] {
+        let li = likely_for_region(input.parse().unwrap()).map(|li| li.to_string());
+        assert_eq!(li.as_deref(), *expected, "{}", input);
+    }
+
+    let mut li: LanguageIdentifier = "en".parse().unwrap();
+    assert!(li.maximize());
+    assert_eq!(li.to_string(), "en-Latn-US");
+
+    let mut li: LanguageIdentifier = "de".parse().unwrap();
+    assert!(!li.maximize());
+    assert_eq!(li.to_string(), "de");
+}
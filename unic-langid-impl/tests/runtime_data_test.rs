@@ -0,0 +1,135 @@
+use unic_langid_impl::provider::{DataError, DataProvider, JsonData};
+use unic_langid_impl::LanguageIdentifier;
+
+static LIKELY_SUBTAGS: &str = r#"{
+    "supplemental": {
+        "version": { "_cldrVersion": "44" },
+        "likelySubtags": {
+            "en": "en-Latn-US",
+            "pa": "pa-Guru-IN",
+            "pa-Arab": "pa-Arab-PK",
+            "zh-TW": "zh-Hant-TW",
+            "und-Cyrl": "ru-Cyrl-RU",
+            "und-Cyrl-RS": "sr-Cyrl-RS",
+            "und-FR": "fr-Latn-FR"
+        }
+    }
+}"#;
+
+static ALIASES: &str = r#"{
+    "supplemental": {
+        "metadata": {
+            "alias": {
+                "languageAlias": {
+                    "iw": { "_reason": "deprecated", "_replacement": "he" },
+                    "sh": { "_reason": "legacy", "_replacement": "sr-Latn" },
+                    "sgn-BR": { "_reason": "deprecated", "_replacement": "bzs" }
+                },
+                "scriptAlias": {
+                    "Qaai": { "_reason": "deprecated", "_replacement": "Zinh" }
+                },
+                "territoryAlias": {
+                    "BU": { "_reason": "deprecated", "_replacement": "MM" },
+                    "SU": { "_reason": "deprecated", "_replacement": "RU AM AZ" }
+                }
+            }
+        }
+    }
+}"#;
+
+fn maximized(data: &JsonData, input: &str) -> String {
+    let mut li: LanguageIdentifier = input.parse().unwrap();
+    li.maximize_with_provider(data);
+    li.to_string()
+}
+
+fn canonicalized(data: &JsonData, input: &str) -> String {
+    let mut li: LanguageIdentifier = input.parse().unwrap();
+    li.canonicalize_with_provider(data);
+    li.to_string()
+}
+
+#[test]
+fn test_load_likely_subtags() {
+    let mut data = JsonData::new();
+    data.load_likely_subtags(LIKELY_SUBTAGS).unwrap();
+    assert_eq!(data.cldr_version(), Some("44"));
+
+    for (input, expected) in &[
+        ("en", "en-Latn-US"),
+        ("en-GB", "en-Latn-GB"),
+        ("pa-Arab", "pa-Arab-PK"),
+        ("zh-TW", "zh-Hant-TW"),
+        ("und-Cyrl", "ru-Cyrl-RU"),
+        ("und-Cyrl-RS", "sr-Cyrl-RS"),
+        ("und-FR", "fr-Latn-FR"),
+        ("de", "de"),
+        ("en-Latn-US", "en-Latn-US"),
+    ] {
+        assert_eq!(maximized(&data, input), *expected, "{}", input);
+    }
+
+    let mut li: LanguageIdentifier = "de".parse().unwrap();
+    assert!(!li.maximize_with_provider(&data));
+}
+
+#[test]
+fn test_load_aliases() {
+    let mut data = JsonData::new();
+    data.load_aliases(ALIASES).unwrap();
+
+    for (input, expected) in &[
+        ("iw-IL", "he-IL"),
+        ("sh", "sr-Latn"),
+        ("sh-Cyrl", "sr-Cyrl"),
+        ("und-Qaai", "und-Zinh"),
+        ("my-BU", "my-MM"),
+        ("ru-SU", "ru-RU"),
+        ("en-US", "en-US"),
+    ] {
+        assert_eq!(canonicalized(&data, input), *expected, "{}", input);
+    }
+
+    // Without loaded likely subtags, nothing is maximized.
+    assert_eq!(data.maximize("en".parse().unwrap(), None, None), None);
+}
+
+#[test]
+fn test_load_errors() {
+    let mut data = JsonData::new();
+    assert!(matches!(
+        data.load_likely_subtags("{"),
+        Err(DataError::Json(_))
+    ));
+    assert_eq!(
+        data.load_likely_subtags(r#"{"supplemental": {}}"#)
+            .unwrap_err()
+            .to_string(),
+        "Missing \"likelySubtags\" object"
+    );
+    assert_eq!(
+        data.load_likely_subtags(r#"{"supplemental": {"likelySubtags": {"en": "en-$"}}}"#)
+            .unwrap_err()
+            .to_string(),
+        "Invalid entry \"en-$\""
+    );
+    assert_eq!(
+        data.load_aliases(r#"{"supplemental": {"metadata": {}}}"#)
+            .unwrap_err()
+            .to_string(),
+        "Missing \"alias\" object"
+    );
+}
+
+#[cfg(feature = "likelysubtags")]
+#[test]
+fn test_compiled_data() {
+    use unic_langid_impl::provider::CompiledData;
+
+    for input in &["en", "und-Cyrl-RS", "zh-TW", "und-FR", "qaa"] {
+        let mut li1: LanguageIdentifier = input.parse().unwrap();
+        let mut li2 = li1.clone();
+        assert_eq!(li1.maximize(), li2.maximize_with_provider(&CompiledData));
+        assert_eq!(li1, li2);
+    }
+}
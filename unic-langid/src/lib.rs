@@ -102,6 +102,41 @@
 //! The feature is optional because it increases the binary size of the library by including
 //! a data table for CLDR likelySubtags.
 //!
+//! To reduce the size further, set the `UNIC_LANGID_LIKELYSUBTAGS_LANGUAGES` environment
+//! variable at build time to a comma separated list of languages (e.g. `en,fr,de`), and only
+//! the likelySubtags entries resolving to those languages will be included.
+//!
+//! ## iso639
+//!
+//! If `feature = "iso639"` is selected, the `iso639` module maps between two-letter
+//! ISO 639-1 and three-letter ISO 639-3 language codes, and `canonicalize` replaces
+//! three-letter codes with their two-letter equivalents.
+//!
+//! ## iso3166
+//!
+//! If `feature = "iso3166"` is selected, the `iso3166` module maps between ISO 3166-1
+//! alpha-2 and alpha-3 region codes, and `ParserOptions::lenient` accepts alpha-3
+//! region codes, such as `USA`, replacing them with the alpha-2 ones.
+//!
+//! ## aliases
+//!
+//! If `feature = "aliases"` is selected, the `aliases` module holds the CLDR replacements
+//! of deprecated subtags and legacy tags, and `LanguageIdentifier::canonicalize_aliases`
+//! replaces them, such as `iw` with `he` or `BU` with `MM`.
+//!
+//! ## runtime_data
+//!
+//! If `feature = "runtime_data"` is selected, `provider::JsonData` loads likely subtags
+//! and aliases from the cldr-json `likelySubtags.json` and `aliases.json` files at runtime,
+//! for use with `LanguageIdentifier::maximize_with_provider` and
+//! `LanguageIdentifier::canonicalize_with_provider`.
+//!
+//! ## character_direction
+//!
+//! The `character_direction` feature is enabled by default and provides
+//! `LanguageIdentifier::character_direction` along with its data. Disabling the default
+//! features removes both, for consumers that only parse and compare identifiers.
+//!
 //! [`UTS #35: Unicode LDML 3.1 Unicode Language Identifier`]: https://unicode.org/reports/tr35/tr35.html#Unicode_language_identifier
 //! [`LanguageIdentifier`]: ./struct.LanguageIdentifier.html
 
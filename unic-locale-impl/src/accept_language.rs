@@ -0,0 +1,923 @@
+//! Utilities for working with the HTTP [`Accept-Language`] header.
+//!
+//! [`Accept-Language`]: https://tools.ietf.org/html/rfc7231#section-5.3.5
+use crate::negotiate::{self, Lookup, NegotiationStrategy};
+use crate::{LanguageIdentifier, Locale};
+#[cfg(feature = "rayon")]
+use rayon::prelude::*;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::Write;
+
+/// A language range of an `Accept-Language` header value.
+#[derive(Debug, Clone, PartialEq)]
+#[allow(clippy::large_enum_variant)] // Almost all ranges are locales.
+pub enum LanguageRange {
+    /// A language tag, which also matches more specific tags.
+    Locale(Locale),
+    /// The `*` wildcard, matching any tag not matched by another range
+    /// of the same header.
+    Wildcard,
+}
+
+impl LanguageRange {
+    /// Returns the locale of the range, or `None` for the wildcard.
+    pub fn locale(&self) -> Option<&Locale> {
+        match self {
+            LanguageRange::Locale(locale) => Some(locale),
+            LanguageRange::Wildcard => None,
+        }
+    }
+}
+
+impl From<Locale> for LanguageRange {
+    fn from(locale: Locale) -> Self {
+        LanguageRange::Locale(locale)
+    }
+}
+
+impl std::fmt::Display for LanguageRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            LanguageRange::Locale(locale) => locale.fmt(f),
+            LanguageRange::Wildcard => f.write_str("*"),
+        }
+    }
+}
+
+/// A list of language ranges and their weights parsed from an `Accept-Language`
+/// header value, ordered by descending weight.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::accept_language::AcceptLanguage;
+///
+/// let list = AcceptLanguage::parse("fr;q=0.8, en-US, *;q=0.1, de;q=0.9");
+///
+/// let tags: Vec<_> = list.0.iter()
+///     .map(|(range, weight)| (range.to_string(), *weight))
+///     .collect();
+/// assert_eq!(tags, &[
+///     ("en-US".to_string(), 1.0),
+///     ("de".to_string(), 0.9),
+///     ("fr".to_string(), 0.8),
+///     ("*".to_string(), 0.1),
+/// ]);
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct AcceptLanguage(pub Vec<(LanguageRange, f32)>);
+
+impl AcceptLanguage {
+    /// Parses an `Accept-Language` header value.
+    ///
+    /// Entries which are not well-formed or have an invalid weight are skipped.
+    /// Entries of equal weight keep their order.
+    pub fn parse(header: &str) -> Self {
+        Self::from_entries(
+            header
+                .split(',')
+                .filter_map(|entry| parse_entry(entry, false).ok().flatten()),
+        )
+    }
+
+    /// Parses an `Accept-Language` header value, failing on the first entry
+    /// which is not well-formed or has an invalid weight.
+    ///
+    /// Empty entries, as in `en,,fr`, are allowed by the list syntax and skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::accept_language::{AcceptLanguage, AcceptLanguageError};
+    ///
+    /// let list = AcceptLanguage::parse_strict("en-US, fr;q=0.5")
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(list.to_string(), "en-US, fr;q=0.5");
+    ///
+    /// assert_eq!(
+    ///     AcceptLanguage::parse_strict("en-US, fr;q=1.5"),
+    ///     Err(AcceptLanguageError::InvalidWeight("fr;q=1.5".to_string()))
+    /// );
+    /// ```
+    pub fn parse_strict(header: &str) -> Result<Self, AcceptLanguageError> {
+        let mut entries = vec![];
+        for entry in header.split(',') {
+            entries.extend(parse_entry(entry, false)?);
+        }
+        Ok(Self::from_entries(entries))
+    }
+
+    /// Parses an `Accept-Language` header value as sent by misbehaving clients.
+    ///
+    /// In addition to [`parse`], whitespace is allowed around the `=` of the weight,
+    /// weights with more than three decimal places or outside of the `0.0..=1.0` range
+    /// are rounded and clamped, and tags are parsed as with `ParserOptions::lenient`.
+    /// Entries which still are not well-formed are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::accept_language::AcceptLanguage;
+    ///
+    /// let list = AcceptLanguage::parse_lenient("de;q = 0.55555, en;q=2, fr;q=oops, *;q=.1");
+    /// assert_eq!(list.to_string(), "en, de;q=0.556, *;q=0.1");
+    /// ```
+    ///
+    /// [`parse`]: #method.parse
+    pub fn parse_lenient(header: &str) -> Self {
+        Self::from_entries(
+            header
+                .split(',')
+                .filter_map(|entry| parse_entry(entry, true).ok().flatten()),
+        )
+    }
+
+    /// Returns an iterator over the locales and their weights, ordered by
+    /// descending weight, skipping the wildcard.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::accept_language::AcceptLanguage;
+    ///
+    /// let list = AcceptLanguage::parse("fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5");
+    ///
+    /// let locales: Vec<_> = list.locales()
+    ///     .map(|(locale, weight)| (locale.to_string(), weight))
+    ///     .collect();
+    /// assert_eq!(locales, &[
+    ///     ("fr-CH".to_string(), 1.0),
+    ///     ("fr".to_string(), 0.9),
+    ///     ("en".to_string(), 0.8),
+    /// ]);
+    /// ```
+    pub fn locales(&self) -> impl Iterator<Item = (&Locale, f32)> {
+        self.0
+            .iter()
+            .filter_map(|(range, weight)| Some((range.locale()?, *weight)))
+    }
+
+    fn from_entries(entries: impl IntoIterator<Item = (LanguageRange, f32)>) -> Self {
+        let mut entries: Vec<_> = entries.into_iter().collect();
+        entries.sort_by(|(_, a), (_, b)| b.partial_cmp(a).expect("Weights are not NaN."));
+        Self(entries)
+    }
+}
+
+/// Errors returned by [`AcceptLanguage::parse_strict`].
+///
+/// [`AcceptLanguage::parse_strict`]: struct.AcceptLanguage.html#method.parse_strict
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AcceptLanguageError {
+    /// An entry whose language range is neither a well-formed locale nor `*`.
+    InvalidRange(String),
+    /// An entry whose weight is not a valid `qvalue`.
+    InvalidWeight(String),
+}
+
+impl std::error::Error for AcceptLanguageError {}
+
+impl std::fmt::Display for AcceptLanguageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::InvalidRange(entry) => write!(f, "Invalid language range in \"{}\"", entry),
+            Self::InvalidWeight(entry) => write!(f, "Invalid weight in \"{}\"", entry),
+        }
+    }
+}
+
+/// Parses a single entry of an `Accept-Language` header value, returning `None`
+/// for an empty entry.
+fn parse_entry(
+    entry: &str,
+    lenient: bool,
+) -> Result<Option<(LanguageRange, f32)>, AcceptLanguageError> {
+    let entry = entry.trim();
+    if entry.is_empty() {
+        return Ok(None);
+    }
+    let mut parts = entry.split(';').map(str::trim);
+    let range = match parts.next().unwrap_or_default() {
+        "*" => LanguageRange::Wildcard,
+        tag => LanguageRange::Locale(
+            parse_tag(tag, lenient)
+                .ok_or_else(|| AcceptLanguageError::InvalidRange(entry.to_string()))?,
+        ),
+    };
+    let mut weight = 1.0;
+    for param in parts {
+        let q = if lenient {
+            param
+                .split_once('=')
+                .filter(|(name, _)| name.trim().eq_ignore_ascii_case("q"))
+                .map(|(_, q)| q.trim())
+        } else {
+            param
+                .strip_prefix("q=")
+                .or_else(|| param.strip_prefix("Q="))
+        };
+        if let Some(q) = q {
+            weight = if lenient {
+                parse_lenient_weight(q)
+            } else {
+                parse_weight(q)
+            }
+            .ok_or_else(|| AcceptLanguageError::InvalidWeight(entry.to_string()))?;
+        }
+    }
+    Ok(Some((range, weight)))
+}
+
+#[cfg_attr(not(feature = "extensions"), allow(unused_variables))]
+fn parse_tag(tag: &str, lenient: bool) -> Option<Locale> {
+    #[cfg(feature = "extensions")]
+    if lenient {
+        let options = crate::parser::ParserOptions::default().lenient(true);
+        return crate::parser::parse_locale_with_options(tag, options).ok();
+    }
+    tag.parse().ok()
+}
+
+impl std::fmt::Display for AcceptLanguage {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&write_entries(self.0.iter().map(|(range, q)| (range, *q))))
+    }
+}
+
+/// An ordered list of requested language ranges and their weights, such as the
+/// ranges of an `Accept-Language` header or the preferred languages of a user,
+/// which is negotiated against the available locales directly.
+///
+/// Ranges are kept by descending weight, and ranges of equal weight in the
+/// order they were added.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::Locale;
+/// use unic_locale_impl::accept_language::{AcceptLanguage, LanguagePriorityList};
+///
+/// let mut list = LanguagePriorityList::from(AcceptLanguage::parse("fr-CA, en;q=0.5, fr;q=0.8"));
+/// list.push("de".parse::<Locale>().expect("Parsing failed."), 0.9);
+/// assert_eq!(list.to_string(), "fr-CA, de;q=0.9, fr;q=0.8, en;q=0.5");
+///
+/// list.dedup_by_language();
+/// list.truncate(2);
+/// assert_eq!(list.to_string(), "fr-CA, de;q=0.9");
+///
+/// let available: Vec<Locale> = vec![
+///     "en".parse().unwrap(),
+///     "de".parse().unwrap(),
+/// ];
+/// assert_eq!(list.best(&available, &available[0]).to_string(), "de");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct LanguagePriorityList(Vec<(LanguageRange, f32)>);
+
+impl LanguagePriorityList {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds the list from locales in descending order of preference, such as the
+    /// preferred languages of the system, each with a weight of `1.0`.
+    pub fn from_locales<L: Into<Locale>>(locales: impl IntoIterator<Item = L>) -> Self {
+        Self(
+            locales
+                .into_iter()
+                .map(|locale| (LanguageRange::Locale(locale.into()), 1.0))
+                .collect(),
+        )
+    }
+
+    /// Adds a range after the ranges of greater or equal weight. The weight is
+    /// clamped to the `0.0..=1.0` range, and a `NaN` weight is treated as `0.0`.
+    pub fn push<R: Into<LanguageRange>>(&mut self, range: R, weight: f32) {
+        let weight = if weight.is_nan() {
+            0.0
+        } else {
+            weight.clamp(0.0, 1.0)
+        };
+        let idx = self.0.partition_point(|(_, w)| *w >= weight);
+        self.0.insert(idx, (range.into(), weight));
+    }
+
+    /// Keeps the first `len` ranges, dropping the others.
+    pub fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+
+    /// Keeps the first range of each language subtag, and the first wildcard,
+    /// dropping the others, such as `en-GB` after `en-US`.
+    pub fn dedup_by_language(&mut self) {
+        let mut seen = vec![];
+        self.0.retain(|(range, _)| {
+            let language = range.locale().map(|locale| locale.id.language);
+            if seen.contains(&language) {
+                false
+            } else {
+                seen.push(language);
+                true
+            }
+        });
+    }
+
+    /// Returns an iterator over the ranges and their weights, by descending weight.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (&LanguageRange, f32)> {
+        self.0.iter().map(|(range, weight)| (range, *weight))
+    }
+
+    /// Returns the number of ranges in the list.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Picks the best of the available locales for the list, as
+    /// [`Locale::best_from_accept_language`] does for a header value.
+    ///
+    /// [`Locale::best_from_accept_language`]: ../struct.Locale.html#method.best_from_accept_language
+    pub fn best<'a>(&self, available: &'a [Locale], default: &'a Locale) -> &'a Locale {
+        Lookup
+            .negotiate_list(self, available)
+            .first()
+            .copied()
+            .unwrap_or(default)
+    }
+
+    /// Returns all the acceptable available locales for the list followed by the
+    /// defaults, as [`Locale::negotiate_accept_language`] does for a header value.
+    ///
+    /// [`Locale::negotiate_accept_language`]: ../struct.Locale.html#method.negotiate_accept_language
+    pub fn negotiate<'a>(
+        &self,
+        available: &'a [Locale],
+        defaults: &'a [Locale],
+        policy: DefaultsPolicy,
+    ) -> Vec<&'a Locale> {
+        negotiate_list(self, available, defaults, policy)
+    }
+
+    /// Returns the available locales matched by the wildcard: those not matched by
+    /// another range, including the ranges excluded with `q=0`.
+    pub(crate) fn wildcard_matches<'a>(&self, available: &'a [Locale]) -> Vec<&'a Locale> {
+        wildcard_matches(&self.0, available)
+    }
+}
+
+impl From<AcceptLanguage> for LanguagePriorityList {
+    fn from(list: AcceptLanguage) -> Self {
+        Self(list.0)
+    }
+}
+
+impl From<LanguagePriorityList> for AcceptLanguage {
+    fn from(list: LanguagePriorityList) -> Self {
+        Self(list.0)
+    }
+}
+
+impl std::fmt::Display for LanguagePriorityList {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(&write_entries(self.iter()))
+    }
+}
+
+/// Returns the progressively shorter language identifiers tried by [`RFC 4647`] lookup,
+/// removing variants, then the region and the script.
+///
+/// [`RFC 4647`]: https://tools.ietf.org/html/rfc4647#section-3.4
+pub(crate) fn lookup_fallbacks(locale: &Locale) -> Vec<LanguageIdentifier> {
+    let mut id = locale.id.clone();
+    let mut result = vec![id.clone()];
+    let mut variants: Vec<_> = id.variants().copied().collect();
+    while variants.pop().is_some() {
+        id.set_variants(&variants);
+        result.push(id.clone());
+    }
+    if id.region.take().is_some() {
+        result.push(id.clone());
+    }
+    if id.script.take().is_some() {
+        result.push(id);
+    }
+    result
+}
+
+/// Picks the best of the available locales for an `Accept-Language` header value.
+///
+/// See [`Locale::best_from_accept_language`](../struct.Locale.html#method.best_from_accept_language).
+pub(crate) fn best<'a>(header: &str, available: &'a [Locale], default: &'a Locale) -> &'a Locale {
+    LanguagePriorityList::from(AcceptLanguage::parse(header)).best(available, default)
+}
+
+/// Picks the best locale as [`Lookup`] does, finding the language identifiers with
+/// `find` rather than scanning the available locales.
+fn best_with<'a>(
+    list: &[(LanguageRange, f32)],
+    available: &'a [Locale],
+    default: &'a Locale,
+    find: impl Fn(&LanguageIdentifier) -> Option<&'a Locale>,
+) -> &'a Locale {
+    list.iter()
+        .filter(|(_, weight)| *weight > 0.0)
+        .find_map(|(range, _)| match range {
+            LanguageRange::Locale(requested) => lookup_fallbacks(requested).iter().find_map(&find),
+            LanguageRange::Wildcard => wildcard_matches(list, available).first().copied(),
+        })
+        .unwrap_or(default)
+}
+
+/// Returns the available locales not matched by any of the ranges of the list,
+/// including those excluded with `q=0`, which the wildcard matches.
+fn wildcard_matches<'a>(list: &[(LanguageRange, f32)], available: &'a [Locale]) -> Vec<&'a Locale> {
+    let explicit: Vec<&Locale> = list
+        .iter()
+        .filter_map(|(range, _)| range.locale())
+        .collect();
+    available
+        .iter()
+        .filter(|locale| {
+            !explicit
+                .iter()
+                .any(|requested| locale.id.matches(&requested.id, false, true))
+        })
+        .collect()
+}
+
+/// When [`Locale::negotiate_accept_language`] and [`negotiate_languages`] append the
+/// developer defaults to the negotiated locales.
+///
+/// [`Locale::negotiate_accept_language`]: ../struct.Locale.html#method.negotiate_accept_language
+/// [`negotiate_languages`]: ../negotiate/fn.negotiate_languages.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DefaultsPolicy {
+    /// Always append the defaults, after the negotiated locales.
+    Always,
+    /// Append the defaults only if no available locale is acceptable.
+    #[default]
+    OnlyIfEmpty,
+    /// Never append the defaults.
+    Never,
+}
+
+/// Returns all the acceptable available locales for an `Accept-Language` header value,
+/// followed by the defaults as selected by the policy.
+///
+/// See [`Locale::negotiate_accept_language`](../struct.Locale.html#method.negotiate_accept_language).
+pub(crate) fn negotiate<'a>(
+    header: &str,
+    available: &'a [Locale],
+    defaults: &'a [Locale],
+    policy: DefaultsPolicy,
+) -> Vec<&'a Locale> {
+    negotiate_list(
+        &LanguagePriorityList::from(AcceptLanguage::parse(header)),
+        available,
+        defaults,
+        policy,
+    )
+}
+
+fn negotiate_list<'a>(
+    list: &LanguagePriorityList,
+    available: &'a [Locale],
+    defaults: &'a [Locale],
+    policy: DefaultsPolicy,
+) -> Vec<&'a Locale> {
+    let mut result = negotiate::LookupFallbacks.negotiate_list(list, available);
+    negotiate::append_defaults(&mut result, defaults, policy, None);
+    result
+}
+
+/// An index of available locales, built once to negotiate many `Accept-Language`
+/// header values against them.
+///
+/// Negotiation picks the same locale as [`Locale::best_from_accept_language`], but
+/// looks up the language identifiers in the index rather than scanning the locales.
+/// With the `rayon` feature, `par_best` negotiates many header values in parallel.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::Locale;
+/// use unic_locale_impl::accept_language::AvailableLocales;
+///
+/// let available: Vec<Locale> = vec![
+///     "en".parse().unwrap(),
+///     "fr".parse().unwrap(),
+/// ];
+/// let index = AvailableLocales::new(&available);
+///
+/// assert_eq!(index.best("fr-CH, en;q=0.5", &available[0]).to_string(), "fr");
+/// assert_eq!(index.best("pl", &available[0]).to_string(), "en");
+/// ```
+///
+/// [`Locale::best_from_accept_language`]: ../struct.Locale.html#method.best_from_accept_language
+#[derive(Debug, Clone)]
+pub struct AvailableLocales<'a> {
+    locales: &'a [Locale],
+    by_id: HashMap<&'a LanguageIdentifier, &'a Locale>,
+}
+
+impl<'a> AvailableLocales<'a> {
+    /// Builds the index of the available locales.
+    pub fn new(locales: &'a [Locale]) -> Self {
+        let mut by_id = HashMap::with_capacity(locales.len());
+        for locale in locales {
+            // The first of the locales with the same language identifier wins.
+            by_id.entry(&locale.id).or_insert(locale);
+        }
+        Self { locales, by_id }
+    }
+
+    /// Picks the best of the available locales for an `Accept-Language` header value,
+    /// or `default` if none of them is acceptable.
+    pub fn best(&self, header: &str, default: &'a Locale) -> &'a Locale {
+        let list = AcceptLanguage::parse(header);
+        best_with(&list.0, self.locales, default, |id| {
+            self.by_id.get(id).copied()
+        })
+    }
+
+    /// Picks the best of the available locales for each of the `Accept-Language`
+    /// header values in parallel, in the order of the headers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    /// use unic_locale_impl::accept_language::AvailableLocales;
+    ///
+    /// let available: Vec<Locale> = vec![
+    ///     "en".parse().unwrap(),
+    ///     "fr".parse().unwrap(),
+    /// ];
+    /// let index = AvailableLocales::new(&available);
+    ///
+    /// let best: Vec<_> = index.par_best(&["fr", "de, en", "pl"], &available[0])
+    ///     .iter()
+    ///     .map(|locale| locale.to_string())
+    ///     .collect();
+    /// assert_eq!(best, &["fr", "en", "en"]);
+    /// ```
+    #[cfg(feature = "rayon")]
+    pub fn par_best<S: AsRef<str> + Sync>(
+        &self,
+        headers: &[S],
+        default: &'a Locale,
+    ) -> Vec<&'a Locale> {
+        headers
+            .par_iter()
+            .map(|header| self.best(header.as_ref(), default))
+            .collect()
+    }
+}
+
+/// Controls how [`Locale::best_from_accept_language_weighted`] combines the weight
+/// of a requested range with its distance to an available locale.
+///
+/// The score of a match is `weight - distance * distance_factor`, where the distance
+/// is `0.0` for identical language identifiers and grows with each differing or
+/// missing subtag.
+///
+/// [`Locale::best_from_accept_language_weighted`]: ../struct.Locale.html#method.best_from_accept_language_weighted
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreWeighting {
+    /// How much the distance lowers the score. With `0.0`, any match of the
+    /// language subtag is as good as an identical one.
+    pub distance_factor: f32,
+}
+
+impl Default for ScoreWeighting {
+    fn default() -> Self {
+        Self {
+            distance_factor: 0.5,
+        }
+    }
+}
+
+/// Ranks the available locales by their best score for any of the requested
+/// ranges, best first. Earlier ranges and locales win ties.
+impl NegotiationStrategy for ScoreWeighting {
+    fn matches<'a>(&self, requested: &Locale, available: &'a [Locale]) -> Vec<&'a Locale> {
+        let list = [(LanguageRange::Locale(requested.clone()), 1.0)];
+        rank_weighted(&list, available, |l| (l, 1.0), *self, None)
+    }
+
+    fn negotiate_list<'a>(
+        &self,
+        list: &LanguagePriorityList,
+        available: &'a [Locale],
+    ) -> Vec<&'a Locale> {
+        rank_weighted(&list.0, available, |l| (l, 1.0), *self, None)
+    }
+}
+
+/// Why an available locale did not match a requested range in a [`NegotiationTrace`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rejection {
+    /// The range has a weight of `0`.
+    ZeroWeight,
+    /// The locale has another language than the range.
+    LanguageMismatch,
+    /// The locale has another script than the range.
+    ScriptMismatch,
+    /// The range is a wildcard, and the locale is matched by another range.
+    ExplicitlyRequested,
+}
+
+impl std::fmt::Display for Rejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::ZeroWeight => "the range has a weight of 0",
+            Self::LanguageMismatch => "the language differs",
+            Self::ScriptMismatch => "the script differs",
+            Self::ExplicitlyRequested => "the locale is matched by another range",
+        })
+    }
+}
+
+/// The outcome of comparing an available locale to a requested range.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Outcome {
+    /// The locale matches the range with the given distance and score.
+    Scored { distance: f32, score: f32 },
+    /// The locale does not match the range.
+    Rejected(Rejection),
+}
+
+/// A comparison of an available locale to a requested range.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TraceEntry<'a> {
+    pub range: LanguageRange,
+    pub weight: f32,
+    pub locale: &'a Locale,
+    pub outcome: Outcome,
+}
+
+/// A record of how [`Locale::best_from_accept_language_traced`] compared each of the
+/// available locales to each of the requested ranges, in the order they were compared.
+///
+/// [`Locale::best_from_accept_language_traced`]: ../struct.Locale.html#method.best_from_accept_language_traced
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct NegotiationTrace<'a> {
+    pub entries: Vec<TraceEntry<'a>>,
+    /// The best scored locale, or `None` if the default was picked.
+    pub chosen: Option<&'a Locale>,
+}
+
+impl<'a> NegotiationTrace<'a> {
+    /// Returns the entries of an available locale.
+    pub fn entries_for<'b>(
+        &'b self,
+        locale: &'b Locale,
+    ) -> impl Iterator<Item = &'b TraceEntry<'a>> + 'b {
+        self.entries
+            .iter()
+            .filter(move |entry| std::ptr::eq(entry.locale, locale))
+    }
+}
+
+/// Returns the distance between a requested and an available language identifier,
+/// or why they do not match if they have a different language or script.
+fn distance(
+    requested: &LanguageIdentifier,
+    available: &LanguageIdentifier,
+) -> Result<f32, Rejection> {
+    if requested.language != available.language {
+        return Err(Rejection::LanguageMismatch);
+    }
+    let mut distance = 0.0;
+    match (requested.script, available.script) {
+        (Some(a), Some(b)) if a != b => return Err(Rejection::ScriptMismatch),
+        (Some(_), None) | (None, Some(_)) => distance += 0.1,
+        _ => {}
+    }
+    match (requested.region, available.region) {
+        (Some(a), Some(b)) if a != b => distance += 0.3,
+        (Some(_), None) | (None, Some(_)) => distance += 0.1,
+        _ => {}
+    }
+    if !requested.variants().eq(available.variants()) {
+        distance += 0.05;
+    }
+    Ok(distance)
+}
+
+/// Picks the best scored of the available locales for an `Accept-Language` header value.
+///
+/// See [`Locale::best_from_accept_language_weighted`](../struct.Locale.html#method.best_from_accept_language_weighted).
+pub(crate) fn best_weighted<'a>(
+    header: &str,
+    available: &'a [Locale],
+    default: &'a Locale,
+    weighting: ScoreWeighting,
+) -> &'a Locale {
+    weighting
+        .negotiate_list(&AcceptLanguage::parse(header).into(), available)
+        .first()
+        .copied()
+        .unwrap_or(default)
+}
+
+/// Picks the best scored of the available locales for an `Accept-Language` header value,
+/// multiplying the score of each of them by its priority.
+///
+/// See [`Locale::best_from_accept_language_prioritized`](../struct.Locale.html#method.best_from_accept_language_prioritized).
+pub(crate) fn best_prioritized<'a>(
+    header: &str,
+    available: &'a [(Locale, f32)],
+    default: &'a Locale,
+    weighting: ScoreWeighting,
+) -> &'a Locale {
+    let list = AcceptLanguage::parse(header);
+    rank_weighted(&list.0, available, |(l, p)| (l, *p), weighting, None)
+        .first()
+        .copied()
+        .unwrap_or(default)
+}
+
+/// Picks the best scored of the available locales for an `Accept-Language` header value,
+/// recording each comparison.
+///
+/// See [`Locale::best_from_accept_language_traced`](../struct.Locale.html#method.best_from_accept_language_traced).
+pub(crate) fn best_traced<'a>(
+    header: &str,
+    available: &'a [Locale],
+    default: &'a Locale,
+    weighting: ScoreWeighting,
+) -> (&'a Locale, NegotiationTrace<'a>) {
+    let list = AcceptLanguage::parse(header);
+    let mut trace = NegotiationTrace::default();
+    trace.chosen = rank_weighted(
+        &list.0,
+        available,
+        |l| (l, 1.0),
+        weighting,
+        Some(&mut trace.entries),
+    )
+    .first()
+    .copied();
+    (trace.chosen.unwrap_or(default), trace)
+}
+
+/// Returns the available locales scoring for any of the ranges, by descending best
+/// score.
+fn rank_weighted<'a, T>(
+    list: &[(LanguageRange, f32)],
+    available: &'a [T],
+    entry: impl Fn(&'a T) -> (&'a Locale, f32),
+    weighting: ScoreWeighting,
+    mut trace: Option<&mut Vec<TraceEntry<'a>>>,
+) -> Vec<&'a Locale> {
+    let explicit: Vec<&Locale> = list
+        .iter()
+        .filter_map(|(range, _)| range.locale())
+        .collect();
+
+    // The best score of each available locale, and the first range giving it.
+    let mut best: Vec<Option<(f32, usize)>> = vec![None; available.len()];
+    for (range_idx, (range, weight)) in list.iter().enumerate() {
+        if *weight <= 0.0 && trace.is_none() {
+            continue;
+        }
+        for (idx, (locale, priority)) in available.iter().map(&entry).enumerate() {
+            let distance = if *weight <= 0.0 {
+                Err(Rejection::ZeroWeight)
+            } else {
+                match range {
+                    LanguageRange::Locale(requested) => distance(&requested.id, &locale.id),
+                    LanguageRange::Wildcard => {
+                        if explicit
+                            .iter()
+                            .any(|requested| locale.id.matches(&requested.id, false, true))
+                        {
+                            Err(Rejection::ExplicitlyRequested)
+                        } else {
+                            Ok(0.0)
+                        }
+                    }
+                }
+            };
+            let outcome = match distance {
+                Ok(distance) => {
+                    let score = (weight - distance * weighting.distance_factor) * priority;
+                    if best[idx].map_or(true, |(best, _)| score > best) {
+                        best[idx] = Some((score, range_idx));
+                    }
+                    Outcome::Scored { distance, score }
+                }
+                Err(rejection) => Outcome::Rejected(rejection),
+            };
+            if let Some(trace) = trace.as_mut() {
+                trace.push(TraceEntry {
+                    range: range.clone(),
+                    weight: *weight,
+                    locale,
+                    outcome,
+                });
+            }
+        }
+    }
+
+    let mut ranked: Vec<(f32, usize, usize)> = best
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, best)| best.map(|(score, range_idx)| (score, range_idx, idx)))
+        .collect();
+    // Earlier ranges and locales win ties.
+    ranked.sort_by(|a, b| {
+        b.0.partial_cmp(&a.0)
+            .unwrap_or(Ordering::Equal)
+            .then((a.1, a.2).cmp(&(b.1, b.2)))
+    });
+    ranked
+        .into_iter()
+        .map(|(_, _, idx)| entry(&available[idx]).0)
+        .collect()
+}
+
+/// Parses a `qvalue`, as defined in RFC 7231.
+fn parse_weight(q: &str) -> Option<f32> {
+    let (int, frac) = q.split_once('.').unwrap_or((q, ""));
+    if !matches!(int, "0" | "1")
+        || frac.len() > 3
+        || !frac.bytes().all(|b| b.is_ascii_digit())
+        || (int == "1" && frac.bytes().any(|b| b != b'0'))
+    {
+        return None;
+    }
+    q.parse().ok()
+}
+
+/// Parses a weight which is a number, clamped to the `0.0..=1.0` range and
+/// rounded to three decimal places.
+fn parse_lenient_weight(q: &str) -> Option<f32> {
+    let q: f32 = q.parse().ok()?;
+    if q.is_nan() {
+        return None;
+    }
+    Some((q.clamp(0.0, 1.0) * 1000.0).round() / 1000.0)
+}
+
+/// Serializes a list of locales and their weights into an `Accept-Language`
+/// header value.
+///
+/// Entries are written in the given order. Weights are clamped to the `0.0..=1.0`
+/// range and rounded to three decimal places, as required by the `qvalue` grammar.
+/// A weight of `1.0` is the default and is omitted, and a `NaN` weight is written as `0`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::Locale;
+/// use unic_locale_impl::accept_language;
+///
+/// let en_us: Locale = "en-US".parse()
+///     .expect("Parsing failed.");
+/// let fr: Locale = "fr".parse()
+///     .expect("Parsing failed.");
+/// let de: Locale = "de".parse()
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(
+///     accept_language::serialize(&[(en_us, 1.0), (fr, 0.8), (de, 0.12345)]),
+///     "en-US, fr;q=0.8, de;q=0.123"
+/// );
+/// ```
+pub fn serialize<L: AsRef<Locale>>(entries: &[(L, f32)]) -> String {
+    write_entries(entries.iter().map(|(locale, q)| (locale.as_ref(), *q)))
+}
+
+fn write_entries<T: std::fmt::Display>(entries: impl Iterator<Item = (T, f32)>) -> String {
+    let mut result = String::new();
+
+    for (idx, (range, weight)) in entries.enumerate() {
+        if idx != 0 {
+            result.push_str(", ");
+        }
+        write!(result, "{}", range).expect("Writing to a String cannot fail.");
+
+        let q = if weight.is_nan() {
+            0
+        } else {
+            (weight.clamp(0.0, 1.0) * 1000.0).round() as u16
+        };
+        match q {
+            1000 => {}
+            0 => result.push_str(";q=0"),
+            q => {
+                let digits = format!("{:03}", q);
+                write!(result, ";q=0.{}", digits.trim_end_matches('0'))
+                    .expect("Writing to a String cannot fail.");
+            }
+        }
+    }
+
+    result
+}
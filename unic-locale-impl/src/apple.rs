@@ -0,0 +1,76 @@
+//! Conversion between `Locale` and Apple's `CFLocale` identifiers, such as
+//! `th_TH@calendar=buddhist;numbers=thai`.
+use crate::errors::LocaleError;
+use crate::parser::ParserError;
+use crate::Locale;
+
+// `CFLocale` keywords and the unicode extension keys they map to, sorted by the former.
+static KEYWORDS: &[(&str, &str)] = &[
+    ("calendar", "ca"),
+    ("collation", "co"),
+    ("currency", "cu"),
+    ("numbers", "nu"),
+];
+
+// Keyword values spelled differently in `CFLocale` identifiers, by key.
+static VALUES: &[(&str, &str, &str)] = &[
+    ("ca", "ethiopic-amete-alem", "ethioaa"),
+    ("ca", "gregorian", "gregory"),
+    ("co", "dictionary", "dict"),
+    ("co", "gb2312han", "gb2312"),
+    ("co", "phonebook", "phonebk"),
+    ("co", "traditional", "trad"),
+];
+
+pub(crate) fn from_apple_identifier(identifier: &str) -> Result<Locale, LocaleError> {
+    let (tag, keywords) = match identifier.split_once('@') {
+        Some((tag, keywords)) => (tag, Some(keywords)),
+        None => (identifier, None),
+    };
+    let mut locale: Locale = tag.parse()?;
+
+    for keyword in keywords.into_iter().flat_map(|k| k.split(';')) {
+        let (name, value) = keyword
+            .split_once('=')
+            .ok_or(ParserError::InvalidExtension)?;
+        let key = KEYWORDS
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name.trim()))
+            .map(|(_, key)| *key)
+            .ok_or(ParserError::InvalidExtension)?;
+        let value = value.trim();
+        let value = VALUES
+            .iter()
+            .find(|(k, v, _)| *k == key && v.eq_ignore_ascii_case(value))
+            .map_or(value, |(_, _, bcp47)| bcp47);
+        locale.extensions.unicode.set_keyword(key, &[value])?;
+    }
+    Ok(locale)
+}
+
+pub(crate) fn to_apple_identifier(locale: &Locale) -> String {
+    let mut result = locale.id.to_string().replace('-', "_");
+
+    let mut separator = '@';
+    for (name, key) in KEYWORDS {
+        let value = locale
+            .extensions
+            .unicode
+            .keyword(key)
+            .expect("The key is well-formed.")
+            .collect::<Vec<_>>();
+        if value.is_empty() {
+            continue;
+        }
+        result.push(separator);
+        result.push_str(name);
+        result.push('=');
+        let value = value.join("-");
+        match VALUES.iter().find(|(k, _, v)| k == key && *v == value) {
+            Some((_, apple, _)) => result.push_str(apple),
+            None => result.push_str(&value),
+        }
+        separator = ';';
+    }
+    result
+}
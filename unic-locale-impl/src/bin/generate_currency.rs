@@ -0,0 +1,66 @@
+use serde_json::Value;
+use std::fs;
+
+// Not a region with a legal tender of its own (the European Union), or the
+// unknown region.
+const SKIPPED_REGIONS: &[&str] = &["EU", "ZZ"];
+
+/// Picks the current tender of a region.
+///
+/// A few regions have several (e.g. `PA` uses both `PAB` and `USD`); the
+/// national currency, whose code starts with the region code, is preferred,
+/// falling back to the first one listed.
+fn current_tender(region: &str, tenders: &[Value]) -> Option<String> {
+    let current: Vec<&str> = tenders
+        .iter()
+        .filter_map(|entry| {
+            let (code, info) = entry.as_object()?.iter().next()?;
+            if info.get("_to").is_some() || info.get("_tender") == Some(&Value::from("false")) {
+                return None;
+            }
+            Some(code.as_str())
+        })
+        .collect();
+
+    current
+        .iter()
+        .find(|code| code.starts_with(region))
+        .or_else(|| current.first())
+        .map(|code| code.to_string())
+}
+
+fn main() {
+    let contents = fs::read_to_string("./data/currencyData.json")
+        .expect("Something went wrong reading the file");
+    let v: Value = serde_json::from_str(&contents).unwrap();
+
+    let regions = v["supplemental"]["currencyData"]["region"]
+        .as_object()
+        .unwrap();
+
+    let mut pairs = vec![];
+    for (region, tenders) in regions.iter() {
+        if region.len() != 2 || SKIPPED_REGIONS.contains(&region.as_str()) {
+            continue;
+        }
+        if let Some(code) = current_tender(region, tenders.as_array().unwrap()) {
+            pairs.push(format!("(\"{}\", \"{}\")", region, code));
+        }
+    }
+    pairs.sort();
+
+    println!(
+        "pub static CLDR_VERSION: &str = \"{}\";",
+        v["supplemental"]["version"]["_cldrVersion"]
+            .as_str()
+            .unwrap()
+    );
+    println!();
+    println!("// Sorted by region.");
+    println!("#[rustfmt::skip]");
+    println!("pub static REGION_CURRENCIES: &[(&str, &str)] = &[");
+    for line in pairs.chunks(6) {
+        println!("    {},", line.join(", "));
+    }
+    println!("];");
+}
@@ -0,0 +1,204 @@
+//! A `Locale` with fixed-capacity inline storage, for environments where the
+//! memory used by a parsed locale has to be known up front.
+//!
+//! Only the parsed value is bounded: parsing shares the grammar of `parse_locale`,
+//! which may allocate temporarily, such as for variants and error messages, so it
+//! is not a no-alloc parser.
+use crate::extensions::ExtensionsMap;
+use crate::parser::{parse_with_visitor, ParserError};
+use crate::{subtags, ExtensionType, Locale, Subtag};
+use std::fmt::Write;
+use std::ops::ControlFlow;
+use tinystr::TinyStr8;
+
+/// A locale parsed by `Locale::from_bytes_bounded`, holding at most `N`
+/// variant and extension subtags inline.
+///
+/// Extension singletons count towards the capacity, so `en-u-hc-h12` uses three
+/// of them. Unlike `Locale`, the extension subtags are kept in the order they
+/// appear in the input, lowercased.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::Locale;
+///
+/// let loc = Locale::from_bytes_bounded::<4>(b"en-US-u-hc-h12")
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(loc.language(), "en");
+/// assert_eq!(loc.extensions().collect::<Vec<_>>(), &["u", "hc", "h12"]);
+/// assert_eq!(loc.to_string(), "en-US-u-hc-h12");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BoundedLocale<const N: usize> {
+    language: subtags::Language,
+    script: Option<subtags::Script>,
+    region: Option<subtags::Region>,
+    subtags: [Option<TinyStr8>; N],
+    variants_len: usize,
+    len: usize,
+    // Whether the input was a private use tag, such as `x-custom`, without a language.
+    private_use_tag: bool,
+}
+
+impl<const N: usize> BoundedLocale<N> {
+    pub fn language(&self) -> subtags::Language {
+        self.language
+    }
+
+    pub fn script(&self) -> Option<subtags::Script> {
+        self.script
+    }
+
+    pub fn region(&self) -> Option<subtags::Region> {
+        self.region
+    }
+
+    /// Returns an iterator over the variant subtags.
+    pub fn variants(&self) -> impl Iterator<Item = &str> {
+        self.stored().take(self.variants_len)
+    }
+
+    /// Returns an iterator over the extension subtags, singletons included.
+    pub fn extensions(&self) -> impl Iterator<Item = &str> {
+        self.stored().skip(self.variants_len)
+    }
+
+    /// Returns the number of variant and extension subtags, at most `N`.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if there are no variant or extension subtags.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    fn stored(&self) -> impl Iterator<Item = &str> {
+        self.subtags[..self.len]
+            .iter()
+            .flatten()
+            .map(|s| s.as_str())
+    }
+}
+
+fn singleton(extension: ExtensionType) -> u8 {
+    match extension {
+        ExtensionType::Unicode => b'u',
+        ExtensionType::Transform => b't',
+        ExtensionType::Private => b'x',
+        ExtensionType::Other(c) => c as u8,
+    }
+}
+
+pub(crate) fn parse<const N: usize>(input: &[u8]) -> Result<BoundedLocale<N>, ParserError> {
+    let input = std::str::from_utf8(input).map_err(|_| ParserError::InvalidSubtag)?;
+    let mut result = BoundedLocale {
+        language: subtags::Language::default(),
+        script: None,
+        region: None,
+        subtags: [None; N],
+        variants_len: 0,
+        len: 0,
+        private_use_tag: true,
+    };
+    let mut overflow = false;
+
+    parse_with_visitor(input, &mut |subtag| {
+        let value = match subtag {
+            Subtag::Language(language) => {
+                result.language = language;
+                result.private_use_tag = false;
+                return ControlFlow::Continue(());
+            }
+            Subtag::Script(script) => {
+                result.script = Some(script);
+                return ControlFlow::Continue(());
+            }
+            Subtag::Region(region) => {
+                result.region = Some(region);
+                return ControlFlow::Continue(());
+            }
+            Subtag::Variant(variant) => {
+                result.variants_len += 1;
+                TinyStr8::from_bytes(variant.as_str().as_bytes())
+            }
+            Subtag::ExtensionSingleton(extension) => TinyStr8::from_bytes(&[singleton(extension)]),
+            Subtag::ExtensionKey(s) | Subtag::ExtensionValue(s) | Subtag::Private(s) => {
+                TinyStr8::from_bytes(s.as_bytes()).map(|s| s.to_ascii_lowercase())
+            }
+        };
+        if result.len == N {
+            overflow = true;
+            return ControlFlow::Break(());
+        }
+        result.subtags[result.len] =
+            Some(value.expect("The parser only yields subtags of at most 8 characters."));
+        result.len += 1;
+        ControlFlow::Continue(())
+    })?;
+
+    if overflow {
+        return Err(ParserError::CapacityExceeded { capacity: N });
+    }
+    result.private_use_tag &= !result.is_empty();
+    Ok(result)
+}
+
+impl<const N: usize> std::fmt::Display for BoundedLocale<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.private_use_tag {
+            for (idx, subtag) in self.extensions().enumerate() {
+                if idx > 0 {
+                    f.write_char('-')?;
+                }
+                f.write_str(subtag)?;
+            }
+            return Ok(());
+        }
+        f.write_str(self.language.as_str())?;
+        if let Some(script) = &self.script {
+            f.write_char('-')?;
+            f.write_str(script.as_str())?;
+        }
+        if let Some(region) = &self.region {
+            f.write_char('-')?;
+            f.write_str(region.as_str())?;
+        }
+        for subtag in self.stored() {
+            f.write_char('-')?;
+            f.write_str(subtag)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const N: usize> From<&BoundedLocale<N>> for Locale {
+    fn from(input: &BoundedLocale<N>) -> Self {
+        let variants = input
+            .variants()
+            .map(|v| subtags::Variant::from_bytes(v.as_bytes()))
+            .collect::<Result<Vec<_>, _>>()
+            .expect("Variants were validated when parsing.");
+        let mut extensions = String::new();
+        for subtag in input.extensions() {
+            extensions.push('-');
+            extensions.push_str(subtag);
+        }
+        #[cfg_attr(not(feature = "extensions"), allow(unused_mut))]
+        let mut extensions = ExtensionsMap::from_bytes(extensions.as_bytes())
+            .expect("Extensions were validated when parsing.");
+        #[cfg(feature = "extensions")]
+        {
+            extensions.private.private_use_tag = input.private_use_tag;
+        }
+        Locale::from_parts(
+            input.language,
+            input.script,
+            input.region,
+            &variants,
+            Some(extensions),
+        )
+    }
+}
@@ -0,0 +1,108 @@
+use crate::errors::LocaleError;
+use crate::Locale;
+
+/// A change made by [`canonicalize_with_report`] to its input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Transformation {
+    /// `_` separators were replaced with `-`.
+    SeparatorNormalized,
+    /// Subtags were converted to their canonical case, as in `EN-us`.
+    CasingFixed,
+    /// Subtags were sorted into their canonical order, removing duplicates
+    /// and `true` values, as in `en-u-nu-thai-ca-buddhist`.
+    ExtensionsReordered,
+    /// A deprecated or non-canonical subtag or keyword was replaced with its
+    /// preferred form, such as `ca-islamicc` with `ca-islamic-civil`.
+    AliasReplaced { from: String, to: String },
+    /// A deprecated unicode extension keyword was removed, such as `vt-0061`.
+    DeprecatedKeywordRemoved { keyword: String },
+}
+
+/// Canonicalizes the input like [`canonicalize`], and returns the canonical `Locale`
+/// with the list of changes made to the input, in the order they were applied.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::{canonicalize_with_report, Transformation};
+///
+/// let (loc, report) = canonicalize_with_report("en_US-u-nu-thai-ca-islamicc")
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(loc.to_string(), "en-US-u-ca-islamic-civil-nu-thai");
+/// assert_eq!(report, &[
+///     Transformation::SeparatorNormalized,
+///     Transformation::ExtensionsReordered,
+///     Transformation::AliasReplaced {
+///         from: "ca-islamicc".to_string(),
+///         to: "ca-islamic-civil".to_string(),
+///     },
+/// ]);
+/// ```
+///
+/// [`canonicalize`]: fn.canonicalize.html
+pub fn canonicalize_with_report<S: AsRef<[u8]>>(
+    input: S,
+) -> Result<(Locale, Vec<Transformation>), LocaleError> {
+    #[cfg_attr(
+        not(any(feature = "extensions", feature = "iso639")),
+        allow(unused_mut)
+    )]
+    let mut locale = Locale::from_bytes(input.as_ref())?;
+    let input = String::from_utf8_lossy(input.as_ref());
+    let mut report = vec![];
+
+    if input.contains('_') {
+        report.push(Transformation::SeparatorNormalized);
+    }
+    let parsed = locale.to_string();
+    let parsed_subtags: Vec<&str> = parsed.split('-').collect();
+    if input.split(['-', '_']).any(|subtag| {
+        !parsed_subtags.contains(&subtag)
+            && parsed_subtags
+                .iter()
+                .any(|p| p.eq_ignore_ascii_case(subtag))
+    }) {
+        report.push(Transformation::CasingFixed);
+    }
+    if !input.replace('_', "-").eq_ignore_ascii_case(&parsed) {
+        report.push(Transformation::ExtensionsReordered);
+    }
+
+    #[cfg(feature = "iso639")]
+    {
+        let from = locale.id.language;
+        if unic_langid_impl::iso639::canonicalize_language(&mut locale.id.language) {
+            report.push(Transformation::AliasReplaced {
+                from: from.to_string(),
+                to: locale.id.language.to_string(),
+            });
+        }
+    }
+
+    #[cfg(feature = "extensions")]
+    {
+        let before = locale.extensions.unicode.clone();
+        if locale.extensions.unicode.replace_deprecated() {
+            let after = &locale.extensions.unicode;
+            let keyword = |list: &crate::extensions::UnicodeExtensionList, key: &str| {
+                let mut keyword = vec![key];
+                keyword.extend(list.keyword(key).expect("Keys are well-formed."));
+                keyword.join("-")
+            };
+            for key in before.keyword_keys() {
+                let from = keyword(&before, key);
+                if !after.keyword_keys().any(|k| k == key) {
+                    report.push(Transformation::DeprecatedKeywordRemoved { keyword: from });
+                } else if keyword(after, key) != from {
+                    report.push(Transformation::AliasReplaced {
+                        from,
+                        to: keyword(after, key),
+                    });
+                }
+            }
+        }
+    }
+
+    Ok((locale, report))
+}
@@ -0,0 +1,78 @@
+//! Levels of conformance a `Locale` can be checked against, from BCP47
+//! well-formedness to canonical form.
+use crate::parser::ParserError;
+use crate::subtags::Region;
+use crate::{canonicalize_with_report, Locale, Transformation};
+
+/// A level of conformance checked by [`Locale::check`], each including the
+/// ones before it.
+///
+/// [`Locale::check`]: struct.Locale.html#method.check
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub enum CheckLevel {
+    /// The syntax of BCP47, which every parsed `Locale` satisfies.
+    WellFormed,
+    /// Subtags and keyword values defined by their registries.
+    Valid,
+    /// The canonical form, as produced by [`canonicalize`].
+    ///
+    /// [`canonicalize`]: fn.canonicalize.html
+    Canonical,
+}
+
+/// A problem found by [`Locale::check`].
+///
+/// [`Locale::check`]: struct.Locale.html#method.check
+#[derive(Debug, PartialEq)]
+pub enum Finding {
+    /// A unicode extension keyword whose value is not allowed for its key, as
+    /// in `hc-h10`.
+    InvalidKeyword(ParserError),
+    /// An alphabetic region not assigned by ISO 3166-1, checked with the
+    /// `iso3166` feature.
+    UnknownRegion(Region),
+    /// A change canonicalization would make.
+    NotCanonical(Transformation),
+}
+
+impl Finding {
+    /// Returns the level at which the finding is reported.
+    pub fn level(&self) -> CheckLevel {
+        match self {
+            Self::InvalidKeyword(_) | Self::UnknownRegion(_) => CheckLevel::Valid,
+            Self::NotCanonical(_) => CheckLevel::Canonical,
+        }
+    }
+}
+
+pub(crate) fn check(locale: &Locale, level: CheckLevel) -> Vec<Finding> {
+    let mut findings = vec![];
+
+    if level >= CheckLevel::Valid {
+        #[cfg(feature = "extensions")]
+        findings.extend(
+            locale
+                .extensions
+                .unicode
+                .keyword_errors()
+                .map(Finding::InvalidKeyword),
+        );
+
+        #[cfg(feature = "iso3166")]
+        if let Some(region) = locale.id.region {
+            if region.as_str().as_bytes()[0].is_ascii_alphabetic()
+                && unic_langid_impl::iso3166::to_alpha3(region).is_none()
+            {
+                findings.push(Finding::UnknownRegion(region));
+            }
+        }
+    }
+
+    if level >= CheckLevel::Canonical {
+        let (_, report) =
+            canonicalize_with_report(locale.to_string()).expect("Serialized locales are valid.");
+        findings.extend(report.into_iter().map(Finding::NotCanonical));
+    }
+
+    findings
+}
@@ -0,0 +1,25 @@
+//! The current legal tender of each region, from CLDR `currencyData`.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_locale_impl::currency::currency_for_region;
+//!
+//! assert_eq!(currency_for_region("HR".parse().unwrap()), Some("EUR"));
+//! assert_eq!(currency_for_region("AQ".parse().unwrap()), None);
+//! ```
+use crate::subtags::Region;
+
+pub use crate::currency_table::CLDR_VERSION;
+use crate::currency_table::REGION_CURRENCIES;
+
+/// Returns the ISO 4217 code of the current legal tender of a region.
+///
+/// Returns `None` for regions without a currency, such as `AQ`, and for
+/// macro-regions, such as `419`.
+pub fn currency_for_region(region: Region) -> Option<&'static str> {
+    REGION_CURRENCIES
+        .binary_search_by_key(&region.as_str(), |(r, _)| r)
+        .ok()
+        .map(|idx| REGION_CURRENCIES[idx].1)
+}
@@ -0,0 +1,36 @@
+//! A process-wide default `Locale`, shared by libraries built on this crate.
+use crate::Locale;
+use std::sync::OnceLock;
+
+static DEFAULT_LOCALE: OnceLock<Locale> = OnceLock::new();
+
+/// Sets the process-wide default locale.
+///
+/// The default can be set only once, and an `Err` with the given locale
+/// is returned if it was already set.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::{default_locale, set_default_locale, Locale};
+///
+/// let loc: Locale = "fr-CA".parse()
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(set_default_locale(loc.clone()), Ok(()));
+/// assert_eq!(default_locale(), Some(&loc));
+///
+/// assert!(set_default_locale("de".parse().unwrap()).is_err());
+/// assert_eq!(default_locale(), Some(&loc));
+/// ```
+#[allow(clippy::result_large_err)] // Mirrors `OnceLock::set`.
+pub fn set_default_locale(locale: Locale) -> Result<(), Locale> {
+    DEFAULT_LOCALE.set(locale)
+}
+
+/// Returns the process-wide default locale, if it was set.
+///
+/// See `Locale::current` for a fallback to the system locale.
+pub fn default_locale() -> Option<&'static Locale> {
+    DEFAULT_LOCALE.get()
+}
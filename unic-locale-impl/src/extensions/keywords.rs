@@ -0,0 +1,206 @@
+//! Typed values of well-known unicode extension keywords.
+use crate::parser::ParserError;
+
+/// A typed value of a unicode extension keyword.
+pub(crate) trait Keyword: Sized {
+    /// The key of the keyword.
+    const KEY: &'static [u8];
+
+    /// Returns the typed value for the subtags of a keyword value, joined with `-`.
+    fn from_value(value: &str) -> Option<Self>;
+
+    /// Returns the keyword value, with its subtags joined with `-`.
+    fn as_str(&self) -> &'static str;
+}
+
+macro_rules! keyword {
+    ($(#[$meta:meta])* $name:ident, $key:literal, { $($variant:ident => $value:literal,)* }) => {
+        $(#[$meta])*
+        #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+        pub enum $name {
+            $($variant,)*
+        }
+
+        impl $name {
+            /// Returns the keyword value, with its subtags joined with `-`.
+            pub fn as_str(&self) -> &'static str {
+                Keyword::as_str(self)
+            }
+        }
+
+        impl Keyword for $name {
+            const KEY: &'static [u8] = $key;
+
+            fn from_value(value: &str) -> Option<Self> {
+                match value {
+                    $($value => Some(Self::$variant),)*
+                    _ => None,
+                }
+            }
+
+            fn as_str(&self) -> &'static str {
+                match self {
+                    $(Self::$variant => $value,)*
+                }
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = ParserError;
+
+            fn from_str(source: &str) -> Result<Self, Self::Err> {
+                Self::from_value(&source.to_ascii_lowercase()).ok_or(ParserError::InvalidSubtag)
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+    };
+}
+
+keyword!(
+    /// The hour cycle, given by the `hc` keyword.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::extensions::HourCycle;
+    ///
+    /// let hc: HourCycle = "h23".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(hc, HourCycle::H23);
+    /// assert_eq!(hc.as_str(), "h23");
+    /// ```
+    HourCycle, b"hc", {
+        H11 => "h11",
+        H12 => "h12",
+        H23 => "h23",
+        H24 => "h24",
+    }
+);
+
+keyword!(
+    /// The calendar algorithm, given by the `ca` keyword.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::extensions::CalendarAlgorithm;
+    ///
+    /// let ca: CalendarAlgorithm = "islamic-civil".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(ca, CalendarAlgorithm::IslamicCivil);
+    /// ```
+    CalendarAlgorithm, b"ca", {
+        Buddhist => "buddhist",
+        Chinese => "chinese",
+        Coptic => "coptic",
+        Dangi => "dangi",
+        Ethioaa => "ethioaa",
+        Ethiopic => "ethiopic",
+        Gregory => "gregory",
+        Hebrew => "hebrew",
+        Indian => "indian",
+        Islamic => "islamic",
+        IslamicCivil => "islamic-civil",
+        IslamicRgsa => "islamic-rgsa",
+        IslamicTbla => "islamic-tbla",
+        IslamicUmalqura => "islamic-umalqura",
+        Iso8601 => "iso8601",
+        Japanese => "japanese",
+        Persian => "persian",
+        Roc => "roc",
+    }
+);
+
+keyword!(
+    /// The collation type, given by the `co` keyword.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::extensions::Collation;
+    ///
+    /// assert_eq!("phonebk".parse::<Collation>(), Ok(Collation::Phonebk));
+    /// ```
+    Collation, b"co", {
+        Compat => "compat",
+        Dict => "dict",
+        Ducet => "ducet",
+        Emoji => "emoji",
+        Eor => "eor",
+        Phonebk => "phonebk",
+        Phonetic => "phonetic",
+        Pinyin => "pinyin",
+        Search => "search",
+        Searchjl => "searchjl",
+        Standard => "standard",
+        Stroke => "stroke",
+        Trad => "trad",
+        Unihan => "unihan",
+        Zhuyin => "zhuyin",
+    }
+);
+
+keyword!(
+    /// The first day of the week, given by the `fw` keyword.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::extensions::FirstDayOfWeek;
+    ///
+    /// assert_eq!(FirstDayOfWeek::Sun.to_string(), "sun");
+    /// ```
+    FirstDayOfWeek, b"fw", {
+        Mon => "mon",
+        Tue => "tue",
+        Wed => "wed",
+        Thu => "thu",
+        Fri => "fri",
+        Sat => "sat",
+        Sun => "sun",
+    }
+);
+
+keyword!(
+    /// A common decimal numbering system, given by the `nu` keyword.
+    ///
+    /// The keyword accepts many more numbering systems, which are only
+    /// available through the raw keyword API.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::extensions::NumberingSystem;
+    ///
+    /// assert_eq!("ARAB".parse::<NumberingSystem>(), Ok(NumberingSystem::Arab));
+    /// assert!("roman".parse::<NumberingSystem>().is_err());
+    /// ```
+    NumberingSystem, b"nu", {
+        Arab => "arab",
+        Arabext => "arabext",
+        Beng => "beng",
+        Deva => "deva",
+        Fullwide => "fullwide",
+        Gujr => "gujr",
+        Guru => "guru",
+        Hanidec => "hanidec",
+        Khmr => "khmr",
+        Knda => "knda",
+        Laoo => "laoo",
+        Latn => "latn",
+        Mlym => "mlym",
+        Mymr => "mymr",
+        Orya => "orya",
+        Tamldec => "tamldec",
+        Telu => "telu",
+        Thai => "thai",
+        Tibt => "tibt",
+    }
+);
@@ -6,23 +6,65 @@
 //!  * Unicode Extensions - marked as `u`.
 //!  * Transform Extensions - marked as `t`.
 //!  * Private Use Extensions - marked as `x`.
-//!  * Other extensions - marked as any `a-z` except of `u`, `t` and `x`.
+//!  * Other extensions - marked as any `0-9` or `a-z` except of `u`, `t` and `x`.
+//!
+//! Without the `extensions` feature, `ExtensionsMap` is an empty placeholder, and
+//! parsing a `Locale` with any extension fails.
+#[cfg(feature = "extensions")]
+mod keywords;
+#[cfg(feature = "extensions")]
 mod private;
+mod shared;
+#[cfg(not(feature = "extensions"))]
+mod thin;
+#[cfg(feature = "extensions")]
 mod transform;
+#[cfg(feature = "extensions")]
 mod unicode;
 
-pub use private::PrivateExtensionList;
-pub use transform::TransformExtensionList;
-pub use unicode::UnicodeExtensionList;
+#[cfg(feature = "extensions")]
+pub use keywords::{CalendarAlgorithm, Collation, FirstDayOfWeek, HourCycle, NumberingSystem};
+#[cfg(feature = "extensions")]
+pub use private::{PrivateExtensionList, PrivateSubtagOrder};
+pub use shared::SharedExtensionsMap;
+#[cfg(not(feature = "extensions"))]
+pub use thin::ExtensionsMap;
+#[cfg(feature = "extensions")]
+pub use transform::{TransformExtensionKey, TransformExtensionList};
+#[cfg(feature = "extensions")]
+pub use unicode::{KeywordValues, UnicodeExtensionKey, UnicodeExtensionList};
+
+#[cfg(feature = "extensions")]
+pub(crate) use private::check_private_subtag;
+#[cfg(feature = "extensions")]
+pub(crate) use transform::is_language_subtag;
+#[cfg(feature = "extensions")]
+pub(crate) use unicode::CLDR_VERSION as UNICODE_CLDR_VERSION;
+#[cfg(feature = "extensions")]
+pub(crate) use unicode::{is_attribute, is_type, type_length, ATTR_LENGTH};
 
+#[cfg(feature = "extensions")]
 use std::collections::BTreeMap;
 use std::fmt::Write;
+#[cfg(feature = "extensions")]
 use std::iter::Peekable;
+#[cfg(feature = "extensions")]
 use std::str::FromStr;
 
+#[cfg(feature = "extensions")]
 use tinystr::TinyStr8;
 
+#[cfg(feature = "extensions")]
+use crate::errors::LocaleError;
 use crate::parser::ParserError;
+#[cfg(feature = "extensions")]
+use crate::parser::{visit_extensions, LocaleVisitor};
+#[cfg(feature = "extensions")]
+use crate::Subtag;
+#[cfg(feature = "extensions")]
+use std::ops::ControlFlow;
+#[cfg(feature = "extensions")]
+use unic_langid_impl::LanguageIdentifier;
 
 /// Defines the type of extension.
 #[derive(PartialEq, Eq, Debug, Clone, Copy, Hash, PartialOrd, Ord)]
@@ -62,7 +104,137 @@ impl std::fmt::Display for ExtensionType {
     }
 }
 
+/// Returns `true` if the bytes are a well-formed unicode extension key, such as `ca`,
+/// or transform extension key, such as `h0`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::extensions::is_valid_extension_key;
+///
+/// assert!(is_valid_extension_key(b"ca"));
+/// assert!(is_valid_extension_key(b"H0"));
+/// assert!(!is_valid_extension_key(b"c"));
+/// assert!(!is_valid_extension_key(b"c-"));
+/// ```
+pub fn is_valid_extension_key(v: &[u8]) -> bool {
+    match v {
+        [a, b] => {
+            (a.is_ascii_alphanumeric() && b.is_ascii_alphabetic())
+                || (a.is_ascii_alphabetic() && b.is_ascii_digit())
+        }
+        _ => false,
+    }
+}
+
+/// Returns an extension subtag, such as a singleton, key or value, in its
+/// canonical casing, lowercase.
+///
+/// The input is not validated.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::extensions::to_extension_case;
+///
+/// assert_eq!(to_extension_case("U"), "u");
+/// assert_eq!(to_extension_case("Buddhist"), "buddhist");
+/// ```
+pub fn to_extension_case(v: &str) -> String {
+    v.to_ascii_lowercase()
+}
+
+/// Returns the lowercase singleton of an extension other than `u`, `t` and `x`.
+#[cfg(feature = "extensions")]
+fn parse_other_singleton(singleton: char) -> Result<char, ParserError> {
+    if !singleton.is_ascii() {
+        return Err(ParserError::InvalidExtension);
+    }
+    match ExtensionType::from_byte(singleton as u8)? {
+        ExtensionType::Other(singleton) => Ok(singleton),
+        _ => Err(ParserError::InvalidExtension),
+    }
+}
+
+/// The storage of a unicode extension value, transform extension value or private
+/// use subtag.
+///
+/// Values are at most 8 characters, unless the `long_subtags` feature widens the
+/// storage for the longer values accepted by `ParserOptions::lenient`.
+#[cfg(all(feature = "extensions", not(feature = "long_subtags")))]
+pub type ValueStr = TinyStr8;
+/// The storage of a unicode extension value, transform extension value or private
+/// use subtag.
+///
+/// Values are at most 8 characters, unless the `long_subtags` feature widens the
+/// storage for the longer values accepted by `ParserOptions::lenient`.
+#[cfg(feature = "long_subtags")]
+pub type ValueStr = tinystr::TinyStr16;
+
+#[cfg(feature = "extensions")]
+pub(crate) const TRUE_VALUE: ValueStr = unsafe { ValueStr::new_unchecked(1_702_195_828) }; // "true"
+
+/// Whether an extension list was parsed with `ParserOptions::lenient`, which decides
+/// the length of the values its setters accept.
+///
+/// The mode isn't part of the value of a list, so all modes compare equal.
+#[cfg(feature = "extensions")]
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct ParseMode {
+    pub(crate) lenient: bool,
+}
+
+#[cfg(feature = "extensions")]
+impl ParseMode {
+    /// The maximum length of a value, which is 16 in lenient mode with the
+    /// `long_subtags` feature, and 8 otherwise.
+    pub(crate) fn max_value_length(self) -> usize {
+        if self.lenient && cfg!(feature = "long_subtags") {
+            16
+        } else {
+            8
+        }
+    }
+}
+
+#[cfg(feature = "extensions")]
+impl PartialEq for ParseMode {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "extensions")]
+impl Eq for ParseMode {}
+
+#[cfg(feature = "extensions")]
+impl std::hash::Hash for ParseMode {
+    fn hash<H: std::hash::Hasher>(&self, _state: &mut H) {}
+}
+
+#[cfg(feature = "extensions")]
+impl PartialOrd for ParseMode {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(feature = "extensions")]
+impl Ord for ParseMode {
+    fn cmp(&self, _other: &Self) -> std::cmp::Ordering {
+        std::cmp::Ordering::Equal
+    }
+}
+
+#[cfg(feature = "extensions")]
+fn parse_other_value(singleton: char, v: &[u8]) -> Result<TinyStr8, ParserError> {
+    ParserError::check_extension_subtag(ExtensionType::Other(singleton), v, 2..=8)?;
+    let s = TinyStr8::from_bytes(v).map_err(|_| ParserError::InvalidSubtag)?;
+    Ok(s.to_ascii_lowercase())
+}
+
 /// A map of extensions associated with a given `Locale.
+#[cfg(feature = "extensions")]
 #[derive(Debug, Default, PartialEq, Eq, Clone, Hash, PartialOrd, Ord)]
 pub struct ExtensionsMap {
     pub unicode: UnicodeExtensionList,
@@ -71,44 +243,343 @@ pub struct ExtensionsMap {
     pub private: PrivateExtensionList,
 }
 
+#[cfg(feature = "extensions")]
 impl ExtensionsMap {
+    /// Parses the extensions of a locale, which may start with a separator, as
+    /// their `Display` output does.
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, ParserError> {
+        let bytes = match bytes {
+            [b'-' | b'_', rest @ ..] => rest,
+            _ => bytes,
+        };
+        if bytes.is_empty() {
+            return Ok(Self::default());
+        }
         let mut iterator = bytes.split(|c| *c == b'-' || *c == b'_').peekable();
-        Self::try_from_iter(&mut iterator)
+        Self::try_from_iter(&mut iterator, false)
+    }
+
+    pub(crate) fn try_from_iter<'a, I>(
+        iter: &mut Peekable<I>,
+        lenient: bool,
+    ) -> Result<Self, ParserError>
+    where
+        I: Iterator<Item = &'a [u8]> + Clone,
+    {
+        let mut builder = ExtensionsBuilder::new(ParseMode { lenient });
+        // The builder only breaks on an error, which `finish` returns.
+        let _ = visit_extensions(iter, lenient, &mut builder)?;
+        builder.finish()
     }
 
-    pub(crate) fn try_from_iter<'a>(
-        iter: &mut Peekable<impl Iterator<Item = &'a [u8]>>,
-    ) -> Result<Self, ParserError> {
-        let mut result = ExtensionsMap::default();
+    /// Returns `true` if the extensions were parsed with `ParserOptions::lenient`.
+    ///
+    /// Their setters then accept the same lengths as the parser did, and their
+    /// serialization may only parse again with the same option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    /// use unic_locale_impl::parser::{parse_locale_with_options, ParserOptions};
+    ///
+    /// let loc: Locale = "en-x-foo".parse()
+    ///     .expect("Parsing failed.");
+    /// assert!(!loc.extensions.is_lenient());
+    ///
+    /// let options = ParserOptions::default().lenient(true);
+    /// let loc = parse_locale_with_options("en-x-foo", options)
+    ///     .expect("Parsing failed.");
+    /// assert!(loc.extensions.is_lenient());
+    /// ```
+    pub fn is_lenient(&self) -> bool {
+        self.unicode.mode.lenient || self.transform.mode.lenient || self.private.mode.lenient
+    }
 
-        let mut st = iter.next();
-        while let Some(subtag) = st {
-            match subtag.get(0).map(|b| ExtensionType::from_byte(*b)) {
-                Some(Ok(ExtensionType::Unicode)) => {
-                    result.unicode = UnicodeExtensionList::try_from_iter(iter)?;
-                }
-                Some(Ok(ExtensionType::Transform)) => {
-                    result.transform = TransformExtensionList::try_from_iter(iter)?;
+    pub fn is_empty(&self) -> bool {
+        self.unicode.is_empty()
+            && self.transform.is_empty()
+            && self.other.is_empty()
+            && self.private.is_empty()
+    }
+
+    /// Returns the subtags of an extension other than `u`, `t` and `x`, which
+    /// are empty if the extension is not set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc: Locale = "en-a-bar-x-u-foo".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.extensions.other_extension('a')
+    ///                .expect("Getting extension failed.")
+    ///                .collect::<Vec<_>>(),
+    ///            &["bar"]);
+    /// assert_eq!(loc.extensions.other_singletons().collect::<Vec<_>>(), &['a']);
+    ///
+    /// loc.extensions.set_other_extension('b', &["foo", "baz"])
+    ///     .expect("Setting extension failed.");
+    /// assert_eq!(loc.to_string(), "en-a-bar-b-foo-baz-x-u-foo");
+    ///
+    /// assert!(loc.extensions.other_extension('u').is_err());
+    /// ```
+    pub fn other_extension(
+        &self,
+        singleton: char,
+    ) -> Result<impl ExactSizeIterator<Item = &str>, LocaleError> {
+        let values: &[_] = match self.other.get(&parse_other_singleton(singleton)?) {
+            Some(v) => v,
+            None => &[],
+        };
+        Ok(values.iter().map(|s| s.as_str()))
+    }
+
+    /// Returns an iterator over the singletons of the extensions other than
+    /// `u`, `t` and `x`, sorted.
+    pub fn other_singletons(&self) -> impl ExactSizeIterator<Item = char> + '_ {
+        self.other.keys().copied()
+    }
+
+    /// Sets the subtags of an extension other than `u`, `t` and `x`, replacing
+    /// the previous ones. Each subtag is 2 to 8 alphanumeric characters.
+    pub fn set_other_extension<S: AsRef<[u8]>>(
+        &mut self,
+        singleton: char,
+        values: &[S],
+    ) -> Result<(), LocaleError> {
+        let singleton = parse_other_singleton(singleton)?;
+        if values.is_empty() {
+            return Err(ParserError::EmptyExtension {
+                extension: ExtensionType::Other(singleton),
+            }
+            .into());
+        }
+        let values = values
+            .iter()
+            .map(|v| parse_other_value(singleton, v.as_ref()))
+            .collect::<Result<_, _>>()?;
+        self.other.insert(singleton, values);
+        Ok(())
+    }
+
+    /// Removes an extension other than `u`, `t` and `x`. Returns `true` if it was set.
+    pub fn remove_other_extension(&mut self, singleton: char) -> Result<bool, LocaleError> {
+        Ok(self
+            .other
+            .remove(&parse_other_singleton(singleton)?)
+            .is_some())
+    }
+
+    /// Writes the extensions other than `u`, `t` and `x` whose singletons are in
+    /// the given range, keeping the extensions sorted by singleton.
+    fn fmt_other(
+        &self,
+        range: impl std::ops::RangeBounds<char>,
+        f: &mut std::fmt::Formatter,
+    ) -> std::fmt::Result {
+        for (singleton, values) in self.other.range(range) {
+            write!(f, "-{}", singleton)?;
+            for value in values {
+                write!(f, "-{}", value)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the `tlang` of the transform extension, the language the content
+    /// was transformed from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    /// use unic_locale_impl::extensions::TransformExtensionKey;
+    ///
+    /// let mut loc: Locale = "ja-t-it-m0-ungegn".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.extensions.transform_language().map(|l| l.to_string()), Some("it".to_string()));
+    ///
+    /// let m0: TransformExtensionKey = "m0".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.extensions.transform_field(m0).collect::<Vec<_>>(), &["ungegn"]);
+    ///
+    /// loc.extensions.set_transform_language(Some("ru".parse().expect("Parsing failed.")));
+    /// loc.extensions.set_transform_field(m0, &["bgn"])
+    ///     .expect("Setting tfield failed.");
+    /// assert_eq!(loc.to_string(), "ja-t-ru-m0-bgn");
+    /// ```
+    pub fn transform_language(&self) -> Option<&LanguageIdentifier> {
+        self.transform.tlang()
+    }
+
+    /// Sets the `tlang` of the transform extension, or removes it with `None`.
+    pub fn set_transform_language(&mut self, tlang: Option<LanguageIdentifier>) {
+        match tlang {
+            Some(tlang) => self
+                .transform
+                .set_tlang(tlang)
+                .expect("Setting tlang cannot fail."),
+            None => self.transform.clear_tlang(),
+        }
+    }
+
+    /// Returns the value of a tfield of the transform extension, which is empty
+    /// if the field is not set.
+    pub fn transform_field(
+        &self,
+        key: TransformExtensionKey,
+    ) -> impl ExactSizeIterator<Item = &str> {
+        self.transform.tvalues(key).iter().map(|s| s.as_str())
+    }
+
+    /// Sets the value of a tfield of the transform extension.
+    pub fn set_transform_field<S: AsRef<[u8]>>(
+        &mut self,
+        key: TransformExtensionKey,
+        value: &[S],
+    ) -> Result<(), LocaleError> {
+        let value: Vec<&[u8]> = value.iter().map(AsRef::as_ref).collect();
+        self.transform.set_tfield(key.as_str().as_bytes(), &value)
+    }
+
+    /// Removes a tfield of the transform extension. Returns `true` if it was set.
+    pub fn remove_transform_field(&mut self, key: TransformExtensionKey) -> bool {
+        self.transform
+            .remove_tfield(key.as_str())
+            .expect("Keys are well-formed.")
+    }
+
+    /// Returns `true` if the extensions were parsed from a private use tag, such as
+    /// `x-custom`, and there are still private use subtags and no other extensions.
+    pub(crate) fn is_private_use_only(&self) -> bool {
+        self.private.private_use_tag
+            && self.unicode.is_empty()
+            && self.transform.is_empty()
+            && self.other.is_empty()
+            && !self.private.is_empty()
+    }
+}
+
+/// Assembles an `ExtensionsMap` from the subtags recognized by `visit_extensions`,
+/// which has already validated them.
+#[cfg(feature = "extensions")]
+struct ExtensionsBuilder<'a> {
+    map: ExtensionsMap,
+    mode: ParseMode,
+    extension: Option<ExtensionType>,
+    key: Option<&'a str>,
+    // The values of the current key, the tlang of a transform extension before
+    // its first tkey, or the subtags of another extension.
+    values: Vec<&'a str>,
+    error: Option<ParserError>,
+}
+
+#[cfg(feature = "extensions")]
+impl<'a> ExtensionsBuilder<'a> {
+    fn new(mode: ParseMode) -> Self {
+        let mut map = ExtensionsMap::default();
+        map.unicode.mode = mode;
+        map.transform.mode = mode;
+        map.private.mode = mode;
+        Self {
+            map,
+            mode,
+            extension: None,
+            key: None,
+            values: vec![],
+            error: None,
+        }
+    }
+
+    fn push(&mut self, subtag: Subtag<'a>) -> Result<(), ParserError> {
+        match subtag {
+            Subtag::ExtensionSingleton(extension) => {
+                self.flush()?;
+                // A repeated `u` or `t` extension replaces the previous one.
+                match extension {
+                    ExtensionType::Unicode => {
+                        self.map.unicode = UnicodeExtensionList::default();
+                        self.map.unicode.mode = self.mode;
+                    }
+                    ExtensionType::Transform => {
+                        self.map.transform = TransformExtensionList::default();
+                        self.map.transform.mode = self.mode;
+                    }
+                    _ => {}
                 }
-                Some(Ok(ExtensionType::Private)) => {
-                    result.private = PrivateExtensionList::try_from_iter(iter)?;
+                self.extension = Some(extension);
+            }
+            Subtag::ExtensionKey(key) => {
+                self.flush()?;
+                self.key = Some(key);
+            }
+            Subtag::ExtensionValue(value) => {
+                if self.extension == Some(ExtensionType::Unicode) && self.key.is_none() {
+                    self.map.unicode.push_parsed_attribute(value.as_bytes())?;
+                } else {
+                    self.values.push(value);
                 }
-                None => {}
-                _ => unimplemented!(),
             }
+            Subtag::Private(tag) => self.map.private.push_parsed_tag(tag.as_bytes())?,
+            _ => {}
+        }
+        Ok(())
+    }
 
-            st = iter.next();
+    fn flush(&mut self) -> Result<(), ParserError> {
+        match (self.extension, self.key.take()) {
+            (Some(ExtensionType::Unicode), Some(key)) => {
+                self.map.unicode.insert_parsed_keyword(key, &self.values)?;
+            }
+            (Some(ExtensionType::Transform), Some(tkey)) => {
+                self.map
+                    .transform
+                    .insert_parsed_tfield(tkey, &self.values)?;
+            }
+            (Some(ExtensionType::Transform), None) if !self.values.is_empty() => {
+                self.map.transform.set_parsed_tlang(&self.values)?;
+            }
+            (Some(ExtensionType::Other(singleton)), _) => {
+                let values = self
+                    .values
+                    .iter()
+                    .map(|v| parse_other_value(singleton, v.as_bytes()))
+                    .collect::<Result<_, _>>()?;
+                self.map.other.entry(singleton).or_insert(values);
+            }
+            _ => {}
         }
+        self.values.clear();
+        Ok(())
+    }
 
-        Ok(result)
+    fn finish(mut self) -> Result<ExtensionsMap, ParserError> {
+        if let Some(error) = self.error.take() {
+            return Err(error);
+        }
+        self.flush()?;
+        Ok(self.map)
     }
+}
 
-    pub fn is_empty(&self) -> bool {
-        self.unicode.is_empty() && self.transform.is_empty() && self.private.is_empty()
+#[cfg(feature = "extensions")]
+impl<'a> LocaleVisitor<'a> for ExtensionsBuilder<'a> {
+    fn visit(&mut self, subtag: Subtag<'a>) -> ControlFlow<()> {
+        match self.push(subtag) {
+            Ok(()) => ControlFlow::Continue(()),
+            Err(error) => {
+                self.error = Some(error);
+                ControlFlow::Break(())
+            }
+        }
     }
 }
 
+#[cfg(feature = "extensions")]
 impl FromStr for ExtensionsMap {
     type Err = ParserError;
 
@@ -117,11 +588,14 @@ impl FromStr for ExtensionsMap {
     }
 }
 
+#[cfg(feature = "extensions")]
 impl std::fmt::Display for ExtensionsMap {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        // Alphabetic by singleton (t, u, x)
-        write!(f, "{}{}{}", self.transform, self.unicode, self.private)?;
-
-        Ok(())
+        // Alphabetic by singleton, with the private use extension last.
+        self.fmt_other(..'t', f)?;
+        self.transform.fmt(f)?;
+        self.unicode.fmt(f)?;
+        self.fmt_other('v'.., f)?;
+        self.private.fmt(f)
     }
 }
@@ -1,38 +1,127 @@
 use crate::errors::LocaleError;
-use crate::parser::ParserError;
+use crate::parser::{InvalidSubtagReason, ParserError};
 
-use tinystr::TinyStr8;
+use super::{ParseMode, ValueStr};
+
+/// Defines how the subtags of a `PrivateExtensionList` are ordered.
+///
+/// Private use subtags are opaque to this crate: their meaning, order included, is
+/// defined by private agreement. Neither the BCP 47 canonical form nor the UTS #35
+/// canonicalization reorders them, so `x-b-a` and `x-a-b` may mean different things.
+/// By default the order in which they were parsed or added is therefore preserved,
+/// and takes part in equality, hashing and ordering. Consumers treating them as a
+/// set may opt into canonical (sorted) order instead.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Hash)]
+pub enum PrivateSubtagOrder {
+    /// Keep subtags in the order they were parsed or added.
+    #[default]
+    Preserve,
+    /// Keep subtags sorted.
+    Canonical,
+}
 
 /// A list of [`Unicode Private Extensions`] as defined in [`Unicode Locale
 /// Identifier`] specification.
 ///
 /// Those extensions are intended for `pass-through` use.
 ///
+/// Each private use subtag is 1 to 8 ASCII alphanumeric characters, or up to 16 if
+/// parsed with `ParserOptions::lenient` and the `long_subtags` feature.
+///
+/// The subtags are kept in the order defined by [`PrivateSubtagOrder`], parse order by
+/// default since the order of private use subtags may be significant, and two lists
+/// are equal if they contain the same subtags in the same order.
+///
+/// The subtags are read back with [`tags`](Self::tags), and queried and removed
+/// with [`has_tag`](Self::has_tag) and [`remove_tag`](Self::remove_tag), which
+/// also answer to the `get_private`, `has_private_value` and `remove_private_value`
+/// search aliases. The only raw access is the `unstable`
+/// `raw_tags`.
+///
 /// # Examples
 ///
 /// ```
 /// use unic_locale_impl::Locale;
+/// use unic_locale_impl::extensions::PrivateSubtagOrder;
 ///
 /// let mut loc: Locale = "en-US-x-foo-faa".parse()
 ///     .expect("Parsing failed.");
 ///
 /// assert_eq!(loc.extensions.private.has_tag("faa"), Ok(true));
+/// assert_eq!(loc.extensions.private.tags().next(), Some("foo")); // parse order is preserved
+///
+/// loc.extensions.private.set_order(PrivateSubtagOrder::Canonical);
 /// assert_eq!(loc.extensions.private.tags().next(), Some("faa")); // tags got sorted
+///
 /// loc.extensions.private.clear_tags();
 /// assert_eq!(loc.to_string(), "en-US");
 /// ```
 ///
 /// [`Unicode Private Extensions`]: https://unicode.org/reports/tr35/#pu_extensions
 /// [`Unicode Locale Identifier`]: https://unicode.org/reports/tr35/#Unicode_locale_identifier
-#[derive(Clone, PartialEq, Eq, Debug, Default, Hash, PartialOrd, Ord)]
-pub struct PrivateExtensionList(Vec<TinyStr8>);
+/// [`PrivateSubtagOrder`]: ./enum.PrivateSubtagOrder.html
+#[derive(Clone, Debug, Default)]
+pub struct PrivateExtensionList {
+    tags: Vec<ValueStr>,
+    order: PrivateSubtagOrder,
+    pub(crate) mode: ParseMode,
+    // Whether the list was parsed from a private use tag, such as `x-custom`, which
+    // is written without the `und` language. Like the mode, it isn't part of the value.
+    pub(crate) private_use_tag: bool,
+}
 
-fn parse_value(t: &[u8]) -> Result<TinyStr8, ParserError> {
-    let s = TinyStr8::from_bytes(t).map_err(|_| ParserError::InvalidSubtag)?;
-    if t.is_empty() || t.len() > 8 || !s.is_ascii_alphanumeric() {
-        return Err(ParserError::InvalidSubtag);
+impl PartialEq for PrivateExtensionList {
+    fn eq(&self, other: &Self) -> bool {
+        self.tags == other.tags
     }
+}
+
+impl Eq for PrivateExtensionList {}
+
+impl std::hash::Hash for PrivateExtensionList {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.tags.hash(state);
+    }
+}
 
+impl PartialOrd for PrivateExtensionList {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PrivateExtensionList {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.tags.cmp(&other.tags)
+    }
+}
+
+/// Checks that a private use subtag is 1 to `ParseMode::max_value_length` ASCII
+/// alphanumeric characters.
+pub(crate) fn check_private_subtag(t: &[u8], mode: ParseMode) -> Result<(), ParserError> {
+    let reason = if t.is_empty() {
+        Some(InvalidSubtagReason::Empty)
+    } else if t.len() > mode.max_value_length() {
+        Some(InvalidSubtagReason::TooLong)
+    } else if !t.iter().all(u8::is_ascii_alphanumeric) {
+        Some(InvalidSubtagReason::InvalidCharacter)
+    } else {
+        None
+    };
+
+    match reason {
+        Some(reason) => Err(ParserError::InvalidPrivateSubtag {
+            subtag: String::from_utf8_lossy(t).into_owned(),
+            reason,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Parses a single private use subtag of up to `ParseMode::max_value_length` characters.
+fn parse_value(t: &[u8], mode: ParseMode) -> Result<ValueStr, ParserError> {
+    check_private_subtag(t, mode)?;
+    let s = ValueStr::from_bytes(t).map_err(|_| ParserError::InvalidSubtag)?;
     Ok(s.to_ascii_lowercase())
 }
 
@@ -50,7 +139,15 @@ impl PrivateExtensionList {
     /// assert_eq!(loc.extensions.private.is_empty(), false);
     /// ```
     pub fn is_empty(&self) -> bool {
-        self.0.is_empty()
+        self.tags.is_empty()
+    }
+
+    /// Returns the stored tags, in the order they are serialized.
+    ///
+    /// Not stable, and only available with the `unstable` feature.
+    #[cfg(feature = "unstable")]
+    pub fn raw_tags(&self) -> &[ValueStr] {
+        &self.tags
     }
 
     /// Returns `true` if tag is included in the `PrivateExtensionList`.
@@ -67,8 +164,9 @@ impl PrivateExtensionList {
     ///               .expect("Getting tag failed."),
     ///            true);
     /// ```
+    #[doc(alias = "has_private_value")]
     pub fn has_tag<S: AsRef<[u8]>>(&self, tag: S) -> Result<bool, LocaleError> {
-        Ok(self.0.contains(&parse_value(tag.as_ref())?))
+        Ok(self.tags.contains(&parse_value(tag.as_ref(), self.mode)?))
     }
 
     /// Returns an iterator over all tags in the `PrivateExtensionList`.
@@ -82,14 +180,17 @@ impl PrivateExtensionList {
     ///     .expect("Parsing failed.");
     ///
     /// assert_eq!(loc.extensions.private.tags().collect::<Vec<_>>(),
-    ///            &["bar", "foo"]);
+    ///            &["foo", "bar"]);
     /// ```
+    #[doc(alias = "get_private")]
     pub fn tags(&self) -> impl ExactSizeIterator<Item = &str> {
-        self.0.iter().map(|s| s.as_ref())
+        self.tags.iter().map(|s| s.as_ref())
     }
 
     /// Adds a tag to the `PrivateExtensionList`.
     ///
+    /// The tag may be as long as the parser accepted, see `ExtensionsMap::is_lenient`.
+    ///
     /// # Examples
     ///
     /// ```
@@ -104,8 +205,10 @@ impl PrivateExtensionList {
     /// assert_eq!(loc.to_string(), "en-US-x-foo");
     /// ```
     pub fn add_tag<S: AsRef<[u8]>>(&mut self, tag: S) -> Result<(), LocaleError> {
-        self.0.push(parse_value(tag.as_ref())?);
-        self.0.sort_unstable();
+        self.tags.push(parse_value(tag.as_ref(), self.mode)?);
+        if self.order == PrivateSubtagOrder::Canonical {
+            self.tags.sort_unstable();
+        }
         Ok(())
     }
 
@@ -128,14 +231,15 @@ impl PrivateExtensionList {
     ///
     /// assert_eq!(loc.to_string(), "en-US");
     /// ```
+    #[doc(alias = "remove_private_value")]
     pub fn remove_tag<S: AsRef<[u8]>>(&mut self, tag: S) -> Result<bool, LocaleError> {
-        let value = parse_value(tag.as_ref())?;
-        match self.0.binary_search(&value) {
-            Ok(idx) => {
-                self.0.remove(idx);
+        let value = parse_value(tag.as_ref(), self.mode)?;
+        match self.tags.iter().position(|t| *t == value) {
+            Some(idx) => {
+                self.tags.remove(idx);
                 Ok(true)
             }
-            Err(_) => Ok(false),
+            None => Ok(false),
         }
     }
 
@@ -153,20 +257,56 @@ impl PrivateExtensionList {
     /// assert_eq!(loc.to_string(), "en-US");
     /// ```
     pub fn clear_tags(&mut self) {
-        self.0.clear();
+        self.tags.clear();
     }
 
-    pub(crate) fn try_from_iter<'a>(
-        iter: &mut impl Iterator<Item = &'a [u8]>,
-    ) -> Result<Self, ParserError> {
-        let mut pext = Self::default();
+    /// Returns the ordering policy of the `PrivateExtensionList`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    /// use unic_locale_impl::extensions::PrivateSubtagOrder;
+    ///
+    /// let loc: Locale = "en-US-x-foo".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.extensions.private.order(), PrivateSubtagOrder::Preserve);
+    /// ```
+    pub fn order(&self) -> PrivateSubtagOrder {
+        self.order
+    }
 
-        for subtag in iter {
-            pext.0.push(parse_value(subtag)?);
+    /// Sets the ordering policy of the `PrivateExtensionList`.
+    ///
+    /// Switching to `PrivateSubtagOrder::Canonical` sorts the existing tags,
+    /// and keeps them sorted when new tags are added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    /// use unic_locale_impl::extensions::PrivateSubtagOrder;
+    ///
+    /// let mut loc: Locale = "en-US-x-foo-bar".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.to_string(), "en-US-x-foo-bar");
+    ///
+    /// loc.extensions.private.set_order(PrivateSubtagOrder::Canonical);
+    /// assert_eq!(loc.to_string(), "en-US-x-bar-foo");
+    /// ```
+    pub fn set_order(&mut self, order: PrivateSubtagOrder) {
+        self.order = order;
+        if order == PrivateSubtagOrder::Canonical {
+            self.tags.sort_unstable();
         }
-        pext.0.sort_unstable();
+    }
 
-        Ok(pext)
+    /// Adds a tag recognized by the parser.
+    pub(crate) fn push_parsed_tag(&mut self, tag: &[u8]) -> Result<(), ParserError> {
+        self.tags.push(parse_value(tag, self.mode)?);
+        Ok(())
     }
 }
 
@@ -178,7 +318,7 @@ impl std::fmt::Display for PrivateExtensionList {
 
         f.write_str("-x")?;
 
-        for subtag in &self.0 {
+        for subtag in &self.tags {
             write!(f, "-{}", subtag)?;
         }
         Ok(())
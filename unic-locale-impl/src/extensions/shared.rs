@@ -0,0 +1,80 @@
+use std::ops::Deref;
+use std::sync::Arc;
+
+use super::ExtensionsMap;
+
+/// A reference-counted `ExtensionsMap`, which is cloned in constant time and
+/// copied on the first mutation of a shared value.
+///
+/// It dereferences to `ExtensionsMap` for reading, while `make_mut` gives
+/// mutable access.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::extensions::SharedExtensionsMap;
+///
+/// let extensions: SharedExtensionsMap = "-u-ca-buddhist-hc-h12".parse::<unic_locale_impl::ExtensionsMap>()
+///     .expect("Parsing failed.")
+///     .into();
+///
+/// let mut copy = extensions.clone();
+/// assert!(SharedExtensionsMap::ptr_eq(&extensions, &copy));
+///
+/// copy.make_mut().unicode.remove_keyword("hc")
+///     .expect("Removing keyword failed.");
+///
+/// assert!(!SharedExtensionsMap::ptr_eq(&extensions, &copy));
+/// assert_eq!(extensions.to_string(), "-u-ca-buddhist-hc-h12");
+/// assert_eq!(copy.to_string(), "-u-ca-buddhist");
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Hash, PartialOrd, Ord)]
+pub struct SharedExtensionsMap(Arc<ExtensionsMap>);
+
+impl SharedExtensionsMap {
+    pub fn new(extensions: ExtensionsMap) -> Self {
+        Self(Arc::new(extensions))
+    }
+
+    /// Returns a mutable reference to the extensions, copying them first
+    /// if they are shared with other clones.
+    pub fn make_mut(&mut self) -> &mut ExtensionsMap {
+        Arc::make_mut(&mut self.0)
+    }
+
+    /// Returns the extensions, copying them if they are shared with other clones.
+    pub fn into_inner(self) -> ExtensionsMap {
+        Arc::try_unwrap(self.0).unwrap_or_else(|shared| (*shared).clone())
+    }
+
+    /// Returns `true` if both values share the same extensions.
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.0, &other.0)
+    }
+}
+
+impl Deref for SharedExtensionsMap {
+    type Target = ExtensionsMap;
+
+    fn deref(&self) -> &ExtensionsMap {
+        &self.0
+    }
+}
+
+impl From<ExtensionsMap> for SharedExtensionsMap {
+    fn from(extensions: ExtensionsMap) -> Self {
+        Self::new(extensions)
+    }
+}
+
+impl From<SharedExtensionsMap> for ExtensionsMap {
+    fn from(extensions: SharedExtensionsMap) -> Self {
+        extensions.into_inner()
+    }
+}
+
+impl std::fmt::Display for SharedExtensionsMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
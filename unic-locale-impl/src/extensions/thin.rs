@@ -0,0 +1,63 @@
+use std::iter::Peekable;
+use std::ops::ControlFlow;
+use std::str::FromStr;
+
+use crate::parser::{visit_extensions, ParserError};
+
+/// An empty placeholder for the map of extensions, used when the
+/// `extensions` feature is disabled.
+///
+/// A `Locale` carries no extensions, and parsing any extension fails.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Hash, PartialOrd, Ord)]
+pub struct ExtensionsMap {}
+
+impl ExtensionsMap {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ParserError> {
+        let bytes = match bytes {
+            [b'-' | b'_', rest @ ..] => rest,
+            _ => bytes,
+        };
+        if bytes.is_empty() {
+            return Ok(Self {});
+        }
+        let mut iterator = bytes.split(|c| *c == b'-' || *c == b'_').peekable();
+        Self::try_from_iter(&mut iterator, false)
+    }
+
+    pub(crate) fn try_from_iter<'a, I>(
+        iter: &mut Peekable<I>,
+        lenient: bool,
+    ) -> Result<Self, ParserError>
+    where
+        I: Iterator<Item = &'a [u8]> + Clone,
+    {
+        let _ = visit_extensions(iter, lenient, &mut |_| ControlFlow::Continue(()))?;
+        Ok(Self {})
+    }
+
+    pub fn is_empty(&self) -> bool {
+        true
+    }
+
+    pub fn is_lenient(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn is_private_use_only(&self) -> bool {
+        false
+    }
+}
+
+impl FromStr for ExtensionsMap {
+    type Err = ParserError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes(source.as_bytes())
+    }
+}
+
+impl std::fmt::Display for ExtensionsMap {
+    fn fmt(&self, _f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        Ok(())
+    }
+}
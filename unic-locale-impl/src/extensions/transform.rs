@@ -1,12 +1,13 @@
 use crate::errors::LocaleError;
+use crate::extensions::{ExtensionType, ParseMode, ValueStr, TRUE_VALUE};
 use crate::parser::ParserError;
 
 use unic_langid_impl::LanguageIdentifier;
 
 use std::collections::BTreeMap;
-use std::iter::Peekable;
+use std::fmt::Write;
 
-use tinystr::{TinyStr4, TinyStr8};
+use tinystr::TinyStr4;
 
 /// A list of [`Unicode BCP47 T Extensions`] as defined in [`Unicode Locale
 /// Identifier`] specification.
@@ -41,8 +42,11 @@ use tinystr::{TinyStr4, TinyStr8};
 pub struct TransformExtensionList {
     tlang: Option<LanguageIdentifier>,
 
-    // Canonical: sort by key (BTreeMap is already) / remove value 'true'
-    tfields: BTreeMap<TinyStr4, Vec<TinyStr8>>,
+    // Canonical: sort by key (BTreeMap is already) / remove value 'true' /
+    // keep the first of duplicate keys
+    tfields: BTreeMap<TinyStr4, Vec<ValueStr>>,
+
+    pub(crate) mode: ParseMode,
 }
 
 fn parse_tkey(key: &[u8]) -> Result<TinyStr4, ParserError> {
@@ -53,26 +57,75 @@ fn parse_tkey(key: &[u8]) -> Result<TinyStr4, ParserError> {
     Ok(tkey.to_ascii_lowercase())
 }
 
-const TRUE_TVALUE: TinyStr8 = unsafe { TinyStr8::new_unchecked(1_702_195_828u64) }; // "true"
+// The tkeys defined by CLDR, sorted.
+static REGISTERED_TKEYS: &[&str] = &["d0", "h0", "i0", "k0", "m0", "s0", "t0", "x0"];
 
-fn parse_tvalue(t: &[u8]) -> Result<Option<TinyStr8>, ParserError> {
-    let s = TinyStr8::from_bytes(t).map_err(|_| ParserError::InvalidSubtag)?;
-    if t.len() < 3 || t.len() > 8 || !s.is_ascii_alphanumeric() {
-        return Err(ParserError::InvalidSubtag);
+/// A transform extension key, such as `m0` or `h0`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::extensions::TransformExtensionKey;
+///
+/// let key: TransformExtensionKey = "M0".parse()
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(key.as_str(), "m0");
+/// assert!(key.is_registered());
+/// assert!(!"z9".parse::<TransformExtensionKey>().unwrap().is_registered());
+/// assert!("mm".parse::<TransformExtensionKey>().is_err());
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct TransformExtensionKey(TinyStr4);
+
+impl TransformExtensionKey {
+    pub fn from_bytes(v: &[u8]) -> Result<Self, ParserError> {
+        parse_tkey(v).map(Self)
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Returns `true` if the key is one of the tkeys defined by CLDR, such as
+    /// `m0` for the transform mechanism.
+    pub fn is_registered(&self) -> bool {
+        REGISTERED_TKEYS.binary_search(&self.as_str()).is_ok()
+    }
+}
+
+impl std::str::FromStr for TransformExtensionKey {
+    type Err = ParserError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes(source.as_bytes())
+    }
+}
+
+impl std::fmt::Display for TransformExtensionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
     }
+}
+
+/// Parses a single tvalue subtag of up to `ParseMode::max_value_length` characters.
+fn parse_tvalue(t: &[u8], mode: ParseMode) -> Result<Option<ValueStr>, ParserError> {
+    let max_len = mode.max_value_length();
+    ParserError::check_extension_subtag(ExtensionType::Transform, t, 3..=max_len)?;
+    let s = ValueStr::from_bytes(t).map_err(|_| ParserError::InvalidSubtag)?;
 
     let s = s.to_ascii_lowercase();
 
-    if s == TRUE_TVALUE {
+    if s == TRUE_VALUE {
         Ok(None)
     } else {
         Ok(Some(s))
     }
 }
 
-fn is_language_subtag(t: &[u8]) -> bool {
+pub(crate) fn is_language_subtag(t: &[u8]) -> bool {
     let slen = t.len();
-    (slen >= 2 && slen <= 8 || slen == 4) && !t.iter().any(|c: &u8| !c.is_ascii_alphabetic())
+    (2..=8).contains(&slen) && !t.iter().any(|c: &u8| !c.is_ascii_alphabetic())
 }
 
 impl TransformExtensionList {
@@ -93,6 +146,17 @@ impl TransformExtensionList {
         self.tlang.is_none() && self.tfields.is_empty()
     }
 
+    /// Returns an iterator over the stored keys and values of all tfields,
+    /// sorted by key.
+    ///
+    /// Not stable, and only available with the `unstable` feature.
+    #[cfg(feature = "unstable")]
+    pub fn raw_tfields(&self) -> impl ExactSizeIterator<Item = (TinyStr4, &[ValueStr])> {
+        self.tfields
+            .iter()
+            .map(|(key, values)| (*key, values.as_slice()))
+    }
+
     /// Gets tlang from the `TransformExtensionList`.
     ///
     /// # Examples
@@ -130,7 +194,7 @@ impl TransformExtensionList {
     /// loc.extensions.transform.set_tlang(tlang)
     ///     .expect("Setting tlang failed.");
     ///
-    /// assert_eq!(loc.to_string(), "en-US-t-es-AR");
+    /// assert_eq!(loc.to_string(), "en-US-t-es-ar");
     /// ```
     pub fn set_tlang(&mut self, tlang: LanguageIdentifier) -> Result<(), LocaleError> {
         self.tlang = Some(tlang);
@@ -183,13 +247,17 @@ impl TransformExtensionList {
         tkey: S,
     ) -> Result<impl ExactSizeIterator<Item = &str>, LocaleError> {
         let tfields: &[_] = match self.tfields.get(&parse_tkey(tkey.as_ref())?) {
-            Some(ref v) => &**v,
+            Some(v) => v,
             None => &[],
         };
 
         Ok(tfields.iter().map(|s| s.as_ref()))
     }
 
+    pub(crate) fn tvalues(&self, tkey: TransformExtensionKey) -> &[ValueStr] {
+        self.tfields.get(&tkey.0).map_or(&[], Vec::as_slice)
+    }
+
     /// Returns an iterator over all tkeys in the `TransformExtensionList`.
     ///
     /// # Examples
@@ -233,7 +301,7 @@ impl TransformExtensionList {
 
         let t = tvalue
             .iter()
-            .filter_map(|val| parse_tvalue(val.as_ref()).transpose())
+            .filter_map(|val| parse_tvalue(val.as_ref(), self.mode).transpose())
             .collect::<Result<Vec<_>, _>>()?;
 
         self.tfields.insert(tkey, t);
@@ -280,47 +348,41 @@ impl TransformExtensionList {
         self.tfields.clear();
     }
 
-    pub(crate) fn try_from_iter<'a>(
-        mut iter: &mut Peekable<impl Iterator<Item = &'a [u8]>>,
-    ) -> Result<Self, ParserError> {
-        let mut text = Self::default();
-
-        let mut st_peek = iter.peek();
-
-        let mut current_tkey = None;
-        let mut current_tvalue = vec![];
-
-        while let Some(subtag) = st_peek {
-            let slen = subtag.len();
-            if slen == 2 && subtag[0].is_ascii_alphabetic() && subtag[1].is_ascii_digit() {
-                if let Some(current_tkey) = current_tkey {
-                    text.tfields.insert(current_tkey, current_tvalue);
-                    current_tvalue = vec![];
-                }
-                current_tkey = Some(parse_tkey(subtag)?);
-                iter.next();
-            } else if current_tkey.is_some() {
-                if let Some(tval) = parse_tvalue(subtag)? {
-                    current_tvalue.push(tval);
-                }
-                iter.next();
-            } else if is_language_subtag(subtag) {
-                text.tlang = Some(
-                    LanguageIdentifier::try_from_iter(&mut iter, true)
-                        .map_err(|_| ParserError::InvalidLanguage)?,
-                );
-            } else {
-                break;
-            }
-            st_peek = iter.peek();
-        }
+    /// Sets the tlang from the subtags recognized by the parser.
+    pub(crate) fn set_parsed_tlang(&mut self, subtags: &[&str]) -> Result<(), ParserError> {
+        let mut iter = subtags.iter().map(|s| s.as_bytes()).peekable();
+        self.tlang = Some(
+            LanguageIdentifier::try_from_iter(&mut iter, false)
+                .map_err(|_| ParserError::InvalidLanguage)?,
+        );
+        Ok(())
+    }
 
-        if let Some(current_keyword) = current_tkey {
-            text.tfields.insert(current_keyword, current_tvalue);
+    /// Adds a tfield recognized by the parser, unless its tkey is already set.
+    pub(crate) fn insert_parsed_tfield(
+        &mut self,
+        tkey: &str,
+        tvalues: &[&str],
+    ) -> Result<(), ParserError> {
+        let tkey = parse_tkey(tkey.as_bytes())?;
+        let mut tvalue = vec![];
+        for subtag in tvalues {
+            if let Some(tval) = parse_tvalue(subtag.as_bytes(), self.mode)? {
+                tvalue.push(tval);
+            }
         }
+        self.tfields.entry(tkey).or_insert(tvalue);
+        Ok(())
+    }
+}
 
-        Ok(text)
+/// Writes a `-` separated subtag in lowercase, without allocating.
+fn write_lowercase(f: &mut std::fmt::Formatter, subtag: &str) -> std::fmt::Result {
+    f.write_char('-')?;
+    for c in subtag.chars() {
+        f.write_char(c.to_ascii_lowercase())?;
     }
+    Ok(())
 }
 
 impl std::fmt::Display for TransformExtensionList {
@@ -331,8 +393,18 @@ impl std::fmt::Display for TransformExtensionList {
 
         f.write_str("-t")?;
 
+        // Canonical: the tlang is lowercase.
         if let Some(tlang) = &self.tlang {
-            write!(f, "-{}", tlang)?;
+            write!(f, "-{}", tlang.language)?;
+            if let Some(script) = &tlang.script {
+                write_lowercase(f, script.as_str())?;
+            }
+            if let Some(region) = &tlang.region {
+                write_lowercase(f, region.as_str())?;
+            }
+            for variant in tlang.variants() {
+                write!(f, "-{}", variant)?;
+            }
         }
 
         for (k, t) in &self.tfields {
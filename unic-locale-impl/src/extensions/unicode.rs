@@ -1,16 +1,20 @@
 use crate::errors::LocaleError;
+use crate::extensions::keywords::Keyword;
+use crate::extensions::{
+    CalendarAlgorithm, Collation, ExtensionType, FirstDayOfWeek, HourCycle, NumberingSystem,
+    ParseMode, ValueStr, TRUE_VALUE,
+};
 use crate::parser::ParserError;
 
 use std::collections::BTreeMap;
-use std::iter::Peekable;
 use std::ops::RangeInclusive;
 
 use tinystr::{TinyStr4, TinyStr8};
+use unic_langid_impl::subtags::{Region, Script};
 
 /// Constants for locale extension key/value handling.
 const KEY_LENGTH: usize = 2;
-const TYPE_LENGTH: RangeInclusive<usize> = 3..=8;
-const ATTR_LENGTH: RangeInclusive<usize> = 3..=8;
+pub(crate) const ATTR_LENGTH: RangeInclusive<usize> = 3..=8;
 
 /// A list of [`Unicode BCP47 U Extensions`] as defined in [`Unicode Locale
 /// Identifier`] specification.
@@ -37,11 +41,14 @@ const ATTR_LENGTH: RangeInclusive<usize> = 3..=8;
 /// [`Unicode Locale Identifier`]: https://unicode.org/reports/tr35/#Unicode_locale_identifier
 #[derive(Clone, PartialEq, Eq, Debug, Default, Hash, PartialOrd, Ord)]
 pub struct UnicodeExtensionList {
-    // Canonical: sort by key (BTreeMap is already sorted) / remove value 'true'
-    keywords: BTreeMap<TinyStr4, Vec<TinyStr8>>,
+    // Canonical: sort by key (BTreeMap is already sorted) / remove value 'true' /
+    // keep the first of duplicate keys
+    keywords: BTreeMap<TinyStr4, Vec<ValueStr>>,
 
     // Canonical: sort / de-dup
     attributes: Vec<TinyStr8>,
+
+    pub(crate) mode: ParseMode,
 }
 
 fn parse_key(key: &[u8]) -> Result<TinyStr4, ParserError> {
@@ -52,38 +59,286 @@ fn parse_key(key: &[u8]) -> Result<TinyStr4, ParserError> {
     Ok(key.to_ascii_lowercase())
 }
 
-const TRUE_TYPE: TinyStr8 = unsafe { TinyStr8::new_unchecked(1_702_195_828u64) }; // "true"
+/// Parses a single keyword value subtag of up to `ParseMode::max_value_length` characters.
+fn parse_type(t: &[u8], mode: ParseMode) -> Result<ValueStr, ParserError> {
+    ParserError::check_extension_subtag(ExtensionType::Unicode, t, type_length(mode))?;
+    let s = ValueStr::from_bytes(t).map_err(|_| ParserError::InvalidSubtag)?;
 
-fn parse_type(t: &[u8]) -> Result<Option<TinyStr8>, ParserError> {
-    let s = TinyStr8::from_bytes(t).map_err(|_| ParserError::InvalidSubtag)?;
-    if !TYPE_LENGTH.contains(&t.len()) || !s.is_ascii_alphanumeric() {
-        return Err(ParserError::InvalidSubtag);
-    }
+    Ok(s.to_ascii_lowercase())
+}
 
-    let s = s.to_ascii_lowercase();
+/// Parses a keyword value which may consist of multiple subtags,
+/// such as `islamic-civil`, appending them to `types`.
+fn parse_types(t: &[u8], types: &mut Vec<ValueStr>, mode: ParseMode) -> Result<(), ParserError> {
+    for subtag in t.split(|c| *c == b'-' || *c == b'_') {
+        types.push(parse_type(subtag, mode)?);
+    }
+    Ok(())
+}
 
-    if s == TRUE_TYPE {
-        Ok(None)
-    } else {
-        Ok(Some(s))
+/// Canonical: a value consisting of just `true` is removed.
+fn canonicalize_types(mut types: Vec<ValueStr>) -> Vec<ValueStr> {
+    if types.len() == 1 && types[0] == TRUE_VALUE {
+        types.clear();
     }
+    types
 }
 
 fn parse_attribute(t: &[u8]) -> Result<TinyStr8, ParserError> {
+    ParserError::check_extension_subtag(ExtensionType::Unicode, t, ATTR_LENGTH)?;
     let s = TinyStr8::from_bytes(t).map_err(|_| ParserError::InvalidSubtag)?;
-    if !ATTR_LENGTH.contains(&t.len()) || !s.is_ascii_alphanumeric() {
-        return Err(ParserError::InvalidSubtag);
-    }
 
     Ok(s.to_ascii_lowercase())
 }
 
-fn is_type(t: &[u8]) -> bool {
-    let slen = t.len();
-    TYPE_LENGTH.contains(&slen) && !t.iter().any(|c: &u8| !c.is_ascii_alphanumeric())
+/// Allowed shapes of keyword values, used in strict mode.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum KeywordValues {
+    /// One of a fixed list of values, possibly spanning multiple subtags.
+    List(&'static [&'static str]),
+    /// `true` (stored as an empty value) or `false`.
+    Boolean,
+    /// A three letter ISO 4217 currency code.
+    Currency,
+    /// A `unicode_region_subtag` followed by `zzzz`.
+    RegionOverride,
+    /// One or more unicode script subtags.
+    Scripts,
+}
+
+// The CLDR version the curated keyword validation and deprecation tables below follow.
+pub(crate) static CLDR_VERSION: &str = "47";
+
+// Sorted by key.
+static KEYWORD_VALUES: &[(&str, KeywordValues)] = &[
+    (
+        "ca",
+        KeywordValues::List(&[
+            "buddhist",
+            "chinese",
+            "coptic",
+            "dangi",
+            "ethioaa",
+            "ethiopic",
+            "gregory",
+            "hebrew",
+            "indian",
+            "islamic",
+            "islamic-civil",
+            "islamic-rgsa",
+            "islamic-tbla",
+            "islamic-umalqura",
+            "iso8601",
+            "japanese",
+            "persian",
+            "roc",
+        ]),
+    ),
+    ("cf", KeywordValues::List(&["account", "standard"])),
+    (
+        "co",
+        KeywordValues::List(&[
+            "compat", "dict", "ducet", "emoji", "eor", "phonebk", "phonetic", "pinyin", "search",
+            "searchjl", "standard", "stroke", "trad", "unihan", "zhuyin",
+        ]),
+    ),
+    ("cu", KeywordValues::Currency),
+    ("dx", KeywordValues::Scripts),
+    ("em", KeywordValues::List(&["default", "emoji", "text"])),
+    (
+        "fw",
+        KeywordValues::List(&["fri", "mon", "sat", "sun", "thu", "tue", "wed"]),
+    ),
+    ("hc", KeywordValues::List(&["h11", "h12", "h23", "h24"])),
+    ("ka", KeywordValues::List(&["noignore", "shifted"])),
+    ("kb", KeywordValues::Boolean),
+    ("kc", KeywordValues::Boolean),
+    ("kf", KeywordValues::List(&["false", "lower", "upper"])),
+    ("kh", KeywordValues::Boolean),
+    ("kk", KeywordValues::Boolean),
+    ("kn", KeywordValues::Boolean),
+    ("lb", KeywordValues::List(&["loose", "normal", "strict"])),
+    (
+        "lw",
+        KeywordValues::List(&["breakall", "keepall", "normal", "phrase"]),
+    ),
+    (
+        "ms",
+        KeywordValues::List(&["metric", "uksystem", "ussystem"]),
+    ),
+    ("rg", KeywordValues::RegionOverride),
+    ("ss", KeywordValues::List(&["none", "standard"])),
+];
+
+/// A unicode extension key, such as `ca` or `hc`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::extensions::{KeywordValues, UnicodeExtensionKey};
+///
+/// let key: UnicodeExtensionKey = "hc".parse()
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(
+///     key.allowed_values(),
+///     Some(KeywordValues::List(&["h11", "h12", "h23", "h24"]))
+/// );
+///
+/// let key: UnicodeExtensionKey = "tz".parse()
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(key.allowed_values(), None);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+pub struct UnicodeExtensionKey(TinyStr4);
+
+impl UnicodeExtensionKey {
+    pub fn from_bytes(v: &[u8]) -> Result<Self, ParserError> {
+        parse_key(v).map(Self)
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+
+    /// Returns the values allowed for the key, as used by strict validation,
+    /// or `None` if the key accepts any well-formed value.
+    pub fn allowed_values(&self) -> Option<KeywordValues> {
+        KEYWORD_VALUES
+            .binary_search_by_key(&self.as_str(), |(k, _)| k)
+            .ok()
+            .map(|idx| KEYWORD_VALUES[idx].1)
+    }
+
+    /// Returns an iterator over all keys with restricted values, sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::extensions::UnicodeExtensionKey;
+    ///
+    /// assert!(UnicodeExtensionKey::restricted().any(|key| key.as_str() == "ca"));
+    /// ```
+    pub fn restricted() -> impl ExactSizeIterator<Item = Self> {
+        KEYWORD_VALUES
+            .iter()
+            .map(|(key, _)| Self::from_bytes(key.as_bytes()).expect("Keys are well-formed."))
+    }
+}
+
+impl std::str::FromStr for UnicodeExtensionKey {
+    type Err = ParserError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Self::from_bytes(source.as_bytes())
+    }
+}
+
+impl std::fmt::Display for UnicodeExtensionKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+fn is_valid_region_override(t: &str) -> bool {
+    t.len() > 4 && t.ends_with("zzzz") && Region::from_bytes(&t.as_bytes()[..t.len() - 4]).is_ok()
+}
+
+fn validate_keyword(key: &TinyStr4, types: &[ValueStr]) -> Result<(), ParserError> {
+    let shape = match KEYWORD_VALUES.binary_search_by_key(&key.as_str(), |(k, _)| k) {
+        Ok(idx) => &KEYWORD_VALUES[idx].1,
+        Err(_) => return Ok(()),
+    };
+
+    let valid = match (shape, types) {
+        (KeywordValues::List(list), [t]) => list.contains(&t.as_str()),
+        (KeywordValues::List(list), [_, _, ..]) => {
+            let value = types
+                .iter()
+                .map(|t| t.as_str())
+                .collect::<Vec<_>>()
+                .join("-");
+            list.contains(&value.as_str())
+        }
+        (KeywordValues::Boolean, []) => true,
+        (KeywordValues::Boolean, [t]) => t.as_str() == "false",
+        (KeywordValues::Currency, [t]) => t.len() == 3 && t.is_ascii_alphabetic(),
+        (KeywordValues::RegionOverride, [t]) => is_valid_region_override(t),
+        (KeywordValues::Scripts, [_, ..]) => types
+            .iter()
+            .all(|t| Script::from_bytes(t.as_bytes()).is_ok()),
+        _ => false,
+    };
+
+    if valid {
+        Ok(())
+    } else if types.is_empty() {
+        Err(ParserError::KeyWithoutValue {
+            key: key.to_string(),
+        })
+    } else {
+        let value = types
+            .iter()
+            .map(|t| t.as_str())
+            .collect::<Vec<_>>()
+            .join("-");
+        Err(ParserError::InvalidKeywordValue {
+            key: key.to_string(),
+            value,
+        })
+    }
+}
+
+// Deprecated keys without a replacement, dropped during canonicalization.
+static DEPRECATED_KEYS: &[&str] = &["kh", "vt"];
+
+// Deprecated keyword values and their preferred replacements, sorted by key and value.
+//
+// Time zone entries come from the CLDR `bcp47/timezone.json` `_preferred` data.
+static DEPRECATED_VALUES: &[(&str, &str, &str)] = &[
+    ("ca", "ethiopic-amete-alem", "ethioaa"),
+    ("ca", "islamicc", "islamic-civil"),
+    ("kb", "yes", "true"),
+    ("kc", "yes", "true"),
+    ("kk", "yes", "true"),
+    ("kn", "yes", "true"),
+    ("ks", "primary", "level1"),
+    ("ks", "tertiary", "level3"),
+    ("ms", "imperial", "uksystem"),
+    ("tz", "aqams", "nzakl"),
+    ("tz", "aukns", "auhba"),
+    ("tz", "caffs", "cawnp"),
+    ("tz", "camtr", "cator"),
+    ("tz", "canpg", "cator"),
+    ("tz", "capnt", "caiql"),
+    ("tz", "cathu", "cator"),
+    ("tz", "cayzf", "caedm"),
+    ("tz", "cnckg", "cnsha"),
+    ("tz", "cnhrb", "cnsha"),
+    ("tz", "cnkhg", "cnurc"),
+    ("tz", "cst6cdt", "uschi"),
+    ("tz", "est5edt", "usnyc"),
+    ("tz", "gaza", "gazastrp"),
+    ("tz", "mncoq", "mnuln"),
+    ("tz", "mst7mdt", "usden"),
+    ("tz", "mxstis", "mxtij"),
+    ("tz", "pst8pdt", "uslax"),
+    ("tz", "uaozh", "uaiev"),
+    ("tz", "uauzh", "uaiev"),
+    ("tz", "umjon", "ushnl"),
+    ("tz", "usnavajo", "usden"),
+];
+
+pub(crate) fn type_length(mode: ParseMode) -> RangeInclusive<usize> {
+    3..=mode.max_value_length()
 }
 
-fn is_attribute(t: &[u8]) -> bool {
+pub(crate) fn is_type(t: &[u8], mode: ParseMode) -> bool {
+    type_length(mode).contains(&t.len()) && !t.iter().any(|c: &u8| !c.is_ascii_alphanumeric())
+}
+
+pub(crate) fn is_attribute(t: &[u8]) -> bool {
     let slen = t.len();
     ATTR_LENGTH.contains(&slen) && !t.iter().any(|c: &u8| !c.is_ascii_alphanumeric())
 }
@@ -132,7 +387,7 @@ impl UnicodeExtensionList {
         key: S,
     ) -> Result<impl ExactSizeIterator<Item = &str>, LocaleError> {
         let keywords: &[_] = match self.keywords.get(&parse_key(key.as_ref())?) {
-            Some(ref v) => &**v,
+            Some(v) => v,
             None => &[],
         };
 
@@ -156,9 +411,59 @@ impl UnicodeExtensionList {
         self.keywords.keys().map(|s| s.as_ref())
     }
 
+    /// Returns an iterator over the stored keys and values of all keywords,
+    /// sorted by key, with `true` values stored as no values.
+    ///
+    /// Not stable, and only available with the `unstable` feature.
+    #[cfg(feature = "unstable")]
+    pub fn raw_keywords(&self) -> impl ExactSizeIterator<Item = (TinyStr4, &[ValueStr])> {
+        self.keywords
+            .iter()
+            .map(|(key, types)| (*key, types.as_slice()))
+    }
+
+    /// Returns the stored attributes, sorted.
+    ///
+    /// Not stable, and only available with the `unstable` feature.
+    #[cfg(feature = "unstable")]
+    pub fn raw_attributes(&self) -> &[TinyStr8] {
+        &self.attributes
+    }
+
+    /// Returns the region of the `rg` (region override) keyword, if it is
+    /// present and well-formed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "en-US-u-rg-gbzzzz".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.extensions.unicode.region_override(), Some("GB".parse().unwrap()));
+    ///
+    /// let loc: Locale = "en-US-u-rg-gbxxxx".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.extensions.unicode.region_override(), None);
+    /// ```
+    pub fn region_override(&self) -> Option<Region> {
+        let key = parse_key(b"rg").expect("Key is well-formed.");
+        match self.keywords.get(&key).map(Vec::as_slice) {
+            Some([t]) if is_valid_region_override(t) => {
+                Region::from_bytes(&t.as_bytes()[..t.len() - 4]).ok()
+            }
+            _ => None,
+        }
+    }
+
     /// Adds a keyword to the `UnicodeExtensionList` or sets value for key if
     /// keyword is already included in the `UnicodeExtensionList`.
     ///
+    /// Values spanning multiple subtags may be passed either as separate
+    /// items, or as a single `-` separated item.
+    ///
     /// # Examples
     ///
     /// ```
@@ -172,23 +477,295 @@ impl UnicodeExtensionList {
     ///
     /// assert_eq!(loc.to_string(), "en-US-u-ca-buddhist");
     ///
-    /// loc.extensions.unicode.set_keyword("ca", &["chinese"])
+    /// loc.extensions.unicode.set_keyword("ca", &["islamic-civil"])
     ///     .expect("Setting keyword failed.");
     ///
-    /// assert_eq!(loc.to_string(), "en-US-u-ca-chinese");
+    /// assert_eq!(loc.to_string(), "en-US-u-ca-islamic-civil");
+    /// assert_eq!(loc.extensions.unicode.keyword("ca")
+    ///                .expect("Getting keyword failed.")
+    ///                .collect::<Vec<_>>(),
+    ///            &["islamic", "civil"]);
     /// ```
     pub fn set_keyword<S: AsRef<[u8]>>(&mut self, key: S, value: &[S]) -> Result<(), LocaleError> {
         let key = parse_key(key.as_ref())?;
 
-        let t = value
-            .iter()
-            .filter_map(|t| parse_type(t.as_ref()).transpose())
-            .collect::<Result<Vec<_>, _>>()?;
+        let mut t = vec![];
+        for v in value {
+            parse_types(v.as_ref(), &mut t, self.mode)?;
+        }
 
+        self.keywords.insert(key, canonicalize_types(t));
+        Ok(())
+    }
+
+    /// Works like [`set_keyword`], but additionally validates the value against
+    /// the values allowed for the key.
+    ///
+    /// Keys without known restrictions accept any well-formed value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc: Locale = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// loc.extensions.unicode.set_keyword_strict("hc", &["h12"])
+    ///     .expect("Setting keyword failed.");
+    ///
+    /// assert_eq!(loc.to_string(), "en-US-u-hc-h12");
+    ///
+    /// assert!(loc.extensions.unicode.set_keyword_strict("hc", &["h13"]).is_err());
+    /// assert_eq!(loc.to_string(), "en-US-u-hc-h12");
+    /// ```
+    ///
+    /// [`set_keyword`]: #method.set_keyword
+    pub fn set_keyword_strict<S: AsRef<[u8]>>(
+        &mut self,
+        key: S,
+        value: &[S],
+    ) -> Result<(), LocaleError> {
+        let key = parse_key(key.as_ref())?;
+
+        let mut t = vec![];
+        for v in value {
+            parse_types(v.as_ref(), &mut t, self.mode)?;
+        }
+        let t = canonicalize_types(t);
+
+        validate_keyword(&key, &t)?;
         self.keywords.insert(key, t);
         Ok(())
     }
 
+    /// Returns the scripts excluded from dictionary based line and word breaking,
+    /// as given by the `dx` keyword.
+    ///
+    /// Values which are not script subtags are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "th-u-dx-thai-laoo".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let scripts = loc.extensions.unicode.dictionary_break_exclusions();
+    /// assert_eq!(scripts.len(), 2);
+    /// assert_eq!(scripts[0].as_str(), "Thai");
+    /// assert_eq!(scripts[1].as_str(), "Laoo");
+    /// ```
+    pub fn dictionary_break_exclusions(&self) -> Vec<Script> {
+        let key = parse_key(b"dx").expect("Key is well-formed.");
+        self.keywords
+            .get(&key)
+            .map(|types| {
+                types
+                    .iter()
+                    .filter_map(|t| Script::from_bytes(t.as_bytes()).ok())
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Sets the scripts excluded from dictionary based line and word breaking,
+    /// as the `dx` keyword. An empty list removes the keyword.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc: Locale = "th".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// loc.extensions.unicode.set_dictionary_break_exclusions(&["Thai".parse().unwrap()]);
+    /// assert_eq!(loc.to_string(), "th-u-dx-thai");
+    ///
+    /// loc.extensions.unicode.set_dictionary_break_exclusions(&[]);
+    /// assert_eq!(loc.to_string(), "th");
+    /// ```
+    pub fn set_dictionary_break_exclusions(&mut self, scripts: &[Script]) {
+        let key = parse_key(b"dx").expect("Key is well-formed.");
+        if scripts.is_empty() {
+            self.keywords.remove(&key);
+            return;
+        }
+        let types = scripts
+            .iter()
+            .map(|script| {
+                parse_type(script.as_str().as_bytes(), ParseMode::default())
+                    .expect("Scripts are valid types.")
+            })
+            .collect();
+        self.keywords.insert(key, types);
+    }
+
+    fn typed_keyword<K: Keyword>(&self) -> Option<K> {
+        let key = parse_key(K::KEY).expect("Key is well-formed.");
+        let types = self.keywords.get(&key)?;
+        let value: Vec<&str> = types.iter().map(|t| t.as_str()).collect();
+        K::from_value(&value.join("-"))
+    }
+
+    fn set_typed_keyword<K: Keyword>(&mut self, value: Option<K>) {
+        let key = parse_key(K::KEY).expect("Key is well-formed.");
+        match value {
+            Some(value) => {
+                let mut types = vec![];
+                parse_types(value.as_str().as_bytes(), &mut types, ParseMode::default())
+                    .expect("Keyword values are valid types.");
+                self.keywords.insert(key, types);
+            }
+            None => {
+                self.keywords.remove(&key);
+            }
+        }
+    }
+
+    /// Returns the hour cycle of the `hc` keyword, or `None` if it is absent
+    /// or has an unknown value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    /// use unic_locale_impl::extensions::HourCycle;
+    ///
+    /// let mut loc: Locale = "en-US-u-hc-h12".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.extensions.unicode.hour_cycle(), Some(HourCycle::H12));
+    ///
+    /// loc.extensions.unicode.set_hour_cycle(Some(HourCycle::H23));
+    /// assert_eq!(loc.to_string(), "en-US-u-hc-h23");
+    ///
+    /// loc.extensions.unicode.set_hour_cycle(None);
+    /// assert_eq!(loc.to_string(), "en-US");
+    /// ```
+    pub fn hour_cycle(&self) -> Option<HourCycle> {
+        self.typed_keyword()
+    }
+
+    /// Sets the `hc` keyword to the hour cycle, or removes it if `None`.
+    pub fn set_hour_cycle(&mut self, value: Option<HourCycle>) {
+        self.set_typed_keyword(value)
+    }
+
+    /// Returns the calendar algorithm of the `ca` keyword, or `None` if it is
+    /// absent or has an unknown value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    /// use unic_locale_impl::extensions::CalendarAlgorithm;
+    ///
+    /// let mut loc: Locale = "ar-SA-u-ca-islamic-umalqura".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(
+    ///     loc.extensions.unicode.calendar(),
+    ///     Some(CalendarAlgorithm::IslamicUmalqura)
+    /// );
+    ///
+    /// loc.extensions.unicode.set_calendar(Some(CalendarAlgorithm::Gregory));
+    /// assert_eq!(loc.to_string(), "ar-SA-u-ca-gregory");
+    /// ```
+    pub fn calendar(&self) -> Option<CalendarAlgorithm> {
+        self.typed_keyword()
+    }
+
+    /// Sets the `ca` keyword to the calendar algorithm, or removes it if `None`.
+    pub fn set_calendar(&mut self, value: Option<CalendarAlgorithm>) {
+        self.set_typed_keyword(value)
+    }
+
+    /// Returns the collation type of the `co` keyword, or `None` if it is
+    /// absent or has an unknown value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    /// use unic_locale_impl::extensions::Collation;
+    ///
+    /// let loc: Locale = "de-u-co-phonebk".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.extensions.unicode.collation(), Some(Collation::Phonebk));
+    /// ```
+    pub fn collation(&self) -> Option<Collation> {
+        self.typed_keyword()
+    }
+
+    /// Sets the `co` keyword to the collation type, or removes it if `None`.
+    pub fn set_collation(&mut self, value: Option<Collation>) {
+        self.set_typed_keyword(value)
+    }
+
+    /// Returns the numbering system of the `nu` keyword, or `None` if it is
+    /// absent or not one of the common decimal numbering systems.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    /// use unic_locale_impl::extensions::NumberingSystem;
+    ///
+    /// let loc: Locale = "th-u-nu-thai".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.extensions.unicode.numbering_system(), Some(NumberingSystem::Thai));
+    ///
+    /// // Other numbering systems are available as raw values.
+    /// let loc: Locale = "en-u-nu-roman".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.extensions.unicode.numbering_system(), None);
+    /// assert_eq!(
+    ///     loc.extensions.unicode.keyword("nu")
+    ///         .expect("Getting keyword failed.")
+    ///         .collect::<Vec<_>>(),
+    ///     &["roman"]
+    /// );
+    /// ```
+    pub fn numbering_system(&self) -> Option<NumberingSystem> {
+        self.typed_keyword()
+    }
+
+    /// Sets the `nu` keyword to the numbering system, or removes it if `None`.
+    pub fn set_numbering_system(&mut self, value: Option<NumberingSystem>) {
+        self.set_typed_keyword(value)
+    }
+
+    /// Returns the first day of the week of the `fw` keyword, or `None` if it
+    /// is absent or has an unknown value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    /// use unic_locale_impl::extensions::FirstDayOfWeek;
+    ///
+    /// let mut loc: Locale = "en-GB".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// loc.extensions.unicode.set_first_day_of_week(Some(FirstDayOfWeek::Sun));
+    /// assert_eq!(loc.to_string(), "en-GB-u-fw-sun");
+    /// assert_eq!(loc.extensions.unicode.first_day_of_week(), Some(FirstDayOfWeek::Sun));
+    /// ```
+    pub fn first_day_of_week(&self) -> Option<FirstDayOfWeek> {
+        self.typed_keyword()
+    }
+
+    /// Sets the `fw` keyword to the first day of the week, or removes it if `None`.
+    pub fn set_first_day_of_week(&mut self, value: Option<FirstDayOfWeek>) {
+        self.set_typed_keyword(value)
+    }
+
     /// Removes a keyword from the `UnicodeExtensionList`.
     ///
     /// Returns `true` if keyword was included in the `UnicodeExtensionList`
@@ -336,47 +913,88 @@ impl UnicodeExtensionList {
         self.attributes.clear();
     }
 
-    pub(crate) fn try_from_iter<'a>(
-        iter: &mut Peekable<impl Iterator<Item = &'a [u8]>>,
-    ) -> Result<Self, ParserError> {
-        let mut uext = Self::default();
-
-        let mut st_peek = iter.peek();
-
-        let mut current_keyword = None;
-        let mut current_types = vec![];
-
-        while let Some(subtag) = st_peek {
-            let slen = subtag.len();
-            if slen == 2 {
-                if let Some(current_keyword) = current_keyword {
-                    uext.keywords.insert(current_keyword, current_types);
-                    current_types = vec![];
-                }
-                current_keyword = Some(parse_key(subtag)?);
-                iter.next();
-            } else if current_keyword.is_some() && is_type(subtag) {
-                if let Some(ty) = parse_type(subtag)? {
-                    current_types.push(ty);
-                }
-                iter.next();
-            } else if is_attribute(subtag) {
-                uext.attributes.push(parse_attribute(subtag)?);
-                iter.next();
-            } else {
-                break;
+    /// Replaces deprecated keyword values with their preferred form and removes
+    /// deprecated keys, following CLDR.
+    ///
+    /// Returns `true` if the `UnicodeExtensionList` has been modified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc: Locale = "en-u-ca-islamicc-kh-tz-cnckg".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert!(loc.extensions.unicode.replace_deprecated());
+    /// assert_eq!(loc.to_string(), "en-u-ca-islamic-civil-tz-cnsha");
+    /// ```
+    pub fn replace_deprecated(&mut self) -> bool {
+        let len = self.keywords.len();
+        self.keywords
+            .retain(|key, _| !DEPRECATED_KEYS.contains(&key.as_str()));
+        let mut modified = self.keywords.len() != len;
+
+        for (key, types) in self.keywords.iter_mut() {
+            let value = types
+                .iter()
+                .map(|t| t.as_str())
+                .collect::<Vec<_>>()
+                .join("-");
+            if let Ok(idx) = DEPRECATED_VALUES
+                .binary_search_by_key(&(key.as_str(), value.as_str()), |(k, v, _)| (k, v))
+            {
+                let mut preferred = vec![];
+                parse_types(
+                    DEPRECATED_VALUES[idx].2.as_bytes(),
+                    &mut preferred,
+                    ParseMode::default(),
+                )
+                .expect("Preferred values are well-formed.");
+                *types = canonicalize_types(preferred);
+                modified = true;
             }
-            st_peek = iter.peek();
         }
+        modified
+    }
 
-        if let Some(current_keyword) = current_keyword {
-            uext.keywords.insert(current_keyword, current_types);
-        }
+    /// Validates all keywords against the values allowed for their keys.
+    pub(crate) fn validate(&self) -> Result<(), ParserError> {
+        self.keyword_errors().next().map_or(Ok(()), Err)
+    }
 
-        uext.attributes.sort_unstable();
-        uext.attributes.dedup();
+    /// Returns the errors of all keywords with values not allowed for their keys.
+    pub(crate) fn keyword_errors(&self) -> impl Iterator<Item = ParserError> + '_ {
+        self.keywords
+            .iter()
+            .filter_map(|(key, types)| validate_keyword(key, types).err())
+    }
 
-        Ok(uext)
+    /// Adds an attribute recognized by the parser, keeping the attributes sorted
+    /// and deduplicated.
+    pub(crate) fn push_parsed_attribute(&mut self, attribute: &[u8]) -> Result<(), ParserError> {
+        let attribute = parse_attribute(attribute)?;
+        if let Err(idx) = self.attributes.binary_search(&attribute) {
+            self.attributes.insert(idx, attribute);
+        }
+        Ok(())
+    }
+
+    /// Adds a keyword recognized by the parser, unless its key is already set.
+    pub(crate) fn insert_parsed_keyword(
+        &mut self,
+        key: &str,
+        types: &[&str],
+    ) -> Result<(), ParserError> {
+        let key = parse_key(key.as_bytes())?;
+        let types = types
+            .iter()
+            .map(|t| parse_type(t.as_bytes(), self.mode))
+            .collect::<Result<_, _>>()?;
+        self.keywords
+            .entry(key)
+            .or_insert_with(|| canonicalize_types(types));
+        Ok(())
     }
 }
 
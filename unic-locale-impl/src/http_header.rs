@@ -0,0 +1,59 @@
+//! Conversions between `http::HeaderValue` and locales.
+use crate::accept_language::AcceptLanguage;
+use crate::Locale;
+use ::http::header::{HeaderValue, InvalidHeaderValue, ToStrError};
+use std::convert::TryFrom;
+
+/// Parses an `Accept-Language` header value.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use http::HeaderValue;
+/// use unic_locale_impl::accept_language::AcceptLanguage;
+///
+/// let header = HeaderValue::from_static("fr-CA, fr;q=0.9");
+/// let list = AcceptLanguage::try_from(&header)
+///     .expect("Header is not visible ASCII.");
+///
+/// assert_eq!(list.0.len(), 2);
+/// ```
+impl TryFrom<&HeaderValue> for AcceptLanguage {
+    type Error = ToStrError;
+
+    fn try_from(value: &HeaderValue) -> Result<Self, Self::Error> {
+        Ok(AcceptLanguage::parse(value.to_str()?))
+    }
+}
+
+/// Serializes an `Accept-Language` header value.
+impl TryFrom<&AcceptLanguage> for HeaderValue {
+    type Error = InvalidHeaderValue;
+
+    fn try_from(value: &AcceptLanguage) -> Result<Self, Self::Error> {
+        HeaderValue::from_str(&value.to_string())
+    }
+}
+
+/// Serializes a `Locale`, for use in a `Content-Language` header.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use http::HeaderValue;
+/// use unic_locale_impl::Locale;
+///
+/// let loc: Locale = "en-US".parse()
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(HeaderValue::try_from(&loc).unwrap(), "en-US");
+/// ```
+impl TryFrom<&Locale> for HeaderValue {
+    type Error = InvalidHeaderValue;
+
+    fn try_from(value: &Locale) -> Result<Self, Self::Error> {
+        HeaderValue::from_str(&value.to_string())
+    }
+}
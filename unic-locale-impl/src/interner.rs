@@ -0,0 +1,146 @@
+//! A pool of deduplicated locales, referenced by `Copy` handles.
+use crate::errors::LocaleError;
+use crate::Locale;
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::ops::Index;
+
+/// A handle to a `Locale` stored in a [`LocaleInterner`].
+///
+/// Handles are only meaningful for the interner which returned them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct LocaleHandle(u32);
+
+impl LocaleHandle {
+    /// Returns the position of the locale in the interner, in the order the
+    /// locales were first interned.
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// A pool of deduplicated locales, handing out a `Copy` handle for each of them.
+///
+/// Interning equal locales returns the same handle, so code holding many references
+/// to a few locales can store and compare handles instead of the locales.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::{Locale, LocaleInterner};
+///
+/// let mut interner = LocaleInterner::new();
+///
+/// let en_us = interner.intern_str("en-US").expect("Parsing failed.");
+/// let fr = interner.intern_str("fr").expect("Parsing failed.");
+/// assert_eq!(interner.intern_str("EN_us").expect("Parsing failed."), en_us);
+///
+/// assert_eq!(interner.len(), 2);
+/// assert_ne!(en_us, fr);
+/// assert_eq!(interner[fr].to_string(), "fr");
+///
+/// let loc: Locale = "fr".parse().expect("Parsing failed.");
+/// assert_eq!(interner.get(&loc), Some(fr));
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct LocaleInterner {
+    locales: Vec<Locale>,
+    // Keyed by the hash of the locale, so that each locale is only stored once,
+    // in `locales`.
+    handles: HashMap<u64, Vec<LocaleHandle>>,
+    hasher: RandomState,
+}
+
+impl LocaleInterner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the handle of the locale, adding it to the interner if it is not
+    /// there yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the interner already holds `u32::MAX` locales.
+    pub fn intern(&mut self, locale: Locale) -> LocaleHandle {
+        let hash = self.hash(&locale);
+        if let Some(handle) = self.find(hash, &locale) {
+            return handle;
+        }
+        let handle = LocaleHandle(
+            u32::try_from(self.locales.len()).expect("The interner has room for a locale."),
+        );
+        self.locales.push(locale);
+        self.handles.entry(hash).or_default().push(handle);
+        handle
+    }
+
+    /// Parses the input and returns the handle of the resulting locale.
+    pub fn intern_str<S: AsRef<[u8]>>(&mut self, input: S) -> Result<LocaleHandle, LocaleError> {
+        Ok(self.intern(Locale::from_bytes(input.as_ref())?))
+    }
+
+    /// Returns the handle of the locale, if it was interned.
+    pub fn get(&self, locale: &Locale) -> Option<LocaleHandle> {
+        self.find(self.hash(locale), locale)
+    }
+
+    /// Returns the locale of a handle, or `None` if the handle is not one of
+    /// this interner.
+    pub fn resolve(&self, handle: LocaleHandle) -> Option<&Locale> {
+        self.locales.get(handle.index())
+    }
+
+    /// Returns the number of distinct locales in the interner.
+    pub fn len(&self) -> usize {
+        self.locales.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.locales.is_empty()
+    }
+
+    fn hash(&self, locale: &Locale) -> u64 {
+        let mut hasher = self.hasher.build_hasher();
+        locale.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    fn find(&self, hash: u64, locale: &Locale) -> Option<LocaleHandle> {
+        self.handles
+            .get(&hash)?
+            .iter()
+            .copied()
+            .find(|handle| self.locales[handle.index()] == *locale)
+    }
+
+    /// Returns an iterator over the handles and locales, in the order the
+    /// locales were first interned.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = (LocaleHandle, &Locale)> {
+        self.locales
+            .iter()
+            .enumerate()
+            .map(|(idx, locale)| (LocaleHandle(idx as u32), locale))
+    }
+}
+
+impl Index<LocaleHandle> for LocaleInterner {
+    type Output = Locale;
+
+    /// # Panics
+    ///
+    /// Panics if the handle is not one of this interner.
+    fn index(&self, handle: LocaleHandle) -> &Locale {
+        &self.locales[handle.index()]
+    }
+}
+
+impl<L: Into<Locale>> Extend<L> for LocaleInterner {
+    fn extend<T: IntoIterator<Item = L>>(&mut self, iter: T) {
+        for locale in iter {
+            self.intern(locale.into());
+        }
+    }
+}
@@ -0,0 +1,125 @@
+//! Conversion between `Locale` and the string form of Java's `java.util.Locale`,
+//! such as `th_TH_#u-nu-thai`.
+use crate::errors::LocaleError;
+use crate::{subtags, Locale};
+
+/// The private use subtag after which Java stores variants which are not
+/// well-formed BCP47 variants, as in `de-x-lvariant-POSIX`.
+const LVARIANT: &str = "lvariant";
+
+// Returns the Java variants stored as private use subtags after `lvariant`,
+// and the extensions without them.
+#[cfg(feature = "extensions")]
+fn split_lvariant(locale: &Locale) -> (Vec<String>, String) {
+    let tags: Vec<&str> = locale.extensions.private.tags().collect();
+    match tags.iter().position(|t| *t == LVARIANT) {
+        Some(idx) => {
+            let mut extensions = locale.extensions.clone();
+            extensions.private.clear_tags();
+            for tag in &tags[..idx] {
+                extensions
+                    .private
+                    .add_tag(tag)
+                    .expect("The tag is well-formed.");
+            }
+            let lvariant = tags[idx + 1..].iter().map(|t| t.to_string()).collect();
+            (lvariant, extensions.to_string())
+        }
+        None => (vec![], locale.extensions.to_string()),
+    }
+}
+
+#[cfg(not(feature = "extensions"))]
+fn split_lvariant(locale: &Locale) -> (Vec<String>, String) {
+    (vec![], locale.extensions.to_string())
+}
+
+// Mirrors `java.util.Locale::toString`.
+pub(crate) fn to_java_string(locale: &Locale) -> String {
+    let (lvariant, extensions) = split_lvariant(locale);
+    let language = locale.id.language.as_opt_str().unwrap_or("");
+    let region = locale.id.region.as_ref().map_or("", |r| r.as_str());
+    let variant = locale
+        .id
+        .variants()
+        .map(|v| v.as_str())
+        .chain(lvariant.iter().map(String::as_str))
+        .collect::<Vec<_>>()
+        .join("_");
+    let script = locale.id.script.as_ref().map_or("", |s| s.as_str());
+    let extensions = extensions.trim_start_matches('-');
+
+    let l = !language.is_empty();
+    let r = !region.is_empty();
+    let v = !variant.is_empty();
+    let s = !script.is_empty();
+    let e = !extensions.is_empty();
+
+    let mut result = String::from(language);
+    if r || (l && (v || s || e)) {
+        result.push('_');
+        result.push_str(region);
+    }
+    if v && (l || r) {
+        result.push('_');
+        result.push_str(&variant);
+    }
+    if s && (l || r) {
+        result.push_str("_#");
+        result.push_str(script);
+    }
+    if e && (l || r) {
+        result.push('_');
+        if !s {
+            result.push('#');
+        }
+        result.push_str(extensions);
+    }
+    result
+}
+
+// Mirrors `java.util.Locale::toLanguageTag`, where the first variant which is
+// not a well-formed BCP47 variant and all the following ones are stored as
+// private use subtags after `lvariant`.
+pub(crate) fn from_java_string(input: &str) -> Result<Locale, LocaleError> {
+    let (main, section) = input.split_once("_#").unwrap_or((input, ""));
+    let mut fields = main.splitn(3, '_');
+    let language = fields.next().filter(|l| !l.is_empty()).unwrap_or("und");
+    let region = fields.next().unwrap_or("");
+    let variant = fields.next().unwrap_or("");
+
+    let (script, extensions) = match section.split_once('_') {
+        Some((script, extensions)) if script.len() == 4 => (script, extensions),
+        _ if section.len() == 4 => (section, ""),
+        _ => ("", section),
+    };
+
+    let mut tag = String::from(language);
+    for subtag in &[script, region] {
+        if !subtag.is_empty() {
+            tag.push('-');
+            tag.push_str(subtag);
+        }
+    }
+    let mut variants = variant.split('_').filter(|v| !v.is_empty()).peekable();
+    while let Some(v) = variants.next_if(|v| subtags::Variant::from_bytes(v.as_bytes()).is_ok()) {
+        tag.push('-');
+        tag.push_str(v);
+    }
+    if !extensions.is_empty() {
+        tag.push('-');
+        tag.push_str(extensions);
+    }
+    if variants.peek().is_some() {
+        let has_private = extensions
+            .split(['-', '_'])
+            .any(|s| s.eq_ignore_ascii_case("x"));
+        tag.push_str(if has_private { "-" } else { "-x-" });
+        tag.push_str(LVARIANT);
+        for v in variants {
+            tag.push('-');
+            tag.push_str(v);
+        }
+    }
+    tag.parse()
+}
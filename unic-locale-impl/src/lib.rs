@@ -1,12 +1,74 @@
+pub mod accept_language;
+#[cfg(feature = "extensions")]
+mod apple;
+#[cfg(feature = "extensions")]
+mod bounded;
+mod canonicalize;
+mod check;
+#[cfg(feature = "currencydata")]
+pub mod currency;
+#[cfg(feature = "currencydata")]
+mod currency_table;
+mod default_locale;
 pub(crate) mod errors;
 pub mod extensions;
+#[cfg(feature = "http")]
+mod http_header;
+mod interner;
+mod java;
+mod locale_set;
+pub mod negotiate;
 pub mod parser;
+mod posix;
+mod preferences;
+mod pseudo;
+mod raw;
+#[cfg(feature = "serde")]
+mod serde;
+#[cfg(feature = "serde_with")]
+pub mod serde_with;
+mod shared;
+mod stable;
+mod subtag;
+#[cfg(feature = "territoryinfo")]
+pub mod territory;
+#[cfg(feature = "test_util")]
+pub mod test_util;
+#[cfg(feature = "timezones")]
+pub mod timezone;
+#[cfg(feature = "extensions")]
+mod windows;
 
+#[cfg(feature = "extensions")]
+pub use bounded::BoundedLocale;
+pub use canonicalize::{canonicalize_with_report, Transformation};
+pub use check::{CheckLevel, Finding};
+pub use default_locale::{default_locale, set_default_locale};
 use errors::LocaleError;
 pub use extensions::{ExtensionType, ExtensionsMap};
+pub use interner::{LocaleHandle, LocaleInterner};
+pub use locale_set::LocaleSet;
+pub use posix::PosixOptions;
+pub use preferences::Preferences;
+pub use pseudo::PseudoLocale;
+pub use raw::{RawLocale, RawLocaleBuf};
+pub use shared::SharedLocale;
+pub use stable::StableDecodingError;
 use std::str::FromStr;
+pub use subtag::Subtag;
+#[cfg(feature = "aliases")]
+pub use unic_langid_impl::aliases;
+#[cfg(feature = "iso3166")]
+pub use unic_langid_impl::iso3166;
+#[cfg(feature = "iso639")]
+pub use unic_langid_impl::iso639;
+#[cfg(feature = "character_direction")]
 pub use unic_langid_impl::CharacterDirection;
-pub use unic_langid_impl::{subtags, LanguageIdentifier};
+pub use unic_langid_impl::{provider, subtags, LanguageIdentifier};
+
+/// The version of `tinystr` used by the raw accessors of the `unstable` feature.
+#[cfg(feature = "unstable")]
+pub use tinystr;
 
 /// `Locale` is a core struct representing a Unicode Locale Identifier.
 ///
@@ -63,6 +125,33 @@ pub use unic_langid_impl::{subtags, LanguageIdentifier};
 /// assert_eq!(loc.id.region, Some("US".parse().unwrap()));
 /// assert_eq!(loc.id.variants().collect::<Vec<_>>(), &["valencia"]);
 /// ```
+///
+/// # Equality
+///
+/// A `Locale` is always kept in a canonical internal representation: unicode extension
+/// keywords and transform extension tfields are sorted by key, unicode extension
+/// attributes are sorted and deduplicated, a keyword value of `true` is removed,
+/// and only the first of duplicate keys is kept. Other extensions are sorted by
+/// singleton. Two locales are therefore equal, and hash the same, whenever they
+/// serialize to the same string.
+///
+/// `Ord` is consistent with equality: locales are ordered by their language
+/// identifier, then by their extensions, so they can be used as keys of both
+/// a `HashMap` and a `BTreeMap`, and sorting them gives a deterministic order.
+///
+/// Private use subtags are the exception, as their order may be meaningful. See
+/// `PrivateSubtagOrder` for opting into a canonical order.
+///
+/// ```
+/// use unic_locale_impl::Locale;
+///
+/// let loc1: Locale = "en-u-ca-buddhist-nu-thai".parse()
+///     .expect("Failed to parse.");
+/// let loc2: Locale = "en-u-nu-thai-ca-buddhist".parse()
+///     .expect("Failed to parse.");
+///
+/// assert_eq!(loc1, loc2);
+/// ```
 #[derive(Debug, Default, PartialEq, Eq, Clone, Hash, PartialOrd, Ord)]
 pub struct Locale {
     pub id: LanguageIdentifier,
@@ -95,6 +184,30 @@ impl Locale {
         Ok(parser::parse_locale(v)?)
     }
 
+    /// Parses a utf8 slice into a [`BoundedLocale`], which stores up to `N` variant
+    /// and extension subtags inline, failing with `ParserError::CapacityExceeded`
+    /// if there are more.
+    ///
+    /// The result holds no heap storage, but the parsing itself may allocate
+    /// temporarily.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc = Locale::from_bytes_bounded::<8>(b"sr-Latn-u-nu-latn")
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_string(), "sr-Latn-u-nu-latn");
+    /// assert_eq!(Locale::from(&loc), "sr-Latn-u-nu-latn".parse::<Locale>().unwrap());
+    ///
+    /// assert!(Locale::from_bytes_bounded::<2>(b"sr-Latn-u-nu-latn").is_err());
+    /// ```
+    #[cfg(feature = "extensions")]
+    pub fn from_bytes_bounded<const N: usize>(v: &[u8]) -> Result<BoundedLocale<N>, LocaleError> {
+        Ok(bounded::parse(v)?)
+    }
+
     /// A constructor which takes optional subtags as `AsRef<[u8]>`, parses them and
     /// produces a well-formed `Locale`.
     ///
@@ -136,131 +249,1746 @@ impl Locale {
         Self { id, extensions }
     }
 
-    /// Consumes `Locale` and produces raw internal representations
-    /// of all subtags in form of `u64`/`u32`.
+    /// Returns the process-wide default locale set with `set_default_locale`, falling
+    /// back to the system locale and then to `und`.
     ///
-    /// Primarily used for storing internal representation and restoring via
-    /// `from_raw_parts_unchecked`.
+    /// The system locale is detected from the `LC_ALL`, `LC_MESSAGES` and `LANG`
+    /// environment variables, in that order of precedence.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::{set_default_locale, Locale};
+    ///
+    /// let loc: Locale = "fr-CA".parse()
+    ///     .expect("Parsing failed.");
+    /// set_default_locale(loc.clone())
+    ///     .expect("Setting the default locale failed.");
+    ///
+    /// assert_eq!(Locale::current(), loc);
+    /// ```
+    pub fn current() -> Self {
+        Self::current_with_options(&PosixOptions::default())
+    }
+
+    /// Like `current`, but maps the `C` and `POSIX` system locales as configured
+    /// in the `PosixOptions`, rather than to `und`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::{Locale, PosixOptions};
+    ///
+    /// let options = PosixOptions {
+    ///     c_locale: "en-US-u-va-posix".parse().expect("Parsing failed."),
+    /// };
+    ///
+    /// std::env::set_var("LC_ALL", "C.UTF-8");
+    /// assert_eq!(Locale::current_with_options(&options).to_string(), "en-US-u-va-posix");
+    /// ```
+    pub fn current_with_options(options: &PosixOptions) -> Self {
+        default_locale()
+            .cloned()
+            .or_else(|| posix::from_env(options))
+            .unwrap_or_default()
+    }
+
+    /// Resolves the formatting preferences of the `Locale`, combining its unicode
+    /// extension keywords with the defaults of its region.
+    ///
+    /// The region is taken from the `rg` keyword, the region subtag, or, with the
+    /// `likelysubtags` feature, the likely region of the locale.
     ///
     /// # Examples
     ///
     /// ```
     /// use unic_locale_impl::Locale;
-    /// use tinystr::{TinyStr8, TinyStr4};
     ///
-    /// let loc: Locale = "en-US".parse()
+    /// let loc: Locale = "en-US-u-hc-h23".parse()
     ///     .expect("Parsing failed.");
+    /// let prefs = loc.resolve_preferences();
     ///
-    /// let (lang, script, region, variants, extensions) = loc.into_parts();
+    /// assert_eq!(prefs.hour_cycle, "h23");
+    /// assert_eq!(prefs.calendar, "gregory");
+    /// assert_eq!(prefs.numbering_system, "latn");
+    /// assert_eq!(prefs.measurement_system, "ussystem");
+    /// assert_eq!(prefs.first_weekday, "sun");
+    /// assert_eq!(prefs.region_override, None);
+    /// ```
+    pub fn resolve_preferences(&self) -> Preferences {
+        preferences::resolve(self)
+    }
+
+    /// Resolves the collation of the `Locale`, which is the `co` keyword if present,
+    /// and the default collation of the language from CLDR otherwise.
     ///
-    /// let loc2 = Locale::from_parts(
-    ///     lang,
-    ///     script,
-    ///     region,
-    ///     &variants,
-    ///     Some(extensions.parse().unwrap())
-    /// );
+    /// All languages default to `standard`, except Chinese, which defaults to `stroke`
+    /// when written in traditional characters, as in `zh-Hant` or `zh-TW`, and to
+    /// `pinyin` otherwise.
+    ///
+    /// # Examples
     ///
-    /// assert_eq!(loc2.to_string(), "en-US");
     /// ```
-    pub fn into_parts(self) -> PartsTuple {
-        let (lang, region, script, variants) = self.id.into_parts();
-        (lang, region, script, variants, self.extensions.to_string())
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "zh-TW".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.resolve_collation(), "stroke");
+    ///
+    /// let loc: Locale = "de-DE-u-co-phonebk".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.resolve_collation(), "phonebk");
+    ///
+    /// let loc: Locale = "de-DE".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.resolve_collation(), "standard");
+    /// ```
+    pub fn resolve_collation(&self) -> String {
+        preferences::resolve_collation(self)
     }
 
-    /// Compares a `Locale` to another `AsRef<Locale`
-    /// allowing for either side to use the missing fields as wildcards.
+    /// Resolves the currency of the `Locale` as an uppercase ISO 4217 code.
     ///
-    /// This allows for matching between `en` (treated as `en-*-*-*`) and `en-US`.
+    /// This is the `cu` keyword if present, and the current legal tender of the
+    /// region from CLDR `currencyData` otherwise. The region is the region override
+    /// (`rg`), the region subtag, or the likely region of the language, in that order.
+    ///
+    /// Returns `None` if no region can be found, or it has no currency.
     ///
     /// # Examples
     ///
     /// ```
     /// use unic_locale_impl::Locale;
     ///
-    /// let loc1: Locale = "en".parse()
+    /// let loc: Locale = "de-CH".parse()
     ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.resolve_currency().as_deref(), Some("CHF"));
     ///
-    /// let loc2: Locale = "en-US".parse()
+    /// let loc: Locale = "en-US-u-cu-eur".parse()
     ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.resolve_currency().as_deref(), Some("EUR"));
     ///
-    /// assert_ne!(loc1, loc2); // "en" != "en-US"
-    /// assert_ne!(loc1.to_string(), loc2.to_string()); // "en" != "en-US"
+    /// let loc: Locale = "en-US-u-rg-gbzzzz".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.resolve_currency().as_deref(), Some("GBP"));
+    /// ```
+    #[cfg(feature = "currencydata")]
+    pub fn resolve_currency(&self) -> Option<String> {
+        preferences::resolve_currency(self)
+    }
+
+    /// Resolves the default time zone of the `Locale` as an IANA name.
+    ///
+    /// If the `tz` keyword is absent, this is the primary time zone of the region,
+    /// which is the region override (`rg`), the region subtag, or the likely region
+    /// of the language, in that order. Returns `None` if the region spans several
+    /// time zones without a primary one, such as `US`.
+    ///
+    /// If the `tz` keyword is present, its BCP 47 id is returned as an IANA name when
+    /// it is the primary time zone of a region, and `None` otherwise.
+    ///
+    /// # Examples
     ///
-    /// assert_eq!(loc1.matches(&loc2, false, false), false); // "en" != "en-US"
-    /// assert_eq!(loc1.matches(&loc2, true, false), true); // "en-*-*-*" == "en-US"
-    /// assert_eq!(loc1.matches(&loc2, false, true), false); // "en" != "en-*-US-*"
-    /// assert_eq!(loc1.matches(&loc2, true, true), true); // "en-*-*-*" == "en-*-US-*"
     /// ```
-    pub fn matches<O: AsRef<Self>>(
-        &self,
-        other: &O,
-        self_as_range: bool,
-        other_as_range: bool,
-    ) -> bool {
-        let other = other.as_ref();
-        if !self.extensions.private.is_empty() || !other.extensions.private.is_empty() {
-            return false;
-        }
-        self.id.matches(&other.id, self_as_range, other_as_range)
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "de-AT".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.resolve_time_zone(), Some("Europe/Vienna"));
+    ///
+    /// let loc: Locale = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.resolve_time_zone(), None);
+    ///
+    /// let loc: Locale = "en-US-u-tz-gblon".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.resolve_time_zone(), Some("Europe/London"));
+    /// ```
+    #[cfg(feature = "timezones")]
+    pub fn resolve_time_zone(&self) -> Option<&'static str> {
+        preferences::resolve_time_zone(self)
     }
-}
 
-impl FromStr for Locale {
-    type Err = LocaleError;
+    /// Picks the best of the `available` locales for an `Accept-Language` header value,
+    /// or `default` if none of them is acceptable.
+    ///
+    /// The requested locales are tried by descending weight, skipping those with
+    /// a weight of `0`. Each of them is looked up as in [`RFC 4647`], by removing its
+    /// variants, region and script until it matches the language identifier of
+    /// an available locale. The `*` wildcard picks the first available locale
+    /// not matched by any other range of the header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let available: Vec<Locale> = vec![
+    ///     "en".parse().unwrap(),
+    ///     "fr".parse().unwrap(),
+    ///     "de-AT".parse().unwrap(),
+    /// ];
+    /// let default = &available[0];
+    ///
+    /// let best = Locale::best_from_accept_language("fr-CH, de;q=0.9", &available, default);
+    /// assert_eq!(best.to_string(), "fr");
+    ///
+    /// let best = Locale::best_from_accept_language("pl, de;q=0.9", &available, default);
+    /// assert_eq!(best.to_string(), "en");
+    ///
+    /// let best = Locale::best_from_accept_language("pl, en;q=0.5, *;q=0.8", &available, default);
+    /// assert_eq!(best.to_string(), "fr");
+    /// ```
+    ///
+    /// [`RFC 4647`]: https://tools.ietf.org/html/rfc4647#section-3.4
+    pub fn best_from_accept_language<'a>(
+        header: &str,
+        available: &'a [Locale],
+        default: &'a Locale,
+    ) -> &'a Locale {
+        accept_language::best(header, available, default)
+    }
 
-    fn from_str(source: &str) -> Result<Self, Self::Err> {
-        Ok(parser::parse_locale(source)?)
+    /// Returns all the `available` locales acceptable for an `Accept-Language` header
+    /// value, in order of preference, followed by the developer `defaults` as selected
+    /// by the `policy`.
+    ///
+    /// The locales are matched as in [`best_from_accept_language`], which picks the
+    /// first of them. Each locale is listed once, so the defaults which were already
+    /// negotiated are not repeated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    /// use unic_locale_impl::accept_language::DefaultsPolicy;
+    ///
+    /// let available: Vec<Locale> = vec![
+    ///     "en".parse().unwrap(),
+    ///     "fr".parse().unwrap(),
+    ///     "de-AT".parse().unwrap(),
+    /// ];
+    /// let defaults: Vec<Locale> = vec![
+    ///     "en".parse().unwrap(),
+    ///     "en-US".parse().unwrap(),
+    /// ];
+    ///
+    /// let negotiated = |header, policy| {
+    ///     Locale::negotiate_accept_language(header, &available, &defaults, policy)
+    ///         .iter()
+    ///         .map(|locale| locale.to_string())
+    ///         .collect::<Vec<_>>()
+    /// };
+    ///
+    /// assert_eq!(negotiated("de-AT, fr;q=0.5", DefaultsPolicy::Always), &["de-AT", "fr", "en", "en-US"]);
+    /// assert_eq!(negotiated("de-AT, fr;q=0.5", DefaultsPolicy::OnlyIfEmpty), &["de-AT", "fr"]);
+    /// assert_eq!(negotiated("pl", DefaultsPolicy::OnlyIfEmpty), &["en", "en-US"]);
+    /// assert!(negotiated("pl", DefaultsPolicy::Never).is_empty());
+    /// ```
+    ///
+    /// [`best_from_accept_language`]: #method.best_from_accept_language
+    pub fn negotiate_accept_language<'a>(
+        header: &str,
+        available: &'a [Locale],
+        defaults: &'a [Locale],
+        policy: accept_language::DefaultsPolicy,
+    ) -> Vec<&'a Locale> {
+        accept_language::negotiate(header, available, defaults, policy)
     }
-}
 
-impl From<LanguageIdentifier> for Locale {
-    fn from(id: LanguageIdentifier) -> Self {
-        Locale {
-            id,
-            extensions: ExtensionsMap::default(),
-        }
+    /// Picks the best of the `available` locales for an `Accept-Language` header value,
+    /// scoring each of them by the weight of a requested range and its distance to it,
+    /// or returns `default` if none of them is acceptable.
+    ///
+    /// Unlike [`best_from_accept_language`], a more specific available locale can
+    /// match a requested range, and a close match of a preferred range can win over
+    /// an identical match of a less preferred one. Ranges with a weight of `0` are
+    /// skipped, and a locale of another language or script never matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    /// use unic_locale_impl::accept_language::ScoreWeighting;
+    ///
+    /// let available: Vec<Locale> = vec![
+    ///     "en".parse().unwrap(),
+    ///     "fr-CA".parse().unwrap(),
+    /// ];
+    /// let default = &available[0];
+    /// let header = "fr;q=0.9, en;q=0.8";
+    ///
+    /// let best = Locale::best_from_accept_language_weighted(
+    ///     header, &available, default, ScoreWeighting::default()
+    /// );
+    /// assert_eq!(best.to_string(), "fr-CA");
+    ///
+    /// let weighting = ScoreWeighting { distance_factor: 2.0 };
+    /// let best = Locale::best_from_accept_language_weighted(header, &available, default, weighting);
+    /// assert_eq!(best.to_string(), "en");
+    /// ```
+    ///
+    /// [`best_from_accept_language`]: #method.best_from_accept_language
+    pub fn best_from_accept_language_weighted<'a>(
+        header: &str,
+        available: &'a [Locale],
+        default: &'a Locale,
+        weighting: accept_language::ScoreWeighting,
+    ) -> &'a Locale {
+        accept_language::best_weighted(header, available, default, weighting)
     }
-}
 
-impl Into<LanguageIdentifier> for Locale {
-    fn into(self) -> LanguageIdentifier {
-        self.id
+    /// Picks the best of the `available` locales like [`best_from_accept_language_weighted`],
+    /// with a priority for each of them, such as a lower one for machine translated
+    /// locales than for fully translated ones.
+    ///
+    /// The score of each match is multiplied by the priority of the available locale,
+    /// so a priority of `1.0` keeps the score, and lower ones favor the other locales.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    /// use unic_locale_impl::accept_language::ScoreWeighting;
+    ///
+    /// let available: Vec<(Locale, f32)> = vec![
+    ///     ("en".parse().unwrap(), 1.0),
+    ///     ("fr".parse().unwrap(), 0.5),
+    /// ];
+    /// let default = &available[0].0;
+    ///
+    /// let best = Locale::best_from_accept_language_prioritized(
+    ///     "fr, en;q=0.8", &available, default, ScoreWeighting::default()
+    /// );
+    /// assert_eq!(best.to_string(), "en");
+    ///
+    /// let best = Locale::best_from_accept_language_prioritized(
+    ///     "fr, en;q=0.4", &available, default, ScoreWeighting::default()
+    /// );
+    /// assert_eq!(best.to_string(), "fr");
+    /// ```
+    ///
+    /// [`best_from_accept_language_weighted`]: #method.best_from_accept_language_weighted
+    pub fn best_from_accept_language_prioritized<'a>(
+        header: &str,
+        available: &'a [(Locale, f32)],
+        default: &'a Locale,
+        weighting: accept_language::ScoreWeighting,
+    ) -> &'a Locale {
+        accept_language::best_prioritized(header, available, default, weighting)
     }
-}
 
-impl AsRef<LanguageIdentifier> for Locale {
-    fn as_ref(&self) -> &LanguageIdentifier {
-        &self.id
+    /// Picks the best of the `available` locales like [`best_from_accept_language_weighted`],
+    /// and returns a trace of why each of them was scored or rejected for each of the
+    /// requested ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    /// use unic_locale_impl::accept_language::{Outcome, Rejection, ScoreWeighting};
+    ///
+    /// let available: Vec<Locale> = vec![
+    ///     "de".parse().unwrap(),
+    ///     "sr-Cyrl".parse().unwrap(),
+    /// ];
+    /// let (best, trace) = Locale::best_from_accept_language_traced(
+    ///     "sr-Latn, en;q=0.8", &available, &available[0], ScoreWeighting::default()
+    /// );
+    ///
+    /// assert_eq!(best.to_string(), "de");
+    /// assert_eq!(trace.chosen, None);
+    ///
+    /// let outcomes: Vec<_> = trace.entries_for(&available[1])
+    ///     .map(|entry| entry.outcome)
+    ///     .collect();
+    /// assert_eq!(outcomes, &[
+    ///     Outcome::Rejected(Rejection::ScriptMismatch),
+    ///     Outcome::Rejected(Rejection::LanguageMismatch),
+    /// ]);
+    /// ```
+    ///
+    /// [`best_from_accept_language_weighted`]: #method.best_from_accept_language_weighted
+    pub fn best_from_accept_language_traced<'a>(
+        header: &str,
+        available: &'a [Locale],
+        default: &'a Locale,
+        weighting: accept_language::ScoreWeighting,
+    ) -> (&'a Locale, accept_language::NegotiationTrace<'a>) {
+        accept_language::best_traced(header, available, default, weighting)
     }
-}
 
-impl AsRef<Locale> for Locale {
-    #[inline(always)]
-    fn as_ref(&self) -> &Locale {
-        self
+    /// Returns an iterator over all subtags of the `Locale`, in serialization order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::{ExtensionType, Locale, Subtag};
+    ///
+    /// let loc: Locale = "en-US-u-ca-buddhist".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.subtags().collect::<Vec<_>>(), &[
+    ///     Subtag::Language("en".parse().unwrap()),
+    ///     Subtag::Region("US".parse().unwrap()),
+    ///     Subtag::ExtensionSingleton(ExtensionType::Unicode),
+    ///     Subtag::ExtensionKey("ca"),
+    ///     Subtag::ExtensionValue("buddhist"),
+    /// ]);
+    /// ```
+    pub fn subtags(&self) -> impl Iterator<Item = Subtag<'_>> {
+        subtag::subtags(self).into_iter()
     }
-}
 
-impl std::fmt::Display for Locale {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        write!(f, "{}{}", self.id, self.extensions)
+    /// Returns `true` if the `Locale` is a private use tag, such as `x-custom-pseudo`,
+    /// which has no language subtag and only private use subtags.
+    ///
+    /// Only a `Locale` parsed from such a tag is one, and conversions keeping its
+    /// extensions, such as to and from a `SharedLocale`, keep it one. Adding any other
+    /// subtag makes it a regular one, written with the `und` language. As in UTS #35,
+    /// `x-foo` and `und-x-foo` are still the same locale, and compare equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc: Locale = "x-custom-pseudo".parse()
+    ///     .expect("Parsing failed.");
+    /// assert!(loc.is_private_use_only());
+    /// assert_eq!(loc.language(), None);
+    /// assert_eq!(loc.to_string(), "x-custom-pseudo");
+    ///
+    /// loc.id.region = Some("US".parse().unwrap());
+    /// assert!(!loc.is_private_use_only());
+    /// assert_eq!(loc.to_string(), "und-US-x-custom-pseudo");
+    ///
+    /// let loc: Locale = "und-x-custom".parse()
+    ///     .expect("Parsing failed.");
+    /// assert!(!loc.is_private_use_only());
+    /// assert_eq!(loc.to_string(), "und-x-custom");
+    /// ```
+    pub fn is_private_use_only(&self) -> bool {
+        self.id == LanguageIdentifier::default() && self.extensions.is_private_use_only()
     }
-}
 
-/// This is a best-effort operation that performs all available levels of canonicalization.
-///
-/// At the moment the operation will normalize casing and the separator, but in the future
-/// it may also validate and update from deprecated subtags to canonical ones.
-///
-/// # Examples
-///
-/// ```
-/// use unic_locale_impl::canonicalize;
-///
-/// assert_eq!(canonicalize("pL_latn_pl-U-HC-H12"), Ok("pl-Latn-PL-u-hc-h12".to_string()));
-/// ```
-pub fn canonicalize<S: AsRef<[u8]>>(input: S) -> Result<String, LocaleError> {
-    let locale = Locale::from_bytes(input.as_ref())?;
-    Ok(locale.to_string())
+    /// Returns the language subtag, or `None` for a private use tag, which has
+    /// no language subtag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "und-US-x-foo".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.language(), Some("und".parse().unwrap()));
+    ///
+    /// let loc: Locale = "x-foo".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.language(), None);
+    /// ```
+    pub fn language(&self) -> Option<subtags::Language> {
+        if self.is_private_use_only() {
+            None
+        } else {
+            Some(self.id.language)
+        }
+    }
+
+    /// Returns the language, script and region subtags, for use in pattern matching.
+    ///
+    /// The language is `None` for `und` and for private use tags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "zh-Hant-HK-u-nu-hanidec".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let family = match loc.as_tuple() {
+    ///     (Some("zh"), Some("Hant"), _) | (Some("zh"), None, Some("TW" | "HK" | "MO")) => "traditional",
+    ///     (Some("zh"), ..) => "simplified",
+    ///     (None, ..) => "undetermined",
+    ///     _ => "other",
+    /// };
+    /// assert_eq!(family, "traditional");
+    /// ```
+    pub fn as_tuple(&self) -> (Option<&str>, Option<&str>, Option<&str>) {
+        let language = if self.is_private_use_only() {
+            None
+        } else {
+            self.id.language_opt()
+        };
+        (
+            language,
+            self.id.script.as_ref().map(|s| s.as_str()),
+            self.id.region.as_ref().map(|r| r.as_str()),
+        )
+    }
+
+    /// Returns the variant subtags of the `Locale` as a slice, sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "ca-ES-valencia".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert!(matches!(loc.variants(), [v] if v.as_str() == "valencia"));
+    /// ```
+    pub fn variants(&self) -> &[subtags::Variant] {
+        self.id.variants_slice()
+    }
+
+    /// Consumes `Locale` and produces raw internal representations
+    /// of all subtags in form of `u64`/`u32`.
+    ///
+    /// Primarily used for storing internal representation and restoring via
+    /// `from_raw_parts_unchecked`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    /// use tinystr::{TinyStr8, TinyStr4};
+    ///
+    /// let loc: Locale = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let (lang, script, region, variants, extensions) = loc.into_parts();
+    ///
+    /// let loc2 = Locale::from_parts(
+    ///     lang,
+    ///     script,
+    ///     region,
+    ///     &variants,
+    ///     Some(extensions.parse().unwrap())
+    /// );
+    ///
+    /// assert_eq!(loc2.to_string(), "en-US");
+    /// ```
+    pub fn into_parts(self) -> PartsTuple {
+        let (lang, region, script, variants) = self.id.into_parts();
+        (lang, region, script, variants, self.extensions.to_string())
+    }
+
+    /// Replaces the language identifier of the `Locale`, keeping its extensions,
+    /// and returns the previous one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::{LanguageIdentifier, Locale};
+    ///
+    /// let mut loc: Locale = "en-US-u-hc-h23-nu-latn".parse()
+    ///     .expect("Parsing failed.");
+    /// let negotiated: LanguageIdentifier = "de-AT".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let previous = loc.set_langid(negotiated);
+    ///
+    /// assert_eq!(previous.to_string(), "en-US");
+    /// assert_eq!(loc.to_string(), "de-AT-u-hc-h23-nu-latn");
+    /// ```
+    pub fn set_langid(&mut self, id: LanguageIdentifier) -> LanguageIdentifier {
+        std::mem::replace(&mut self.id, id)
+    }
+
+    /// Returns the `Locale` with its extensions replaced by the result of `f`,
+    /// keeping the language identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "en-US-u-hc-h23-x-foo".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let loc = loc.map_extensions(|mut extensions| {
+    ///     extensions.private.clear_tags();
+    ///     extensions
+    /// });
+    ///
+    /// assert_eq!(loc.to_string(), "en-US-u-hc-h23");
+    /// ```
+    pub fn map_extensions(
+        mut self,
+        f: impl FnOnce(extensions::ExtensionsMap) -> extensions::ExtensionsMap,
+    ) -> Self {
+        self.extensions = f(std::mem::take(&mut self.extensions));
+        self
+    }
+
+    /// Like [`map_extensions`], for a transformation which may fail.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "en-US-u-hc-h23".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let loc = loc.try_map_extensions(|mut extensions| {
+    ///     extensions.unicode.set_keyword("ca", &["buddhist"])?;
+    ///     Ok::<_, Box<dyn std::error::Error>>(extensions)
+    /// }).expect("Setting keyword failed.");
+    ///
+    /// assert_eq!(loc.to_string(), "en-US-u-ca-buddhist-hc-h23");
+    /// ```
+    ///
+    /// [`map_extensions`]: #method.map_extensions
+    pub fn try_map_extensions<E>(
+        mut self,
+        f: impl FnOnce(extensions::ExtensionsMap) -> Result<extensions::ExtensionsMap, E>,
+    ) -> Result<Self, E> {
+        self.extensions = f(std::mem::take(&mut self.extensions))?;
+        Ok(self)
+    }
+
+    /// Compares a `Locale` to another `AsRef<Locale`
+    /// allowing for either side to use the missing fields as wildcards.
+    ///
+    /// This allows for matching between `en` (treated as `en-*-*-*`) and `en-US`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc1: Locale = "en".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let loc2: Locale = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_ne!(loc1, loc2); // "en" != "en-US"
+    /// assert_ne!(loc1.to_string(), loc2.to_string()); // "en" != "en-US"
+    ///
+    /// assert_eq!(loc1.matches(&loc2, false, false), false); // "en" != "en-US"
+    /// assert_eq!(loc1.matches(&loc2, true, false), true); // "en-*-*-*" == "en-US"
+    /// assert_eq!(loc1.matches(&loc2, false, true), false); // "en" != "en-*-US-*"
+    /// assert_eq!(loc1.matches(&loc2, true, true), true); // "en-*-*-*" == "en-*-US-*"
+    /// ```
+    pub fn matches<O: AsRef<Self>>(
+        &self,
+        other: &O,
+        self_as_range: bool,
+        other_as_range: bool,
+    ) -> bool {
+        let other = other.as_ref();
+        #[cfg(feature = "extensions")]
+        if !self.extensions.private.is_empty() || !other.extensions.private.is_empty() {
+            return false;
+        }
+        self.id.matches(&other.id, self_as_range, other_as_range)
+    }
+
+    /// Returns `true` if the `Locale` [`matches`] any of the given locales.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let supported: Vec<Locale> = vec![
+    ///     "en-US".parse().unwrap(),
+    ///     "fr-CA".parse().unwrap(),
+    /// ];
+    ///
+    /// let requested: Locale = "fr".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert!(!requested.matches_any(&supported, false, false));
+    /// assert!(requested.matches_any(&supported, true, false));
+    /// ```
+    ///
+    /// [`matches`]: #method.matches
+    pub fn matches_any<O: AsRef<Self>>(
+        &self,
+        others: &[O],
+        self_as_range: bool,
+        other_as_range: bool,
+    ) -> bool {
+        others
+            .iter()
+            .any(|other| self.matches(other, self_as_range, other_as_range))
+    }
+
+    /// Returns `true` if the `Locale` [`matches`] all of the given locales.
+    ///
+    /// Returns `true` for an empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let locales: Vec<Locale> = vec![
+    ///     "en-US".parse().unwrap(),
+    ///     "en-GB".parse().unwrap(),
+    /// ];
+    ///
+    /// let en: Locale = "en".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert!(en.matches_all(&locales, true, false));
+    ///
+    /// let en_us: Locale = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert!(!en_us.matches_all(&locales, true, false));
+    /// ```
+    ///
+    /// [`matches`]: #method.matches
+    pub fn matches_all<O: AsRef<Self>>(
+        &self,
+        others: &[O],
+        self_as_range: bool,
+        other_as_range: bool,
+    ) -> bool {
+        others
+            .iter()
+            .all(|other| self.matches(other, self_as_range, other_as_range))
+    }
+
+    /// Returns a bitmask of the optional subtags and extensions present in the `Locale`.
+    ///
+    /// The bits of `LanguageIdentifier::presence` are combined with `PRESENCE_UNICODE`,
+    /// `PRESENCE_TRANSFORM`, `PRESENCE_OTHER` and `PRESENCE_PRIVATE`. Locales with
+    /// different masks are never equal.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::{LanguageIdentifier, Locale};
+    ///
+    /// let loc: Locale = "en-US-u-hc-h12".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(
+    ///     loc.presence(),
+    ///     LanguageIdentifier::PRESENCE_REGION | Locale::PRESENCE_UNICODE
+    /// );
+    /// ```
+    #[inline]
+    pub fn presence(&self) -> u8 {
+        #[cfg_attr(not(feature = "extensions"), allow(unused_mut))]
+        let mut result = self.id.presence();
+        #[cfg(feature = "extensions")]
+        {
+            let extensions = &self.extensions;
+            if !extensions.unicode.is_empty() {
+                result |= Self::PRESENCE_UNICODE;
+            }
+            if !extensions.transform.is_empty() {
+                result |= Self::PRESENCE_TRANSFORM;
+            }
+            if !extensions.other.is_empty() {
+                result |= Self::PRESENCE_OTHER;
+            }
+            if !extensions.private.is_empty() {
+                result |= Self::PRESENCE_PRIVATE;
+            }
+        }
+        result
+    }
+
+    /// The bit set in `presence` when there is a unicode extension.
+    pub const PRESENCE_UNICODE: u8 = 1 << 3;
+    /// The bit set in `presence` when there is a transform extension.
+    pub const PRESENCE_TRANSFORM: u8 = 1 << 4;
+    /// The bit set in `presence` when there are other extensions.
+    pub const PRESENCE_OTHER: u8 = 1 << 5;
+    /// The bit set in `presence` when there is a private use extension.
+    pub const PRESENCE_PRIVATE: u8 = 1 << 6;
+
+    /// Compares only the language subtags of a `Locale` and another `AsRef<Locale>`,
+    /// ignoring the script, region, variants and extensions.
+    ///
+    /// With `und_as_wildcard`, `und` on either side matches any language.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let en_us: Locale = "en-US-u-hc-h12".parse()
+    ///     .expect("Parsing failed.");
+    /// let en_gb: Locale = "en-GB".parse()
+    ///     .expect("Parsing failed.");
+    /// let und: Locale = "und-US".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert!(en_us.matches_language(&en_gb, false));
+    /// assert!(!en_us.matches_language(&und, false));
+    /// assert!(en_us.matches_language(&und, true));
+    /// ```
+    pub fn matches_language<O: AsRef<Self>>(&self, other: O, und_as_wildcard: bool) -> bool {
+        self.id
+            .language
+            .matches(other.as_ref().id.language, und_as_wildcard, und_as_wildcard)
+    }
+
+    /// Returns a new `Locale` with just the language subtag of this one, without
+    /// the script, region, variants and extensions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "sr-Latn-RS-u-nu-latn-x-foo".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.to_language_only().to_string(), "sr");
+    /// ```
+    pub fn to_language_only(&self) -> Locale {
+        LanguageIdentifier::from_parts(self.id.language, None, None, &[]).into()
+    }
+
+    /// Compares the `Locale` to a string, ignoring the casing and the
+    /// non-canonical forms of the string, such as `_` separators.
+    ///
+    /// Returns `false` if the string is not a well-formed locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "en-US-u-hc-h12".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert!(loc.eq_ignore_case("EN_us-U-HC-H12"));
+    /// assert!(!loc.eq_ignore_case("en-US"));
+    /// assert!(!loc.eq_ignore_case("not a locale"));
+    /// ```
+    pub fn eq_ignore_case<S: AsRef<[u8]>>(&self, other: S) -> bool {
+        parser::parse_locale(other).is_ok_and(|other| *self == other)
+    }
+
+    /// Compares the language identifiers of two locales, ignoring their extensions.
+    ///
+    /// Unlike [`matches`], this takes no ranges into account, so an `und` language
+    /// or a missing subtag only equals the same.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc1: Locale = "en-US-u-hc-h12-x-foo".parse()
+    ///     .expect("Parsing failed.");
+    /// let loc2: Locale = "en-US-t-h0-hybrid".parse()
+    ///     .expect("Parsing failed.");
+    /// let loc3: Locale = "en".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert!(loc1.eq_ignore_extensions(&loc2));
+    /// assert!(!loc1.eq_ignore_extensions(&loc3));
+    /// ```
+    ///
+    /// [`matches`]: #method.matches
+    pub fn eq_ignore_extensions<O: AsRef<Self>>(&self, other: O) -> bool {
+        self.id == other.as_ref().id
+    }
+
+    /// Extends the `Locale` adding likely subtags based on tables provided
+    /// by CLDR, treating the region of the `-u-rg-` keyword as authoritative.
+    ///
+    /// If the `Locale` has no region subtag, the likely script is inferred for
+    /// the region override, and the region override becomes the region.
+    /// An explicit region subtag is kept, and the `rg` keyword is never removed.
+    ///
+    /// Without a well-formed region override this behaves like
+    /// `LanguageIdentifier::maximize`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc: Locale = "en-u-rg-gbzzzz".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.maximize_with_region_override(), true);
+    /// assert_eq!(loc.to_string(), "en-Latn-GB-u-rg-gbzzzz");
+    ///
+    /// let mut loc: Locale = "sr-u-rg-mezzzz".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.maximize_with_region_override(), true);
+    /// assert_eq!(loc.to_string(), "sr-Latn-ME-u-rg-mezzzz");
+    /// ```
+    #[cfg(all(feature = "likelysubtags", feature = "extensions"))]
+    pub fn maximize_with_region_override(&mut self) -> bool {
+        if self.id.region.is_none() {
+            if let Some(region) = self.extensions.unicode.region_override() {
+                self.id.region = Some(region);
+                self.id.maximize();
+                return true;
+            }
+        }
+        self.id.maximize()
+    }
+
+    /// Returns the likely language, script and region of the `Locale`, based on
+    /// tables provided by CLDR, without modifying it.
+    ///
+    /// Returns `None` if the likely subtags are unknown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "sr-ME-u-nu-latn".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let (language, script, region) = loc.likely_subtags()
+    ///     .expect("Likely subtags are known.");
+    /// assert_eq!(language.as_str(), "sr");
+    /// assert_eq!(script.as_str(), "Latn");
+    /// assert_eq!(region.as_str(), "ME");
+    ///
+    /// assert_eq!(loc.to_string(), "sr-ME-u-nu-latn");
+    /// ```
+    #[cfg(feature = "likelysubtags")]
+    pub fn likely_subtags(&self) -> Option<(subtags::Language, subtags::Script, subtags::Region)> {
+        let id = &self.id;
+        let (language, script, region) =
+            unic_langid_impl::likelysubtags::maximize(id.language, id.script, id.region)
+                .unwrap_or((id.language, id.script, id.region));
+        Some((language, script?, region?))
+    }
+
+    /// Returns the most likely `Locale` for a region, based on tables provided
+    /// by CLDR, for suggesting a language once a region is known.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc = Locale::likely_for_region("CH".parse().unwrap())
+    ///     .expect("The region has likely subtags.");
+    ///
+    /// assert_eq!(loc.to_string(), "de-Latn-CH");
+    /// ```
+    #[cfg(feature = "likelysubtags")]
+    pub fn likely_for_region(region: subtags::Region) -> Option<Self> {
+        unic_langid_impl::likelysubtags::likely_for_region(region).map(Self::from)
+    }
+
+    /// Replaces deprecated language, script and region subtags with their CLDR
+    /// aliases, so that two spellings of the same locale compare equal.
+    ///
+    /// Returns `true` if any subtag was replaced. Legacy tags which are not
+    /// well-formed, such as `i-klingon`, are only replaced by
+    /// [`canonicalize_with_mode`], before parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let mut loc: Locale = "iw-IL-u-ca-hebrew".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.canonicalize_aliases(), true);
+    /// assert_eq!(loc, "he-IL-u-ca-hebrew".parse::<Locale>().unwrap());
+    /// ```
+    ///
+    /// [`canonicalize_with_mode`]: fn.canonicalize_with_mode.html
+    #[cfg(feature = "aliases")]
+    pub fn canonicalize_aliases(&mut self) -> bool {
+        self.id.canonicalize_aliases()
+    }
+
+    /// Returns the ISO 639-3 code of the language, for interoperability with
+    /// datasets keyed by it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "de-AT".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.iso639_3().map(|l| l.to_string()), Some("deu".to_string()));
+    /// ```
+    #[cfg(feature = "iso639")]
+    pub fn iso639_3(&self) -> Option<subtags::Language> {
+        unic_langid_impl::iso639::to_iso639_3(self.id.language)
+    }
+
+    /// Returns the length in bytes of the serialized `Locale`, without
+    /// allocating.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "en-US-u-hc-h12".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.serialized_len(), 14);
+    /// assert_eq!(loc.serialized_len(), loc.to_string().len());
+    /// ```
+    pub fn serialized_len(&self) -> usize {
+        struct LengthCounter(usize);
+
+        impl std::fmt::Write for LengthCounter {
+            fn write_str(&mut self, s: &str) -> std::fmt::Result {
+                self.0 += s.len();
+                Ok(())
+            }
+        }
+
+        let mut counter = LengthCounter(0);
+        std::fmt::Write::write_fmt(&mut counter, format_args!("{}", self))
+            .expect("Writing to a LengthCounter cannot fail.");
+        counter.0
+    }
+
+    /// Encodes the `Locale` into a versioned binary form, meant for values
+    /// persisted in databases or caches and read back with `from_stable_bytes`.
+    ///
+    /// The encoding starts with the `0xB7` magic byte and a format version,
+    /// followed by the version specific payload. Version `1` stores the canonical
+    /// serialization of the `Locale` as ASCII. Version `2`, the current one,
+    /// precedes it with a byte of flags recording whether the extensions were
+    /// parsed with `ParserOptions::lenient`, so that they decode with the same
+    /// option.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "en-US-u-hc-h12".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// let bytes = loc.to_stable_bytes();
+    /// assert_eq!(&bytes[..3], &[0xB7, 2, 0]);
+    /// assert_eq!(&bytes[3..], b"en-US-u-hc-h12");
+    /// ```
+    pub fn to_stable_bytes(&self) -> Vec<u8> {
+        stable::encode(self)
+    }
+
+    /// Decodes a `Locale` encoded with `to_stable_bytes`.
+    ///
+    /// Every format version written by an earlier version of this crate is
+    /// accepted. Values written with an unknown, newer format version are
+    /// rejected with `StableDecodingError::UnsupportedVersion` and need to be
+    /// migrated.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::{Locale, StableDecodingError};
+    ///
+    /// let loc: Locale = "en-US-u-hc-h12".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(Locale::from_stable_bytes(&loc.to_stable_bytes()), Ok(loc));
+    /// assert_eq!(
+    ///     Locale::from_stable_bytes(&[0xB7, 200, b'e', b'n']),
+    ///     Err(StableDecodingError::UnsupportedVersion(200))
+    /// );
+    /// ```
+    pub fn from_stable_bytes(bytes: &[u8]) -> Result<Self, StableDecodingError> {
+        stable::decode(bytes)
+    }
+
+    /// Serializes the `Locale` into a POSIX (glibc style) locale name, such as
+    /// the values of the `LANG` and `LC_*` environment variables.
+    ///
+    /// The result has the form `language[_REGION][.charset][@modifier]`. A script
+    /// or variant subtag is written as the `@modifier` where glibc defines one
+    /// (e.g. `sr-Latn` becomes `@latin`, `ca-valencia` becomes `@valencia`),
+    /// and is dropped otherwise. The `cu-eur` keyword becomes `@euro`, and the
+    /// other extensions are dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "sr-Latn-RS".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.to_unix_locale(Some("UTF-8")), "sr_RS.UTF-8@latin");
+    ///
+    /// let loc: Locale = "en-US-u-hc-h12".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.to_unix_locale(None), "en_US");
+    /// ```
+    #[doc(alias = "to_posix")]
+    pub fn to_unix_locale(&self, charset: Option<&str>) -> String {
+        posix::to_unix_locale(self, charset)
+    }
+
+    /// Parses a POSIX (glibc style) locale name, such as `sr_RS.UTF-8@latin`,
+    /// as when detecting the system locale in `Locale::current`.
+    ///
+    /// The charset is ignored. A modifier written by `to_unix_locale` becomes the
+    /// script or variant subtag it stands for (e.g. `@latin` becomes `sr-Latn`),
+    /// `@euro` becomes the `cu-eur` unicode extension keyword if the `extensions`
+    /// feature is enabled, and any other modifier is dropped. The special `C` and
+    /// `POSIX` names map to `und`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc = Locale::from_unix_locale("sr_RS.UTF-8@latin")
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_string(), "sr-Latn-RS");
+    ///
+    /// let loc = Locale::from_unix_locale("ca_ES@valencia")
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_string(), "ca-ES-valencia");
+    ///
+    /// let loc = Locale::from_unix_locale("C.UTF-8")
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_string(), "und");
+    /// ```
+    #[doc(alias = "from_posix")]
+    pub fn from_unix_locale(name: &str) -> Result<Self, LocaleError> {
+        posix::from_unix_locale(name, &PosixOptions::default())
+    }
+
+    /// Like `from_unix_locale`, but maps the `C` and `POSIX` names as configured
+    /// in the `PosixOptions`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::{Locale, PosixOptions};
+    ///
+    /// let options = PosixOptions {
+    ///     c_locale: "en-US-u-va-posix".parse().expect("Parsing failed."),
+    /// };
+    ///
+    /// let loc = Locale::from_unix_locale_with_options("POSIX", &options)
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_string(), "en-US-u-va-posix");
+    /// ```
+    pub fn from_unix_locale_with_options(
+        name: &str,
+        options: &PosixOptions,
+    ) -> Result<Self, LocaleError> {
+        posix::from_unix_locale(name, options)
+    }
+
+    /// Parses an Apple `CFLocale` identifier, such as `th_TH@calendar=buddhist`,
+    /// mapping its keywords to unicode extension keywords.
+    ///
+    /// The known keywords are `calendar` (`ca`), `collation` (`co`), `currency` (`cu`)
+    /// and `numbers` (`nu`), and any other keyword is an error. Values with a different
+    /// spelling in BCP47, such as `gregorian` for `gregory`, are replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc = Locale::from_apple_identifier("th_TH@calendar=buddhist;numbers=thai")
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_string(), "th-TH-u-ca-buddhist-nu-thai");
+    /// ```
+    #[cfg(feature = "extensions")]
+    pub fn from_apple_identifier(identifier: &str) -> Result<Self, LocaleError> {
+        apple::from_apple_identifier(identifier)
+    }
+
+    /// Serializes the `Locale` into an Apple `CFLocale` identifier, writing the
+    /// unicode extension keywords with a `CFLocale` equivalent, sorted by name.
+    ///
+    /// Other extensions are not written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "ja-JP-u-nu-jpan-ca-japanese-hc-h12".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_apple_identifier(), "ja_JP@calendar=japanese;numbers=jpan");
+    /// ```
+    #[cfg(feature = "extensions")]
+    pub fn to_apple_identifier(&self) -> String {
+        apple::to_apple_identifier(self)
+    }
+
+    /// Parses a Windows locale name, such as `de-DE_phoneb`, mapping the alternate
+    /// sort suffix to the `co` unicode extension keyword.
+    ///
+    /// The suffixes mapped to a collation are `phoneb` (`phonebk`), `pronun` (`zhuyin`),
+    /// `radstr` (`unihan`), `stroke` (`stroke`) and `tradnl` (`trad`). The `modern` and
+    /// `technl` suffixes select Windows specific sorts without a CLDR collation, and
+    /// are dropped. Any other suffix is an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc = Locale::from_windows_name("de-DE_phoneb")
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_string(), "de-DE-u-co-phonebk");
+    ///
+    /// let loc = Locale::from_windows_name("hu-HU_technl")
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_string(), "hu-HU");
+    /// ```
+    #[cfg(feature = "extensions")]
+    #[doc(alias = "from_windows_locale_name")]
+    pub fn from_windows_name(name: &str) -> Result<Self, LocaleError> {
+        windows::from_windows_name(name)
+    }
+
+    /// Serializes the `Locale` into a Windows locale name, writing a `co` keyword
+    /// with a Windows equivalent as the alternate sort suffix.
+    ///
+    /// Other extensions are not written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "zh-TW-u-co-zhuyin-nu-hanidec".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_windows_name(), "zh-TW_pronun");
+    /// ```
+    #[cfg(feature = "extensions")]
+    #[doc(alias = "to_windows_locale_name")]
+    pub fn to_windows_name(&self) -> String {
+        windows::to_windows_name(self)
+    }
+
+    /// Serializes the `Locale` into the form produced by Java's `Locale.toString()`,
+    /// including the `#` section for the script and extensions.
+    ///
+    /// An undefined language is written as an empty string, and private use subtags
+    /// after `lvariant` are written as variants. The form produced by Java's
+    /// `Locale.toLanguageTag()` is the one of `Locale::to_string`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "zh-Hant-TW".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.to_java_string(), "zh_TW_#Hant");
+    ///
+    /// let loc: Locale = "th-TH-u-nu-thai".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.to_java_string(), "th_TH_#u-nu-thai");
+    /// ```
+    pub fn to_java_string(&self) -> String {
+        java::to_java_string(self)
+    }
+
+    /// Parses the form produced by Java's `Locale.toString()`, such as
+    /// `th_TH_#u-nu-thai`.
+    ///
+    /// As in Java's `Locale.toLanguageTag()`, the first variant which is not a
+    /// well-formed BCP47 variant and all the following ones are kept as private
+    /// use subtags after `lvariant`, which `Locale::to_java_string` writes back
+    /// as variants.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc = Locale::from_java_string("zh_TW_#Hant")
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_string(), "zh-Hant-TW");
+    ///
+    /// let loc = Locale::from_java_string("th_TH_TH_#u-nu-thai")
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.to_string(), "th-TH-u-nu-thai-x-lvariant-th");
+    /// assert_eq!(loc.to_java_string(), "th_TH_th_#u-nu-thai");
+    /// ```
+    pub fn from_java_string(input: &str) -> Result<Self, LocaleError> {
+        java::from_java_string(input)
+    }
+
+    /// Returns a value for the HTML `lang` attribute, the canonical language
+    /// identifier without the extensions.
+    ///
+    /// With the `likelysubtags` feature, an undefined language is replaced with
+    /// the most likely one, so `und-TH` gives `th-TH` rather than `und-TH`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "th-TH-u-ca-buddhist".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.to_html_lang(), "th-TH");
+    /// ```
+    pub fn to_html_lang(&self) -> String {
+        #[cfg_attr(
+            not(any(feature = "likelysubtags", feature = "iso639")),
+            allow(unused_mut)
+        )]
+        let mut id = self.id.clone();
+        #[cfg(feature = "iso639")]
+        unic_langid_impl::iso639::canonicalize_language(&mut id.language);
+        #[cfg(feature = "likelysubtags")]
+        if id.language.is_empty() {
+            if let Some((language, _, _)) =
+                unic_langid_impl::likelysubtags::maximize(id.language, id.script, id.region)
+            {
+                id.language = language;
+            }
+        }
+        id.to_string()
+    }
+
+    /// Returns the kind of pseudo-locale the `Locale` is, based on its
+    /// `XA` or `XB` region subtag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::{Locale, PseudoLocale};
+    ///
+    /// let loc: Locale = "en-XA".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.pseudo_locale(), Some(PseudoLocale::Accented));
+    ///
+    /// let loc: Locale = "ar-XB".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.pseudo_locale(), Some(PseudoLocale::Bidi));
+    ///
+    /// let loc: Locale = "en-US".parse()
+    ///     .expect("Parsing failed.");
+    /// assert_eq!(loc.pseudo_locale(), None);
+    /// ```
+    pub fn pseudo_locale(&self) -> Option<PseudoLocale> {
+        self.id.region.and_then(PseudoLocale::from_region)
+    }
+
+    /// Returns `true` if the `Locale` is a pseudo-locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "en-XB".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert!(loc.is_pseudo_locale());
+    /// ```
+    pub fn is_pseudo_locale(&self) -> bool {
+        self.pseudo_locale().is_some()
+    }
+
+    /// Checks the `Locale` against the given level of conformance, returning the
+    /// problems found at that level and the ones below it.
+    ///
+    /// Parsing already guarantees well-formedness, while validity covers unicode
+    /// extension keyword values and, with the `iso3166` feature, region subtags.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::{CheckLevel, Finding, Locale, Transformation};
+    ///
+    /// let loc: Locale = "en-u-ca-islamicc-hc-h10".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert!(loc.check(CheckLevel::WellFormed).is_empty());
+    ///
+    /// let findings = loc.check(CheckLevel::Canonical);
+    /// assert_eq!(findings.len(), 3);
+    /// assert!(matches!(findings[0], Finding::InvalidKeyword(_)));
+    /// assert_eq!(findings[0].level(), CheckLevel::Valid);
+    /// assert_eq!(findings[2], Finding::NotCanonical(Transformation::AliasReplaced {
+    ///     from: "ca-islamicc".to_string(),
+    ///     to: "ca-islamic-civil".to_string(),
+    /// }));
+    /// ```
+    pub fn check(&self, level: CheckLevel) -> Vec<Finding> {
+        check::check(self, level)
+    }
+
+    /// Derives the pseudo-locale of the given kind for the `Locale`, by replacing
+    /// its region subtag.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::{Locale, PseudoLocale};
+    ///
+    /// let loc: Locale = "fr-CA-u-hc-h23".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc.to_pseudo_locale(PseudoLocale::Accented).to_string(), "fr-XA-u-hc-h23");
+    /// assert_eq!(loc.to_pseudo_locale(PseudoLocale::Bidi).to_string(), "fr-XB-u-hc-h23");
+    /// ```
+    pub fn to_pseudo_locale(&self, kind: PseudoLocale) -> Locale {
+        pseudo::to_pseudo_locale(self, kind)
+    }
+
+    /// Returns character direction of the `Locale`.
+    ///
+    /// An explicit script subtag takes precedence. Without one, and with the
+    /// `likelysubtags` feature enabled, the likely script for the language and
+    /// region is used, so that `pa-PK` is resolved as right-to-left even though
+    /// `pa` alone is left-to-right.
+    ///
+    /// A bidi pseudo-locale, such as `en-XB`, is always right-to-left.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::{Locale, CharacterDirection};
+    ///
+    /// let loc1: Locale = "pa-IN".parse()
+    ///     .expect("Parsing failed.");
+    /// let loc2: Locale = "pa-Arab-PK".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert_eq!(loc1.character_direction(), CharacterDirection::LTR);
+    /// assert_eq!(loc2.character_direction(), CharacterDirection::RTL);
+    /// ```
+    #[cfg(feature = "character_direction")]
+    pub fn character_direction(&self) -> CharacterDirection {
+        if self.pseudo_locale() == Some(PseudoLocale::Bidi) {
+            return CharacterDirection::RTL;
+        }
+        #[cfg(feature = "likelysubtags")]
+        if self.id.script.is_none() {
+            let mut id = self.id.clone();
+            if id.maximize() && id.script.is_some() {
+                return id.character_direction();
+            }
+        }
+        self.id.character_direction()
+    }
+
+    /// Returns `true` if the [`character_direction`] of the `Locale` is right-to-left.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "ar-EG-u-nu-latn".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert!(loc.is_rtl());
+    /// ```
+    ///
+    /// [`character_direction`]: #method.character_direction
+    #[cfg(feature = "character_direction")]
+    pub fn is_rtl(&self) -> bool {
+        self.character_direction() == CharacterDirection::RTL
+    }
+
+    /// Returns `true` if the [`character_direction`] of the `Locale` is left-to-right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::Locale;
+    ///
+    /// let loc: Locale = "sr-Latn-RS".parse()
+    ///     .expect("Parsing failed.");
+    ///
+    /// assert!(loc.is_ltr());
+    /// ```
+    ///
+    /// [`character_direction`]: #method.character_direction
+    #[cfg(feature = "character_direction")]
+    pub fn is_ltr(&self) -> bool {
+        self.character_direction() == CharacterDirection::LTR
+    }
+}
+
+impl FromStr for Locale {
+    type Err = LocaleError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Ok(parser::parse_locale(source)?)
+    }
+}
+
+impl From<LanguageIdentifier> for Locale {
+    fn from(id: LanguageIdentifier) -> Self {
+        Locale {
+            id,
+            extensions: ExtensionsMap::default(),
+        }
+    }
+}
+
+impl From<&LanguageIdentifier> for Locale {
+    fn from(id: &LanguageIdentifier) -> Self {
+        Self::from(id.clone())
+    }
+}
+
+impl From<Locale> for LanguageIdentifier {
+    fn from(locale: Locale) -> Self {
+        locale.id
+    }
+}
+
+impl From<Locale> for String {
+    /// Produces the canonical serialization of the `Locale`.
+    fn from(locale: Locale) -> Self {
+        locale.to_string()
+    }
+}
+
+impl AsRef<LanguageIdentifier> for Locale {
+    fn as_ref(&self) -> &LanguageIdentifier {
+        &self.id
+    }
+}
+
+impl AsRef<Locale> for Locale {
+    #[inline(always)]
+    fn as_ref(&self) -> &Locale {
+        self
+    }
+}
+
+impl std::fmt::Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if self.is_private_use_only() {
+            let extensions = self.extensions.to_string();
+            return f.write_str(extensions.trim_start_matches('-'));
+        }
+        self.id.fmt(f)?;
+        if !self.extensions.is_empty() {
+            self.extensions.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Versions of the CLDR data compiled into the crate.
+///
+/// Each table may be based on a different CLDR release. The likely subtags,
+/// character direction, currency and aliases versions are written by the generators
+/// from the bundled CLDR data; the other tables are curated by hand, and report
+/// the release they follow.
+///
+/// A dataset whose feature is disabled is reported as `None`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DataVersion {
+    /// CLDR version of the likely subtags data, with the `likelysubtags` feature.
+    pub likely_subtags: Option<&'static str>,
+    /// CLDR version of the character direction data, with the `character_direction`
+    /// feature.
+    pub character_direction: Option<&'static str>,
+    /// CLDR version of the unicode extension keyword validation and deprecation data,
+    /// with the `extensions` feature.
+    pub unicode_extensions: Option<&'static str>,
+    /// CLDR version of the territory languages data, with the `territoryinfo` feature.
+    pub territory_info: Option<&'static str>,
+    /// CLDR version of the currency data, with the `currencydata` feature.
+    pub currency: Option<&'static str>,
+    /// CLDR version of the primary time zone data, with the `timezones` feature.
+    pub time_zones: Option<&'static str>,
+    /// CLDR version of the subtag and legacy tag aliases, with the `aliases` feature.
+    pub aliases: Option<&'static str>,
+    /// CLDR version of the regional defaults used by [`Locale::resolve_preferences`].
+    pub regional_preferences: &'static str,
+}
+
+/// Returns the versions of the CLDR data compiled into the crate,
+/// so that deployments can audit and log which locale data they ship.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::data_version;
+///
+/// let version = data_version();
+///
+/// assert_eq!(version.character_direction, Some("37"));
+/// assert_eq!(version.unicode_extensions, Some("47"));
+/// assert_eq!(version.regional_preferences, "47");
+/// ```
+pub fn data_version() -> DataVersion {
+    #[cfg(feature = "likelysubtags")]
+    let likely_subtags = Some(unic_langid_impl::likelysubtags::CLDR_VERSION);
+    #[cfg(not(feature = "likelysubtags"))]
+    let likely_subtags = None;
+
+    #[cfg(feature = "character_direction")]
+    let character_direction = Some(unic_langid_impl::LAYOUT_CLDR_VERSION);
+    #[cfg(not(feature = "character_direction"))]
+    let character_direction = None;
+
+    #[cfg(feature = "extensions")]
+    let unicode_extensions = Some(extensions::UNICODE_CLDR_VERSION);
+    #[cfg(not(feature = "extensions"))]
+    let unicode_extensions = None;
+
+    #[cfg(feature = "territoryinfo")]
+    let territory_info = Some(territory::CLDR_VERSION);
+    #[cfg(not(feature = "territoryinfo"))]
+    let territory_info = None;
+
+    #[cfg(feature = "currencydata")]
+    let currency = Some(currency::CLDR_VERSION);
+    #[cfg(not(feature = "currencydata"))]
+    let currency = None;
+
+    #[cfg(feature = "timezones")]
+    let time_zones = Some(timezone::CLDR_VERSION);
+    #[cfg(not(feature = "timezones"))]
+    let time_zones = None;
+
+    #[cfg(feature = "aliases")]
+    let aliases = Some(unic_langid_impl::aliases::CLDR_VERSION);
+    #[cfg(not(feature = "aliases"))]
+    let aliases = None;
+
+    DataVersion {
+        likely_subtags,
+        character_direction,
+        unicode_extensions,
+        territory_info,
+        currency,
+        time_zones,
+        aliases,
+        regional_preferences: preferences::CLDR_VERSION,
+    }
+}
+
+/// This is a best-effort operation that performs all available levels of canonicalization.
+///
+/// At the moment the operation will normalize casing and the separator, replace
+/// deprecated unicode extension keys and values, and with the `iso639` feature replace
+/// three-letter language codes with their two-letter equivalents. With the `aliases`
+/// feature, [`canonicalize_with_mode`] also replaces deprecated subtags.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::canonicalize;
+///
+/// assert_eq!(canonicalize("pL_latn_pl-U-HC-H12"), Ok("pl-Latn-PL-u-hc-h12".to_string()));
+/// assert_eq!(canonicalize("th-u-ca-islamicc-vt-0061"), Ok("th-u-ca-islamic-civil".to_string()));
+/// ```
+///
+/// [`canonicalize_with_mode`]: fn.canonicalize_with_mode.html
+pub fn canonicalize<S: AsRef<[u8]>>(input: S) -> Result<String, LocaleError> {
+    canonicalize_with_mode(input, CanonicalizationMode::Syntax)
+}
+
+/// The canonicalization performed by [`canonicalize_with_mode`].
+///
+/// [`canonicalize_with_mode`]: fn.canonicalize_with_mode.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum CanonicalizationMode {
+    /// The canonicalization of [`canonicalize`].
+    ///
+    /// [`canonicalize`]: fn.canonicalize.html
+    #[default]
+    Syntax,
+    /// Also replace legacy tags and deprecated subtags with their CLDR aliases,
+    /// such as `i-klingon` with `tlh` or `in` with `id`.
+    ///
+    /// The aliases are only available with the `aliases` feature; without it, this
+    /// is the same as `Syntax`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::{canonicalize_with_mode, CanonicalizationMode};
+    ///
+    /// # #[cfg(feature = "aliases")] {
+    /// assert_eq!(
+    ///     canonicalize_with_mode("iw_il", CanonicalizationMode::Aliases),
+    ///     Ok("he-IL".to_string())
+    /// );
+    /// assert_eq!(
+    ///     canonicalize_with_mode("i-klingon", CanonicalizationMode::Aliases),
+    ///     Ok("tlh".to_string())
+    /// );
+    /// # }
+    /// ```
+    Aliases,
+}
+
+/// Canonicalizes the input like [`canonicalize`], optionally replacing aliases.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::{canonicalize_with_mode, CanonicalizationMode};
+///
+/// assert_eq!(
+///     canonicalize_with_mode("iw_il", CanonicalizationMode::Syntax),
+///     Ok("iw-IL".to_string())
+/// );
+/// ```
+///
+/// [`canonicalize`]: fn.canonicalize.html
+#[cfg_attr(not(feature = "aliases"), allow(unused_variables))]
+pub fn canonicalize_with_mode<S: AsRef<[u8]>>(
+    input: S,
+    mode: CanonicalizationMode,
+) -> Result<String, LocaleError> {
+    #[cfg_attr(not(feature = "aliases"), allow(unused_mut))]
+    let mut input = input.as_ref();
+    #[cfg(feature = "aliases")]
+    if mode == CanonicalizationMode::Aliases {
+        if let Some(tag) = std::str::from_utf8(input)
+            .ok()
+            .and_then(unic_langid_impl::aliases::legacy_tag)
+        {
+            input = tag.as_bytes();
+        }
+    }
+    let mut locale = Locale::from_bytes(input)?;
+    canonicalize_locale(&mut locale);
+    #[cfg(feature = "aliases")]
+    if mode == CanonicalizationMode::Aliases {
+        locale.canonicalize_aliases();
+    }
+    Ok(locale.to_string())
+}
+
+#[cfg_attr(
+    not(any(feature = "extensions", feature = "iso639")),
+    allow(unused_variables)
+)]
+pub(crate) fn canonicalize_locale(locale: &mut Locale) {
+    #[cfg(feature = "extensions")]
+    locale.extensions.unicode.replace_deprecated();
+    #[cfg(feature = "iso639")]
+    unic_langid_impl::iso639::canonicalize_language(&mut locale.id.language);
 }
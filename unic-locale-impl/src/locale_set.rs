@@ -0,0 +1,158 @@
+//! A set of canonicalized locales, such as the locales supported by an application.
+use crate::errors::LocaleError;
+use crate::{accept_language, Locale};
+use std::collections::BTreeMap;
+use std::iter::FromIterator;
+
+/// A set of deduplicated locales, canonicalized on insertion and iterated
+/// in the order of their canonical serialization.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::{Locale, LocaleSet};
+///
+/// let supported: LocaleSet = ["fr-CA", "en-US", "EN_us", "en", "sr-Latn"]
+///     .iter()
+///     .map(|s| s.parse::<Locale>().expect("Parsing failed."))
+///     .collect();
+///
+/// assert_eq!(supported.len(), 4);
+/// assert_eq!(
+///     supported.iter().map(|l| l.to_string()).collect::<Vec<_>>(),
+///     &["en", "en-US", "fr-CA", "sr-Latn"]
+/// );
+///
+/// let range: Locale = "en".parse().expect("Parsing failed.");
+/// assert_eq!(supported.filter(&range).count(), 2);
+///
+/// let requested: Locale = "sr-Latn-RS".parse().expect("Parsing failed.");
+/// assert_eq!(supported.lookup(&requested).map(Locale::to_string), Some("sr-Latn".to_string()));
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+pub struct LocaleSet {
+    locales: BTreeMap<String, Locale>,
+}
+
+impl LocaleSet {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Canonicalizes the locale and adds it to the set. Returns `true` if the set
+    /// did not contain it yet.
+    pub fn insert<L: Into<Locale>>(&mut self, locale: L) -> bool {
+        let mut locale = locale.into();
+        crate::canonicalize_locale(&mut locale);
+        let key = locale.to_string();
+        if self.locales.contains_key(&key) {
+            return false;
+        }
+        self.locales.insert(key, locale);
+        true
+    }
+
+    /// Parses the input and adds the resulting locale to the set. Returns `true`
+    /// if the set did not contain it yet.
+    pub fn insert_str<S: AsRef<[u8]>>(&mut self, input: S) -> Result<bool, LocaleError> {
+        Ok(self.insert(Locale::from_bytes(input.as_ref())?))
+    }
+
+    /// Removes the canonical form of the locale from the set. Returns `true` if
+    /// the set contained it.
+    pub fn remove(&mut self, locale: &Locale) -> bool {
+        self.locales.remove(&canonical_key(locale)).is_some()
+    }
+
+    /// Returns `true` if the set contains the canonical form of the locale.
+    pub fn contains(&self, locale: &Locale) -> bool {
+        self.locales.contains_key(&canonical_key(locale))
+    }
+
+    /// Returns an iterator over the locales matched by the range, such as `en-US`
+    /// and `en-GB` for `en`, as in [`Locale::matches`] with the range as a range.
+    ///
+    /// [`Locale::matches`]: struct.Locale.html#method.matches
+    pub fn filter<'a>(&'a self, range: &'a Locale) -> impl Iterator<Item = &'a Locale> {
+        self.iter()
+            .filter(move |locale| range.matches(locale, true, false))
+    }
+
+    /// Returns the locale of the set best matching the range, by removing its
+    /// subtags from the end until the language identifier of a locale matches,
+    /// as in `sr-Latn-RS`, `sr-Latn`, then `sr`.
+    pub fn lookup(&self, range: &Locale) -> Option<&Locale> {
+        accept_language::lookup_fallbacks(range)
+            .iter()
+            .find_map(|id| self.iter().find(|locale| locale.id == *id))
+    }
+
+    /// Returns the set of locales contained in either set.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut result = self.clone();
+        for (key, locale) in &other.locales {
+            result
+                .locales
+                .entry(key.clone())
+                .or_insert_with(|| locale.clone());
+        }
+        result
+    }
+
+    /// Returns the set of locales contained in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let locales = self
+            .locales
+            .iter()
+            .filter(|(key, _)| other.locales.contains_key(*key))
+            .map(|(key, locale)| (key.clone(), locale.clone()))
+            .collect();
+        Self { locales }
+    }
+
+    /// Returns the number of locales in the set.
+    pub fn len(&self) -> usize {
+        self.locales.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.locales.is_empty()
+    }
+
+    /// Returns an iterator over the locales, in the order of their canonical
+    /// serialization.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &Locale> {
+        self.locales.values()
+    }
+}
+
+fn canonical_key(locale: &Locale) -> String {
+    let mut locale = locale.clone();
+    crate::canonicalize_locale(&mut locale);
+    locale.to_string()
+}
+
+impl<L: Into<Locale>> Extend<L> for LocaleSet {
+    fn extend<T: IntoIterator<Item = L>>(&mut self, iter: T) {
+        for locale in iter {
+            self.insert(locale);
+        }
+    }
+}
+
+impl<L: Into<Locale>> FromIterator<L> for LocaleSet {
+    fn from_iter<T: IntoIterator<Item = L>>(iter: T) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a> IntoIterator for &'a LocaleSet {
+    type Item = &'a Locale;
+    type IntoIter = std::collections::btree_map::Values<'a, String, Locale>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.locales.values()
+    }
+}
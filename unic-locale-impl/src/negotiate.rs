@@ -0,0 +1,367 @@
+//! Negotiation of requested locales against the available ones, with pluggable
+//! strategies.
+//!
+//! The built-in strategies follow [`RFC 4647`]: [`Filtering`] returns all the
+//! available locales matching each requested one, [`Matching`] the best of them,
+//! and [`Lookup`] the single best locale for the first requested locale with one.
+//! [`ScoreWeighting`] ranks the available locales by their score, as the weighted
+//! `Accept-Language` negotiation does.
+//! Applications with their own rules implement [`NegotiationStrategy`], usually
+//! by wrapping a built-in one, and [`negotiate_languages`] adds the developer
+//! defaults to the result of any strategy, according to a [`DefaultsPolicy`].
+//!
+//! Strategies negotiate plain lists of requested locales, as well as weighted
+//! [`LanguagePriorityList`]s such as `Accept-Language` header values.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_locale_impl::Locale;
+//! use unic_locale_impl::negotiate::{Filtering, NegotiationStrategy};
+//!
+//! // Only offer the locales of the markets the application is sold in.
+//! struct Markets<'r>(&'r [&'r str]);
+//!
+//! impl NegotiationStrategy for Markets<'_> {
+//!     fn matches<'a>(&self, requested: &Locale, available: &'a [Locale]) -> Vec<&'a Locale> {
+//!         let mut result = Filtering.matches(requested, available);
+//!         result.retain(|locale| locale.id.region.is_some_and(|r| self.0.contains(&r.as_str())));
+//!         result
+//!     }
+//! }
+//!
+//! let requested: Vec<Locale> = vec!["en".parse().unwrap()];
+//! let available: Vec<Locale> = vec![
+//!     "en-US".parse().unwrap(),
+//!     "en-GB".parse().unwrap(),
+//! ];
+//!
+//! assert_eq!(Filtering.negotiate(&requested, &available).len(), 2);
+//! assert_eq!(Markets(&["GB"]).negotiate(&requested, &available), vec![&available[1]]);
+//! ```
+//!
+//! [`RFC 4647`]: https://tools.ietf.org/html/rfc4647
+//! [`ScoreWeighting`]: ../accept_language/struct.ScoreWeighting.html
+//! [`LanguagePriorityList`]: ../accept_language/struct.LanguagePriorityList.html
+//! [`DefaultsPolicy`]: ../accept_language/enum.DefaultsPolicy.html
+use crate::accept_language::{self, DefaultsPolicy, LanguagePriorityList, LanguageRange};
+#[cfg(feature = "likelysubtags")]
+use crate::subtags;
+use crate::{LanguageIdentifier, Locale};
+
+/// A rule selecting the available locales acceptable for requested locales.
+pub trait NegotiationStrategy {
+    /// Returns the available locales acceptable for a requested locale, best first.
+    fn matches<'a>(&self, requested: &Locale, available: &'a [Locale]) -> Vec<&'a Locale>;
+
+    /// Returns the maximum number of negotiated locales, or `None` for no limit.
+    fn limit(&self) -> Option<usize> {
+        None
+    }
+
+    /// Returns the acceptable available locales for each of the requested locales,
+    /// in order of preference and without duplicates, up to the limit.
+    fn negotiate<'a>(&self, requested: &[Locale], available: &'a [Locale]) -> Vec<&'a Locale> {
+        let limit = self.limit().unwrap_or(usize::MAX);
+        let mut result: Vec<&Locale> = vec![];
+        for requested in requested {
+            if !push_matches(&mut result, self.matches(requested, available), limit) {
+                break;
+            }
+        }
+        result
+    }
+
+    /// Returns the acceptable available locales for each of the ranges of a weighted
+    /// list, in order of preference and without duplicates, up to the limit.
+    ///
+    /// Ranges with a weight of `0` are skipped, and the wildcard matches the available
+    /// locales not matched by another range.
+    fn negotiate_list<'a>(
+        &self,
+        list: &LanguagePriorityList,
+        available: &'a [Locale],
+    ) -> Vec<&'a Locale> {
+        let limit = self.limit().unwrap_or(usize::MAX);
+        let mut result: Vec<&Locale> = vec![];
+        for (range, weight) in list.iter() {
+            if weight <= 0.0 {
+                continue;
+            }
+            let matches = match range {
+                LanguageRange::Locale(requested) => self.matches(requested, available),
+                LanguageRange::Wildcard => list.wildcard_matches(available),
+            };
+            if !push_matches(&mut result, matches, limit) {
+                break;
+            }
+        }
+        result
+    }
+}
+
+impl<S: NegotiationStrategy + ?Sized> NegotiationStrategy for &S {
+    fn matches<'a>(&self, requested: &Locale, available: &'a [Locale]) -> Vec<&'a Locale> {
+        (**self).matches(requested, available)
+    }
+
+    fn limit(&self) -> Option<usize> {
+        (**self).limit()
+    }
+
+    fn negotiate<'a>(&self, requested: &[Locale], available: &'a [Locale]) -> Vec<&'a Locale> {
+        (**self).negotiate(requested, available)
+    }
+
+    fn negotiate_list<'a>(
+        &self,
+        list: &LanguagePriorityList,
+        available: &'a [Locale],
+    ) -> Vec<&'a Locale> {
+        (**self).negotiate_list(list, available)
+    }
+}
+
+/// Appends the matches which were not negotiated yet, returning `false` once the
+/// limit is reached.
+fn push_matches<'a>(result: &mut Vec<&'a Locale>, matches: Vec<&'a Locale>, limit: usize) -> bool {
+    for locale in matches {
+        if result.len() == limit {
+            return false;
+        }
+        if !result.contains(&locale) {
+            result.push(locale);
+        }
+    }
+    true
+}
+
+/// A predicate selecting the available locales of one matching tier.
+type Tier<'r> = Box<dyn Fn(&Locale) -> bool + 'r>;
+
+/// Returns the language and script of the maximized locale.
+#[cfg(feature = "likelysubtags")]
+fn likely_language_script(locale: &Locale) -> (subtags::Language, Option<subtags::Script>) {
+    let id = &locale.id;
+    match unic_langid_impl::likelysubtags::maximize(id.language, id.script, id.region) {
+        Some((language, script, _)) => (language, script),
+        None => (id.language, id.script),
+    }
+}
+
+/// Negotiates the requested locales against the available ones with a strategy,
+/// followed by the developer defaults as selected by the policy, as far as the
+/// limit of the strategy allows.
+///
+/// With the `likelysubtags` feature, the built-in strategies also match locales
+/// with the same likely language and script, such as `zh-Hant` for `zh-TW`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::Locale;
+/// use unic_locale_impl::accept_language::DefaultsPolicy;
+/// use unic_locale_impl::negotiate::{negotiate_languages, Filtering, Lookup};
+///
+/// let requested: Vec<Locale> = vec!["fr-CH".parse().unwrap(), "de".parse().unwrap()];
+/// let available: Vec<Locale> = vec![
+///     "en-US".parse().unwrap(),
+///     "de-DE".parse().unwrap(),
+///     "fr".parse().unwrap(),
+/// ];
+/// let defaults = &available[..1];
+///
+/// assert_eq!(
+///     negotiate_languages(&requested, &available, defaults, DefaultsPolicy::Always, Filtering),
+///     vec![&available[2], &available[1], &available[0]]
+/// );
+///
+/// let requested: Vec<Locale> = vec!["pl".parse().unwrap()];
+/// assert_eq!(
+///     negotiate_languages(&requested, &available, defaults, DefaultsPolicy::OnlyIfEmpty, Lookup),
+///     vec![&available[0]]
+/// );
+/// ```
+pub fn negotiate_languages<'a, S: NegotiationStrategy>(
+    requested: &[Locale],
+    available: &'a [Locale],
+    defaults: &'a [Locale],
+    policy: DefaultsPolicy,
+    strategy: S,
+) -> Vec<&'a Locale> {
+    let mut result = strategy.negotiate(requested, available);
+    append_defaults(&mut result, defaults, policy, strategy.limit());
+    result
+}
+
+/// Appends the defaults which were not negotiated yet, as selected by the policy
+/// and up to the limit.
+pub(crate) fn append_defaults<'a>(
+    result: &mut Vec<&'a Locale>,
+    defaults: &'a [Locale],
+    policy: DefaultsPolicy,
+    limit: Option<usize>,
+) {
+    let append = match policy {
+        DefaultsPolicy::Always => true,
+        DefaultsPolicy::OnlyIfEmpty => result.is_empty(),
+        DefaultsPolicy::Never => false,
+    };
+    if append {
+        push_matches(
+            result,
+            defaults.iter().collect(),
+            limit.unwrap_or(usize::MAX),
+        );
+    }
+}
+
+/// Returns the available locales matching the requested one in successively
+/// looser tiers, stopping at the first tier if `first_only` is set.
+fn tiers<'a>(requested: &Locale, available: &'a [Locale], first_only: bool) -> Vec<&'a Locale> {
+    let language_script: Locale =
+        LanguageIdentifier::from_parts(requested.id.language, requested.id.script, None, &[])
+            .into();
+    #[cfg(feature = "likelysubtags")]
+    let likely = likely_language_script(requested);
+    let mut tiers: Vec<Tier> = vec![
+        // The same language identifier.
+        Box::new(|locale| locale.matches(requested, false, false)),
+        // A less specific locale, such as `en` for `en-US`.
+        Box::new(|locale| locale.matches(requested, true, false)),
+        // A more specific locale, such as `en-US` for `en`.
+        Box::new(|locale| requested.matches(locale, true, false)),
+    ];
+    // The same likely language and script, such as `zh-Hant` for `zh-TW`.
+    #[cfg(feature = "likelysubtags")]
+    tiers.push(Box::new(move |locale| {
+        likely.1.is_some() && likely_language_script(locale) == likely
+    }));
+    // Another region, such as `en-GB` for `en-US`.
+    tiers.push(Box::new(move |locale| {
+        language_script.matches(locale, true, false)
+    }));
+
+    let mut result: Vec<&Locale> = vec![];
+    for tier in tiers {
+        for locale in available.iter().filter(|locale| tier(locale)) {
+            if first_only {
+                return vec![locale];
+            }
+            if !result.contains(&locale) {
+                result.push(locale);
+            }
+        }
+    }
+    result
+}
+
+/// Returns all the available locales matching each requested locale, from
+/// identical language identifiers to other regions of the same language and script.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::Locale;
+/// use unic_locale_impl::negotiate::{Filtering, NegotiationStrategy};
+///
+/// let requested: Vec<Locale> = vec!["de-AT".parse().unwrap(), "en".parse().unwrap()];
+/// let available: Vec<Locale> = vec![
+///     "en-US".parse().unwrap(),
+///     "de-DE".parse().unwrap(),
+///     "de".parse().unwrap(),
+/// ];
+///
+/// let result: Vec<_> = Filtering.negotiate(&requested, &available)
+///     .iter()
+///     .map(|locale| locale.to_string())
+///     .collect();
+/// assert_eq!(result, &["de", "de-DE", "en-US"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Filtering;
+
+impl NegotiationStrategy for Filtering {
+    fn matches<'a>(&self, requested: &Locale, available: &'a [Locale]) -> Vec<&'a Locale> {
+        tiers(requested, available, false)
+    }
+}
+
+/// Returns the best of the available locales matching each requested locale,
+/// as ordered by [`Filtering`].
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::Locale;
+/// use unic_locale_impl::negotiate::{Matching, NegotiationStrategy};
+///
+/// let requested: Vec<Locale> = vec!["de-AT".parse().unwrap(), "en".parse().unwrap()];
+/// let available: Vec<Locale> = vec![
+///     "en-US".parse().unwrap(),
+///     "de-DE".parse().unwrap(),
+///     "de".parse().unwrap(),
+/// ];
+///
+/// let result: Vec<_> = Matching.negotiate(&requested, &available)
+///     .iter()
+///     .map(|locale| locale.to_string())
+///     .collect();
+/// assert_eq!(result, &["de", "en-US"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Matching;
+
+impl NegotiationStrategy for Matching {
+    fn matches<'a>(&self, requested: &Locale, available: &'a [Locale]) -> Vec<&'a Locale> {
+        tiers(requested, available, true)
+    }
+}
+
+/// Returns the single available locale found by [`RFC 4647`] lookup, which removes
+/// subtags from the end of each requested locale until one is available.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::Locale;
+/// use unic_locale_impl::negotiate::{Lookup, NegotiationStrategy};
+///
+/// let requested: Vec<Locale> = vec!["fr-CH".parse().unwrap(), "de-AT".parse().unwrap()];
+/// let available: Vec<Locale> = vec![
+///     "de".parse().unwrap(),
+///     "fr-FR".parse().unwrap(),
+/// ];
+///
+/// assert_eq!(Lookup.negotiate(&requested, &available), vec![&available[0]]);
+/// ```
+///
+/// [`RFC 4647`]: https://tools.ietf.org/html/rfc4647#section-3.4
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Lookup;
+
+impl NegotiationStrategy for Lookup {
+    fn matches<'a>(&self, requested: &Locale, available: &'a [Locale]) -> Vec<&'a Locale> {
+        let mut result = LookupFallbacks.matches(requested, available);
+        result.truncate(1);
+        result
+    }
+
+    fn limit(&self) -> Option<usize> {
+        Some(1)
+    }
+}
+
+/// Returns the available locales found by [`Lookup`] for each of its fallbacks,
+/// rather than only the first one, without a limit.
+pub(crate) struct LookupFallbacks;
+
+impl NegotiationStrategy for LookupFallbacks {
+    fn matches<'a>(&self, requested: &Locale, available: &'a [Locale]) -> Vec<&'a Locale> {
+        let mut result: Vec<&Locale> = vec![];
+        for id in accept_language::lookup_fallbacks(requested) {
+            result.extend(available.iter().filter(|locale| locale.id == id));
+        }
+        result
+    }
+}
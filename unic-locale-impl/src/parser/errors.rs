@@ -1,12 +1,93 @@
+use crate::extensions::ExtensionType;
 use std::error::Error;
 use std::fmt::{self, Display};
+#[cfg(feature = "extensions")]
+use std::ops::RangeInclusive;
 use unic_langid_impl::parser::ParserError as LangIdParserError;
 
+/// Describes why a subtag is not well-formed.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum InvalidSubtagReason {
+    /// The subtag is empty.
+    Empty,
+    /// The subtag is longer than allowed.
+    TooLong,
+    /// The subtag is shorter than allowed.
+    TooShort,
+    /// The subtag contains a character which is not ASCII alphanumeric.
+    InvalidCharacter,
+}
+
+impl Display for InvalidSubtagReason {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match self {
+            InvalidSubtagReason::Empty => "it is empty",
+            InvalidSubtagReason::TooLong => "it is too long",
+            InvalidSubtagReason::TooShort => "it is too short",
+            InvalidSubtagReason::InvalidCharacter => "it contains an invalid character",
+        };
+        f.write_str(value)
+    }
+}
+
+#[cfg(feature = "extensions")]
+impl InvalidSubtagReason {
+    /// Returns why the subtag is not ASCII alphanumeric with a length in the range,
+    /// or `None` if it is.
+    pub(crate) fn check(subtag: &[u8], length: RangeInclusive<usize>) -> Option<Self> {
+        if subtag.is_empty() {
+            Some(InvalidSubtagReason::Empty)
+        } else if !subtag.iter().all(u8::is_ascii_alphanumeric) {
+            Some(InvalidSubtagReason::InvalidCharacter)
+        } else if subtag.len() < *length.start() {
+            Some(InvalidSubtagReason::TooShort)
+        } else if subtag.len() > *length.end() {
+            Some(InvalidSubtagReason::TooLong)
+        } else {
+            None
+        }
+    }
+}
+
 #[derive(Debug, PartialEq)]
+#[non_exhaustive]
 pub enum ParserError {
+    /// The input is empty, and `ParserOptions::empty_input` does not allow it.
+    EmptyInput,
     InvalidLanguage,
     InvalidSubtag,
     InvalidExtension,
+    /// A unicode extension keyword value which is not allowed for its key.
+    InvalidKeywordValue {
+        key: String,
+        value: String,
+    },
+    /// A private use subtag which is not 1 to 8 ASCII alphanumeric characters.
+    InvalidPrivateSubtag {
+        subtag: String,
+        reason: InvalidSubtagReason,
+    },
+    /// A subtag of a unicode or transform extension which is not a key and not
+    /// 3 to 8 ASCII alphanumeric characters, such as `calendar1` in `en-u-ca-calendar1`.
+    InvalidExtensionSubtag {
+        extension: ExtensionType,
+        subtag: String,
+        reason: InvalidSubtagReason,
+    },
+    /// An extension singleton which is not followed by any subtags, such as `en-u`.
+    EmptyExtension {
+        extension: ExtensionType,
+    },
+    /// A key which requires a value but has none, such as `h0` in `en-t-h0`,
+    /// or `hc` in `en-u-hc` when validating keyword values.
+    KeyWithoutValue {
+        key: String,
+    },
+    /// The input has more variant and extension subtags than fit in a
+    /// `BoundedLocale` of the given capacity.
+    CapacityExceeded {
+        capacity: usize,
+    },
     LangIdError(LangIdParserError),
 }
 
@@ -16,14 +97,61 @@ impl From<LangIdParserError> for ParserError {
     }
 }
 
+#[cfg(feature = "extensions")]
+impl ParserError {
+    /// Checks that a subtag of the extension is ASCII alphanumeric with a length
+    /// in the range.
+    pub(crate) fn check_extension_subtag(
+        extension: ExtensionType,
+        subtag: &[u8],
+        length: RangeInclusive<usize>,
+    ) -> Result<(), Self> {
+        match InvalidSubtagReason::check(subtag, length) {
+            Some(reason) => Err(ParserError::InvalidExtensionSubtag {
+                extension,
+                subtag: String::from_utf8_lossy(subtag).into_owned(),
+                reason,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
 impl Error for ParserError {}
 
 impl Display for ParserError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let value = match &self {
+            ParserError::EmptyInput => "The input is empty",
             ParserError::InvalidLanguage => "The given language subtag is invalid",
             ParserError::InvalidSubtag => "Invalid subtag",
             ParserError::InvalidExtension => "Invalid extension",
+            ParserError::InvalidKeywordValue { key, value } => {
+                return write!(f, "Invalid value \"{}\" for keyword \"{}\"", value, key);
+            }
+            ParserError::InvalidPrivateSubtag { subtag, reason } => {
+                return write!(f, "Invalid private use subtag \"{}\": {}", subtag, reason);
+            }
+            ParserError::InvalidExtensionSubtag {
+                extension,
+                subtag,
+                reason,
+            } => {
+                return write!(
+                    f,
+                    "Invalid subtag \"{}\" in extension \"{}\": {}",
+                    subtag, extension, reason
+                );
+            }
+            ParserError::EmptyExtension { extension } => {
+                return write!(f, "Extension \"{}\" has no subtags", extension);
+            }
+            ParserError::KeyWithoutValue { key } => {
+                return write!(f, "Key \"{}\" has no value", key);
+            }
+            ParserError::CapacityExceeded { capacity } => {
+                return write!(f, "The input has more than {} subtags", capacity);
+            }
             ParserError::LangIdError(_) => "Language Identifier Parser Error",
         };
         f.write_str(value)
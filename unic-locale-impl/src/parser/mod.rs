@@ -1,16 +1,247 @@
 pub mod errors;
+mod spanned;
+mod units;
+mod visitor;
 
-pub use self::errors::ParserError;
+pub use self::errors::{InvalidSubtagReason, ParserError};
+pub use self::spanned::{parse_locale_spanned, Component, SpannedParserError};
+pub use self::units::{parse_extension_units, ExtensionUnit};
+use self::visitor::parse_language;
+pub(crate) use self::visitor::visit_extensions;
+pub use self::visitor::{parse_with_visitor, LocaleVisitor};
 use super::extensions::ExtensionsMap;
-use super::Locale;
+#[cfg(feature = "extensions")]
+use super::extensions::PrivateSubtagOrder;
+use super::{Locale, Subtag};
+use std::hash::{Hash, Hasher};
+use std::ops::ControlFlow;
 use unic_langid_impl::LanguageIdentifier;
 
+/// The result of parsing an empty input.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EmptyInputPolicy {
+    /// Fail with `ParserError::EmptyInput`.
+    #[default]
+    Error,
+    /// Produce the `und` locale.
+    Und,
+    /// Produce the process-wide default locale set with `set_default_locale`,
+    /// or `und` if there is none.
+    DefaultLocale,
+}
+
+/// Options controlling the behavior of [`parse_locale_with_options`].
+///
+/// New options may be added in minor releases, so the options are built from
+/// `ParserOptions::default()`, such as `ParserOptions::default().lenient(true)`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ParserOptions {
+    /// Validate unicode extension keyword values against the values
+    /// allowed for each key (e.g. `hc` must be one of `h11`, `h12`, `h23`, `h24`).
+    #[cfg(feature = "extensions")]
+    pub strict: bool,
+    /// The ordering policy applied to private use subtags.
+    #[cfg(feature = "extensions")]
+    pub private_order: PrivateSubtagOrder,
+    /// Accept the non-canonical codes accepted by `unic_langid_impl::ParserOptions::lenient`,
+    /// and with the `long_subtags` feature, extension values and private use subtags of
+    /// up to 16 characters, instead of rejecting those longer than 8.
+    ///
+    /// Variant subtags and unicode extension attributes keep their BCP47 lengths.
+    ///
+    /// The mode is recorded in the parsed extensions, see `ExtensionsMap::is_lenient`,
+    /// so that their setters accept the same lengths.
+    #[cfg(feature = "extensions")]
+    pub lenient: bool,
+    /// Accept the 4-letter primary language subtags reserved by BCP47
+    /// for future use, instead of rejecting them.
+    pub allow_reserved_language: bool,
+    /// What an empty input is parsed as.
+    pub empty_input: EmptyInputPolicy,
+}
+
+impl ParserOptions {
+    /// Sets `strict`.
+    #[cfg(feature = "extensions")]
+    pub const fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Sets `private_order`.
+    #[cfg(feature = "extensions")]
+    pub const fn private_order(mut self, private_order: PrivateSubtagOrder) -> Self {
+        self.private_order = private_order;
+        self
+    }
+
+    /// Sets `lenient`.
+    #[cfg(feature = "extensions")]
+    pub const fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Sets `allow_reserved_language`.
+    pub const fn allow_reserved_language(mut self, allow_reserved_language: bool) -> Self {
+        self.allow_reserved_language = allow_reserved_language;
+        self
+    }
+
+    /// Sets `empty_input`.
+    pub const fn empty_input(mut self, empty_input: EmptyInputPolicy) -> Self {
+        self.empty_input = empty_input;
+        self
+    }
+}
+
+/// Parses the language identifier of a locale, validating the extensions as
+/// `parse_locale` does, but without storing them.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::parser::parse_langid_only;
+///
+/// let id = parse_langid_only("sr-latn-rs-u-nu-latn-x-foo")
+///     .expect("Parsing failed.");
+/// assert_eq!(id.to_string(), "sr-Latn-RS");
+///
+/// assert!(parse_langid_only("sr-Latn-u").is_err());
+/// ```
+pub fn parse_langid_only<S: AsRef<[u8]>>(t: S) -> Result<LanguageIdentifier, ParserError> {
+    let input = std::str::from_utf8(t.as_ref()).map_err(|_| ParserError::InvalidLanguage)?;
+
+    let mut id = LanguageIdentifier::default();
+    let mut variants = vec![];
+    parse_with_visitor(input, &mut |subtag| {
+        match subtag {
+            Subtag::Language(language) => id.language = language,
+            Subtag::Script(script) => id.script = Some(script),
+            Subtag::Region(region) => id.region = Some(region),
+            Subtag::Variant(variant) => variants.push(variant),
+            _ => {}
+        }
+        ControlFlow::Continue(())
+    })?;
+    id.set_variants(&variants);
+    Ok(id)
+}
+
 pub fn parse_locale<S: AsRef<[u8]>>(t: S) -> Result<Locale, ParserError> {
+    parse_locale_with_options(t, ParserOptions::default())
+}
+
+pub fn parse_locale_with_options<S: AsRef<[u8]>>(
+    t: S,
+    options: ParserOptions,
+) -> Result<Locale, ParserError> {
+    if t.as_ref().is_empty() {
+        return match options.empty_input {
+            EmptyInputPolicy::Error => Err(ParserError::EmptyInput),
+            EmptyInputPolicy::Und => Ok(Locale::default()),
+            EmptyInputPolicy::DefaultLocale => {
+                Ok(crate::default_locale().cloned().unwrap_or_default())
+            }
+        };
+    }
+
     let mut iter = t.as_ref().split(|c| *c == b'-' || *c == b'_').peekable();
 
-    let id = LanguageIdentifier::try_from_iter(&mut iter, true)
-        .map_err(|_| ParserError::InvalidLanguage)?;
+    #[cfg_attr(not(feature = "extensions"), allow(unused_mut))]
+    let mut id_options = unic_langid_impl::ParserOptions::default()
+        .allow_reserved_language(options.allow_reserved_language);
+    #[cfg(feature = "extensions")]
+    {
+        id_options.lenient = options.lenient;
+    }
+    let id = parse_language(&mut iter, id_options)?;
+    #[cfg(feature = "extensions")]
+    let private_use_tag = id.is_none();
+    let id = id.unwrap_or_default();
+
+    #[cfg(feature = "extensions")]
+    let extensions = {
+        let mut extensions = ExtensionsMap::try_from_iter(&mut iter, options.lenient)?;
+        extensions.private.set_order(options.private_order);
+        extensions.private.private_use_tag = private_use_tag;
+
+        if options.strict {
+            extensions.unicode.validate()?;
+        }
+        extensions
+    };
+    #[cfg(not(feature = "extensions"))]
+    let extensions = {
+        let _ = options;
+        ExtensionsMap::try_from_iter(&mut iter, false)?
+    };
 
-    let extensions = ExtensionsMap::try_from_iter(&mut iter)?;
     Ok(Locale { id, extensions })
 }
+
+/// A `Locale` along with the input it was parsed from, as produced by
+/// [`parse_locale_with_original`].
+///
+/// Equality and hashing only use the normalized `Locale`, so `EN_us` and `en-US`
+/// give equal `ParsedLocale`s.
+#[derive(Debug, Clone)]
+pub struct ParsedLocale {
+    /// The parsed, normalized `Locale`.
+    pub locale: Locale,
+    original: String,
+}
+
+impl ParsedLocale {
+    /// Returns the input, as given to the parser.
+    pub fn original(&self) -> &str {
+        &self.original
+    }
+}
+
+impl PartialEq for ParsedLocale {
+    fn eq(&self, other: &Self) -> bool {
+        self.locale == other.locale
+    }
+}
+
+impl Eq for ParsedLocale {}
+
+impl Hash for ParsedLocale {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.locale.hash(state);
+    }
+}
+
+impl From<ParsedLocale> for Locale {
+    fn from(parsed: ParsedLocale) -> Self {
+        parsed.locale
+    }
+}
+
+/// Parses a `Locale`, keeping the input alongside the normalized form.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::Locale;
+/// use unic_locale_impl::parser::{parse_locale_with_original, ParserOptions};
+///
+/// let parsed = parse_locale_with_original("EN_us", ParserOptions::default())
+///     .expect("Parsing failed.");
+///
+/// assert_eq!(parsed.locale.to_string(), "en-US");
+/// assert_eq!(parsed.original(), "EN_us");
+/// assert_eq!(parsed.locale, "en-US".parse::<Locale>().unwrap());
+/// ```
+pub fn parse_locale_with_original<S: AsRef<str>>(
+    t: S,
+    options: ParserOptions,
+) -> Result<ParsedLocale, ParserError> {
+    let original = t.as_ref();
+    Ok(ParsedLocale {
+        locale: parse_locale_with_options(original, options)?,
+        original: original.to_string(),
+    })
+}
@@ -0,0 +1,188 @@
+use super::{parse_locale, parse_with_visitor, ParserError};
+use crate::extensions::ExtensionType;
+use crate::{Locale, Subtag};
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::ops::{ControlFlow, Range};
+
+/// The component of a locale a subtag was parsed as.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Component {
+    Language,
+    Script,
+    Region,
+    Variant,
+    /// The singleton starting an extension, such as `u`.
+    ExtensionSingleton,
+    /// A unicode extension key or a transform extension tkey.
+    ExtensionKey,
+    /// A unicode extension attribute or value, or a subtag of a transform
+    /// extension tlang or tvalue, or of another extension.
+    ExtensionValue,
+    /// A private use subtag.
+    PrivateUse,
+}
+
+impl Display for Component {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let value = match self {
+            Component::Language => "language",
+            Component::Script => "script",
+            Component::Region => "region",
+            Component::Variant => "variant",
+            Component::ExtensionSingleton => "extension singleton",
+            Component::ExtensionKey => "extension key",
+            Component::ExtensionValue => "extension value",
+            Component::PrivateUse => "private use",
+        };
+        f.write_str(value)
+    }
+}
+
+/// A `ParserError` with the location of the offending subtags in the input,
+/// as returned by [`parse_locale_spanned`].
+#[derive(Debug, PartialEq)]
+pub struct SpannedParserError {
+    pub error: ParserError,
+    /// The byte range of the offending subtags in the input, which is empty
+    /// if the input is.
+    pub span: Range<usize>,
+    /// The component the offending subtags were parsed as.
+    pub component: Component,
+    /// The offending subtags, as written in the input.
+    pub found: String,
+}
+
+impl From<SpannedParserError> for ParserError {
+    fn from(error: SpannedParserError) -> Self {
+        error.error
+    }
+}
+
+impl Error for SpannedParserError {}
+
+impl Display for SpannedParserError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} at bytes {}..{}, in {} subtag \"{}\"",
+            self.error, self.span.start, self.span.end, self.component, self.found
+        )
+    }
+}
+
+/// Parses the input like [`parse_locale`], returning on failure the location of the
+/// offending subtags, the component they were parsed as, and their text, such as
+/// for rendering a caret diagnostic.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::parser::{parse_locale_spanned, Component};
+///
+/// let input = "en-US-u-ca-gregorian1";
+/// let error = parse_locale_spanned(input).unwrap_err();
+///
+/// assert_eq!(error.span, 11..21);
+/// assert_eq!(error.component, Component::ExtensionValue);
+/// assert_eq!(error.found, "gregorian1");
+///
+/// let caret = format!("{}{}", " ".repeat(error.span.start), "^".repeat(error.span.len()));
+/// assert_eq!(caret, "           ^^^^^^^^^^");
+/// ```
+///
+/// [`parse_locale`]: fn.parse_locale.html
+pub fn parse_locale_spanned(input: &str) -> Result<Locale, SpannedParserError> {
+    let error = match parse_locale(input) {
+        Ok(locale) => return Ok(locale),
+        Err(error) => error,
+    };
+
+    let mut spans = vec![];
+    let mut start = 0;
+    for subtag in input.split(['-', '_']) {
+        spans.push(start..start + subtag.len());
+        start += subtag.len() + 1;
+    }
+
+    // The subtags before the offending one are visited in the order they appear
+    // in the input, one per subtag.
+    let mut visited: Vec<Subtag> = vec![];
+    // The visitor follows the grammar of `parse_locale`, so it fails on the same
+    // subtag.
+    let _ = parse_with_visitor(input, &mut |subtag| {
+        visited.push(subtag);
+        ControlFlow::Continue(())
+    });
+    let last = visited.last().copied();
+    let in_extension = |extension: ExtensionType| {
+        visited
+            .iter()
+            .rev()
+            .find_map(|subtag| match subtag {
+                Subtag::ExtensionSingleton(e) => Some(*e),
+                _ => None,
+            })
+            .is_some_and(|e| e == extension)
+    };
+
+    let (mut first, mut end) = match &error {
+        ParserError::EmptyInput | ParserError::InvalidLanguage | ParserError::LangIdError(_)
+            if visited.is_empty() =>
+        {
+            (0, 0)
+        }
+        ParserError::KeyWithoutValue { .. } => {
+            let key = visited
+                .iter()
+                .rposition(|subtag| matches!(subtag, Subtag::ExtensionKey(_)))
+                .unwrap_or(0);
+            (key, key)
+        }
+        _ => (visited.len(), visited.len()),
+    };
+    // An invalid transform extension tlang spans all of its subtags.
+    if last == Some(Subtag::ExtensionSingleton(ExtensionType::Transform)) {
+        let subtags: Vec<&str> = input.split(['-', '_']).collect();
+        while end + 1 < subtags.len() && subtags[end + 1].len() > 1 {
+            let next = subtags[end + 1].as_bytes();
+            if next.len() == 2 && next[0].is_ascii_alphabetic() && next[1].is_ascii_digit() {
+                break;
+            }
+            end += 1;
+        }
+    }
+    first = first.min(spans.len() - 1);
+    end = end.min(spans.len() - 1);
+    let span = spans[first].start..spans[end].end;
+    let found = input[span.clone()].to_string();
+
+    let component = match (&error, last) {
+        (ParserError::InvalidPrivateSubtag { .. }, _) => Component::PrivateUse,
+        (ParserError::KeyWithoutValue { .. }, _) => Component::ExtensionKey,
+        (ParserError::EmptyExtension { .. }, _) => Component::ExtensionSingleton,
+        (_, None) => Component::Language,
+        _ if found.len() == 1 => Component::ExtensionSingleton,
+        (_, Some(Subtag::Language(_))) => match found.len() {
+            4 => Component::Script,
+            2 | 3 => Component::Region,
+            _ => Component::Variant,
+        },
+        (_, Some(Subtag::Script(_))) => match found.len() {
+            2 | 3 => Component::Region,
+            _ => Component::Variant,
+        },
+        (_, Some(Subtag::Region(_))) | (_, Some(Subtag::Variant(_))) => Component::Variant,
+        (_, Some(Subtag::Private(_))) => Component::PrivateUse,
+        _ if in_extension(ExtensionType::Private) => Component::PrivateUse,
+        _ if found.len() == 2 && !in_extension(ExtensionType::Transform) => Component::ExtensionKey,
+        _ => Component::ExtensionValue,
+    };
+
+    Err(SpannedParserError {
+        error,
+        span,
+        component,
+        found,
+    })
+}
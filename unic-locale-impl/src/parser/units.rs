@@ -0,0 +1,119 @@
+use super::{parse_locale_with_options, ParserError, ParserOptions};
+use crate::extensions::ExtensionType;
+use std::ops::Range;
+
+/// A single unit of an extension, as it appeared in the parsed input.
+///
+/// A unit is a unicode extension attribute or keyword, a transform extension
+/// tlang or tfield, a private use subtag, or the whole of any other extension.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct ExtensionUnit<'a> {
+    /// The extension the unit belongs to.
+    pub extension: ExtensionType,
+    /// The key of a unicode extension keyword or a transform extension tfield.
+    pub key: Option<&'a str>,
+    /// The value of the unit as written in the input, possibly empty.
+    pub value: &'a str,
+    /// The byte range of the whole unit, including its key, in the input.
+    pub span: Range<usize>,
+}
+
+fn is_tkey(t: &str) -> bool {
+    let t = t.as_bytes();
+    t.len() == 2 && t[0].is_ascii_alphabetic() && t[1].is_ascii_digit()
+}
+
+/// Parses the input with the given options and returns its extension units
+/// in the order they appeared, as opposed to the canonical order used by `Locale`.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::parser::{parse_extension_units, ParserOptions};
+///
+/// let units: Vec<_> = parse_extension_units("en-u-nu-thai-ca-buddhist", ParserOptions::default())
+///     .expect("Parsing failed.")
+///     .map(|unit| (unit.key, unit.value, unit.span))
+///     .collect();
+///
+/// assert_eq!(units, &[
+///     (Some("nu"), "thai", 5..12),
+///     (Some("ca"), "buddhist", 13..24),
+/// ]);
+/// ```
+pub fn parse_extension_units(
+    t: &str,
+    options: ParserOptions,
+) -> Result<impl Iterator<Item = ExtensionUnit<'_>>, ParserError> {
+    parse_locale_with_options(t, options)?;
+
+    let mut offset = 0;
+    let subtags: Vec<(usize, &str)> = t
+        .split(['-', '_'])
+        .map(|subtag| {
+            let start = offset;
+            offset += subtag.len() + 1;
+            (start, subtag)
+        })
+        .collect();
+
+    // No subtag of a language identifier is a single character, so the
+    // first one starts the extensions.
+    let first = subtags
+        .iter()
+        .position(|(_, subtag)| subtag.len() == 1)
+        .unwrap_or(subtags.len());
+
+    let mut units: Vec<ExtensionUnit> = vec![];
+    let mut extension = None;
+    let mut open = false;
+
+    for &(start, subtag) in &subtags[first..] {
+        let end = start + subtag.len();
+
+        if subtag.len() == 1 && extension != Some(ExtensionType::Private) {
+            extension = Some(ExtensionType::from_byte(subtag.as_bytes()[0])?);
+            open = false;
+            continue;
+        }
+        let extension = extension.expect("Extensions start with a singleton.");
+
+        let starts_unit = match extension {
+            ExtensionType::Unicode => subtag.len() == 2 || !open,
+            ExtensionType::Transform => is_tkey(subtag) || !open,
+            ExtensionType::Private => true,
+            ExtensionType::Other(_) => !open,
+        };
+        let is_key = match extension {
+            ExtensionType::Unicode => subtag.len() == 2,
+            ExtensionType::Transform => is_tkey(subtag),
+            _ => false,
+        };
+
+        if starts_unit {
+            units.push(ExtensionUnit {
+                extension,
+                key: if is_key { Some(subtag) } else { None },
+                value: if is_key { &t[end..end] } else { subtag },
+                span: start..end,
+            });
+            // Unicode extension attributes and private use subtags are units of their own.
+            open = is_key
+                || matches!(
+                    extension,
+                    ExtensionType::Transform | ExtensionType::Other(_)
+                );
+        } else {
+            let unit = units.last_mut().expect("A unit is open.");
+            let value_start = if unit.value.is_empty() {
+                start
+            } else {
+                unit.span.end - unit.value.len()
+            };
+            unit.value = &t[value_start..end];
+            unit.span.end = end;
+        }
+    }
+
+    Ok(units.into_iter())
+}
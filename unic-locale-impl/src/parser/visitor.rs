@@ -0,0 +1,294 @@
+use super::ParserError;
+#[cfg(feature = "extensions")]
+use crate::extensions::{
+    check_private_subtag, is_attribute, is_language_subtag, is_type, type_length, ExtensionType,
+    ParseMode, ATTR_LENGTH,
+};
+use crate::{LanguageIdentifier, Subtag};
+use std::iter::Peekable;
+use std::ops::ControlFlow;
+use unic_langid_impl::ParserOptions as LangIdParserOptions;
+
+/// Receives the subtags of a locale as they are recognized by [`parse_with_visitor`].
+///
+/// Returning `ControlFlow::Break` stops the parsing early, in which case the rest
+/// of the input is not validated.
+///
+/// The trait is implemented for closures taking a `Subtag`.
+pub trait LocaleVisitor<'a> {
+    fn visit(&mut self, subtag: Subtag<'a>) -> ControlFlow<()>;
+}
+
+impl<'a, F: FnMut(Subtag<'a>) -> ControlFlow<()>> LocaleVisitor<'a> for F {
+    fn visit(&mut self, subtag: Subtag<'a>) -> ControlFlow<()> {
+        self(subtag)
+    }
+}
+
+macro_rules! visit {
+    ($visitor:expr, $subtag:expr) => {
+        if $visitor.visit($subtag).is_break() {
+            return Ok(ControlFlow::Break(()));
+        }
+    };
+}
+
+/// Returns a subtag the grammar has validated, and so is ASCII, as a `&str`.
+#[cfg(feature = "extensions")]
+fn as_str(subtag: &[u8]) -> &str {
+    std::str::from_utf8(subtag).unwrap_or_default()
+}
+
+#[cfg(feature = "extensions")]
+fn is_tkey(t: &[u8]) -> bool {
+    t.len() == 2 && t[0].is_ascii_alphabetic() && t[1].is_ascii_digit()
+}
+
+#[cfg(feature = "extensions")]
+fn is_key(t: &[u8]) -> bool {
+    t.len() == 2 && t[0].is_ascii_alphanumeric() && t[1].is_ascii_alphabetic()
+}
+
+/// Parses the input, passing each subtag to the visitor in the order it appears
+/// in the input, without constructing a `Locale`.
+///
+/// The language identifier is parsed first, and its subtags are passed normalized
+/// as in `LanguageIdentifier`, with the variants sorted and deduplicated. Extension
+/// subtags are then passed as written in the input, as they are recognized. The input
+/// is validated as with the default `ParserOptions`, so an empty input is an error.
+///
+/// `parse_locale` builds its extensions from the same subtags, so both accept and
+/// reject the same inputs, with the same errors.
+///
+/// # Examples
+///
+/// ```
+/// use std::ops::ControlFlow;
+/// use unic_locale_impl::Subtag;
+/// use unic_locale_impl::parser::parse_with_visitor;
+///
+/// let mut calendar = None;
+/// let mut current_key = None;
+/// parse_with_visitor("en-US-u-ca-buddhist-nu-thai", &mut |subtag| {
+///     match subtag {
+///         Subtag::ExtensionKey(key) => current_key = Some(key),
+///         Subtag::ExtensionValue(value) if current_key == Some("ca") => {
+///             calendar = Some(value);
+///             return ControlFlow::Break(());
+///         }
+///         _ => {}
+///     }
+///     ControlFlow::Continue(())
+/// }).expect("Parsing failed.");
+///
+/// assert_eq!(calendar, Some("buddhist"));
+/// ```
+pub fn parse_with_visitor<'a>(
+    input: &'a str,
+    visitor: &mut impl LocaleVisitor<'a>,
+) -> Result<(), ParserError> {
+    visit_locale(input.as_bytes(), visitor).map(|_| ())
+}
+
+fn visit_locale<'a>(
+    input: &'a [u8],
+    visitor: &mut impl LocaleVisitor<'a>,
+) -> Result<ControlFlow<()>, ParserError> {
+    if input.is_empty() {
+        return Err(ParserError::EmptyInput);
+    }
+    let mut iter = input.split(|c| *c == b'-' || *c == b'_').peekable();
+
+    if let Some(id) = parse_language(&mut iter, LangIdParserOptions::default())? {
+        visit!(visitor, Subtag::Language(id.language));
+        if let Some(script) = id.script {
+            visit!(visitor, Subtag::Script(script));
+        }
+        if let Some(region) = id.region {
+            visit!(visitor, Subtag::Region(region));
+        }
+        for variant in id.variants() {
+            visit!(visitor, Subtag::Variant(*variant));
+        }
+    }
+
+    visit_extensions(&mut iter, false, visitor)
+}
+
+/// Parses the language identifier at the start of a locale, leaving the extensions
+/// in the iterator.
+///
+/// Returns `None` for a private use tag, such as `x-custom`, which has no language
+/// identifier.
+pub(crate) fn parse_language<'a>(
+    iter: &mut Peekable<impl Iterator<Item = &'a [u8]>>,
+    options: LangIdParserOptions,
+) -> Result<Option<LanguageIdentifier>, ParserError> {
+    if cfg!(feature = "extensions") && iter.peek().is_some_and(|s| s.eq_ignore_ascii_case(b"x")) {
+        return Ok(None);
+    }
+    LanguageIdentifier::try_from_iter_with_options(iter, true, options)
+        .map(Some)
+        .map_err(|_| ParserError::InvalidLanguage)
+}
+
+/// Passes the subtags of the extensions following a language identifier to the
+/// visitor, validating them as `ExtensionsMap` does.
+///
+/// With `lenient`, extension values and private use subtags may be as long as
+/// `ParseMode::max_value_length` allows.
+#[cfg(feature = "extensions")]
+pub(crate) fn visit_extensions<'a, I>(
+    iter: &mut Peekable<I>,
+    lenient: bool,
+    visitor: &mut impl LocaleVisitor<'a>,
+) -> Result<ControlFlow<()>, ParserError>
+where
+    I: Iterator<Item = &'a [u8]> + Clone,
+{
+    let mode = ParseMode { lenient };
+    while let Some(singleton) = iter.next() {
+        if singleton.len() != 1 {
+            return Err(ParserError::InvalidExtension);
+        }
+        let extension = ExtensionType::from_byte(singleton[0])?;
+        let is_empty = match iter.peek() {
+            None => true,
+            Some(next) => next.len() == 1 && extension != ExtensionType::Private,
+        };
+        if is_empty {
+            return Err(ParserError::EmptyExtension { extension });
+        }
+        visit!(visitor, Subtag::ExtensionSingleton(extension));
+
+        let flow = match extension {
+            ExtensionType::Unicode => visit_unicode(iter, mode, visitor)?,
+            ExtensionType::Transform => visit_transform(iter, mode, visitor)?,
+            ExtensionType::Private => {
+                for subtag in &mut *iter {
+                    check_private_subtag(subtag, mode)?;
+                    visit!(visitor, Subtag::Private(as_str(subtag)));
+                }
+                ControlFlow::Continue(())
+            }
+            ExtensionType::Other(_) => {
+                while let Some(subtag) = iter.next_if(|s| s.len() != 1) {
+                    ParserError::check_extension_subtag(extension, subtag, 2..=8)?;
+                    visit!(visitor, Subtag::ExtensionValue(as_str(subtag)));
+                }
+                ControlFlow::Continue(())
+            }
+        };
+        if flow.is_break() {
+            return Ok(flow);
+        }
+    }
+
+    Ok(ControlFlow::Continue(()))
+}
+
+/// Without the `extensions` feature, any extension is an error.
+#[cfg(not(feature = "extensions"))]
+pub(crate) fn visit_extensions<'a, I>(
+    iter: &mut Peekable<I>,
+    _lenient: bool,
+    _visitor: &mut impl LocaleVisitor<'a>,
+) -> Result<ControlFlow<()>, ParserError>
+where
+    I: Iterator<Item = &'a [u8]> + Clone,
+{
+    match iter.next() {
+        Some(_) => Err(ParserError::InvalidExtension),
+        None => Ok(ControlFlow::Continue(())),
+    }
+}
+
+#[cfg(feature = "extensions")]
+fn visit_unicode<'a>(
+    iter: &mut Peekable<impl Iterator<Item = &'a [u8]>>,
+    mode: ParseMode,
+    visitor: &mut impl LocaleVisitor<'a>,
+) -> Result<ControlFlow<()>, ParserError> {
+    // Attributes precede all keywords.
+    let mut has_key = false;
+    while let Some(&subtag) = iter.peek() {
+        let next = if subtag.len() == 2 {
+            if !is_key(subtag) {
+                return Err(ParserError::InvalidSubtag);
+            }
+            has_key = true;
+            Subtag::ExtensionKey(as_str(subtag))
+        } else if has_key && is_type(subtag, mode) || !has_key && is_attribute(subtag) {
+            Subtag::ExtensionValue(as_str(subtag))
+        } else if subtag.len() == 1 {
+            break;
+        } else {
+            let length = if has_key {
+                type_length(mode)
+            } else {
+                ATTR_LENGTH
+            };
+            ParserError::check_extension_subtag(ExtensionType::Unicode, subtag, length)?;
+            return Err(ParserError::InvalidExtension);
+        };
+        visit!(visitor, next);
+        iter.next();
+    }
+    Ok(ControlFlow::Continue(()))
+}
+
+#[cfg(feature = "extensions")]
+fn visit_transform<'a, I>(
+    iter: &mut Peekable<I>,
+    mode: ParseMode,
+    visitor: &mut impl LocaleVisitor<'a>,
+) -> Result<ControlFlow<()>, ParserError>
+where
+    I: Iterator<Item = &'a [u8]> + Clone,
+{
+    let mut current_tkey: Option<&[u8]> = None;
+    // Whether the current tkey is followed by a tvalue, which may be a removed `true`.
+    let mut has_tvalue = false;
+    let mut has_tlang = false;
+    while let Some(&subtag) = iter.peek() {
+        if is_tkey(subtag) {
+            if let (Some(key), false) = (current_tkey, has_tvalue) {
+                return Err(ParserError::KeyWithoutValue {
+                    key: as_str(key).to_ascii_lowercase(),
+                });
+            }
+            current_tkey = Some(subtag);
+            has_tvalue = false;
+            visit!(visitor, Subtag::ExtensionKey(as_str(subtag)));
+        } else if current_tkey.is_some() && subtag.len() != 1 {
+            ParserError::check_extension_subtag(
+                ExtensionType::Transform,
+                subtag,
+                3..=mode.max_value_length(),
+            )?;
+            has_tvalue = true;
+            visit!(visitor, Subtag::ExtensionValue(as_str(subtag)));
+        } else if current_tkey.is_none() && !has_tlang && is_language_subtag(subtag) {
+            // The tlang, if any, precedes all tfields. Its subtags are those the
+            // language identifier parser consumes.
+            let start = iter.clone();
+            LanguageIdentifier::try_from_iter(iter, true)
+                .map_err(|_| ParserError::InvalidLanguage)?;
+            let consumed = start.clone().count() - iter.clone().count();
+            for subtag in start.take(consumed) {
+                visit!(visitor, Subtag::ExtensionValue(as_str(subtag)));
+            }
+            has_tlang = true;
+            continue;
+        } else {
+            break;
+        }
+        iter.next();
+    }
+    if let (Some(key), false) = (current_tkey, has_tvalue) {
+        return Err(ParserError::KeyWithoutValue {
+            key: as_str(key).to_ascii_lowercase(),
+        });
+    }
+    Ok(ControlFlow::Continue(()))
+}
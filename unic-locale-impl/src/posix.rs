@@ -0,0 +1,152 @@
+//! Conversion between `Locale` and POSIX (glibc style) locale names,
+//! such as `sr_RS.UTF-8@latin`.
+use crate::errors::LocaleError;
+use crate::Locale;
+
+// Script subtags expressed as a glibc `@modifier`, by language.
+//
+// Only non-default scripts of a language get a modifier, so `sr-Latn-RS`
+// maps to `sr_RS@latin`, while `sr-Cyrl-RS` maps to `sr_RS`.
+static SCRIPT_MODIFIERS: &[(&str, &str, &str)] = &[
+    ("be", "Latn", "latin"),
+    ("ks", "Deva", "devanagari"),
+    ("sd", "Deva", "devanagari"),
+    ("sr", "Latn", "latin"),
+    ("uz", "Cyrl", "cyrillic"),
+];
+
+// Variant subtags expressed as a glibc `@modifier`, by language.
+static VARIANT_MODIFIERS: &[(&str, &str, &str)] =
+    &[("aa", "saaho", "saaho"), ("ca", "valencia", "valencia")];
+
+/// Returns the glibc `@modifier` standing for the script or variant subtag of the
+/// locale, or `euro` for the `cu-eur` keyword.
+fn modifier(locale: &Locale) -> Option<&'static str> {
+    let language = locale.id.language.as_str();
+
+    if let Some(script) = locale.id.script {
+        let script = script.as_str();
+        if let Some((_, _, modifier)) = SCRIPT_MODIFIERS
+            .iter()
+            .find(|(l, s, _)| *l == language && *s == script)
+        {
+            return Some(modifier);
+        }
+    }
+
+    let variant = locale.id.variants().find_map(|variant| {
+        let variant = variant.as_str();
+        VARIANT_MODIFIERS
+            .iter()
+            .find(|(l, v, _)| *l == language && *v == variant)
+            .map(|(_, _, modifier)| *modifier)
+    });
+    if variant.is_some() {
+        return variant;
+    }
+
+    // The `cu-eur` keyword, as read from `@euro` by `apply_modifier`.
+    #[cfg(feature = "extensions")]
+    if locale
+        .extensions
+        .unicode
+        .keyword("cu")
+        .expect("Key is well-formed.")
+        .eq(["eur"])
+    {
+        return Some("euro");
+    }
+    None
+}
+
+pub(crate) fn to_unix_locale(locale: &Locale, charset: Option<&str>) -> String {
+    let mut result = String::from(locale.id.language.as_str());
+
+    if let Some(region) = locale.id.region {
+        result.push('_');
+        result.push_str(region.as_str());
+    }
+
+    if let Some(charset) = charset {
+        result.push('.');
+        result.push_str(charset);
+    }
+
+    if let Some(modifier) = modifier(locale) {
+        result.push('@');
+        result.push_str(modifier);
+    }
+
+    result
+}
+
+/// Options controlling how POSIX locale names are parsed by
+/// `Locale::from_unix_locale_with_options` and `Locale::current_with_options`.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct PosixOptions {
+    /// The locale the special `C` and `POSIX` names map to, `und` by default.
+    /// `en-US-u-va-posix` is the closest equivalent of their behavior.
+    pub c_locale: Locale,
+}
+
+// Environment variables consulted for the ambient locale, by precedence.
+static LOCALE_VARS: &[&str] = &["LC_ALL", "LC_MESSAGES", "LANG"];
+
+/// Applies a glibc `@modifier` to the locale, as the script or variant subtag
+/// it stands for, or the `cu-eur` keyword for `euro`. Other modifiers are dropped.
+fn apply_modifier(locale: &mut Locale, modifier: &str) {
+    let language = locale.id.language.as_str();
+
+    if let Some((_, script, _)) = SCRIPT_MODIFIERS
+        .iter()
+        .find(|(l, _, m)| *l == language && *m == modifier)
+    {
+        if locale.id.script.is_none() {
+            locale.id.script = Some(script.parse().expect("Scripts are well-formed."));
+        }
+    } else if let Some((_, variant, _)) = VARIANT_MODIFIERS
+        .iter()
+        .find(|(l, _, m)| *l == language && *m == modifier)
+    {
+        let variant = variant.parse().expect("Variants are well-formed.");
+        if !locale.id.has_variant(variant) {
+            let mut variants = locale.id.variants_slice().to_vec();
+            variants.push(variant);
+            locale.id.set_variants(&variants);
+        }
+    } else if modifier == "euro" {
+        #[cfg(feature = "extensions")]
+        locale
+            .extensions
+            .unicode
+            .set_keyword("cu", &["eur"])
+            .expect("Keyword is well-formed.");
+    }
+}
+
+/// Parses a POSIX locale name, mapping the modifier with `apply_modifier`
+/// and ignoring the charset.
+pub(crate) fn from_unix_locale(name: &str, options: &PosixOptions) -> Result<Locale, LocaleError> {
+    let (name, modifier) = match name.split_once('@') {
+        Some((name, modifier)) => (name, Some(modifier)),
+        None => (name, None),
+    };
+    let name = name.split('.').next().unwrap_or_default();
+    let mut locale: Locale = match name {
+        "C" | "POSIX" => return Ok(options.c_locale.clone()),
+        name => name.parse()?,
+    };
+    if let Some(modifier) = modifier {
+        apply_modifier(&mut locale, &modifier.to_ascii_lowercase());
+    }
+    Ok(locale)
+}
+
+/// Detects the ambient locale from the POSIX environment variables.
+pub(crate) fn from_env(options: &PosixOptions) -> Option<Locale> {
+    let name = LOCALE_VARS
+        .iter()
+        .filter_map(|var| std::env::var(var).ok())
+        .find(|value| !value.is_empty())?;
+    from_unix_locale(&name, options).ok()
+}
@@ -0,0 +1,279 @@
+//! Locale-derived preferences, resolved from unicode extension keywords
+//! and regional defaults.
+use crate::subtags::Region;
+use crate::Locale;
+
+// The CLDR version the curated tables below follow. They aren't generated, as the
+// `timeData`, `calendarPreferenceData` and `measurementData` aren't bundled.
+pub(crate) static CLDR_VERSION: &str = "47";
+
+// Regions preferring a 12 hour clock, from CLDR `timeData`.
+static H12_REGIONS: &[&str] = &[
+    "AE", "AS", "AU", "BD", "BH", "CA", "CO", "DO", "EG", "GU", "HK", "HN", "IN", "IQ", "JO", "KR",
+    "KW", "LB", "LY", "MO", "MP", "MY", "NI", "NZ", "OM", "PA", "PH", "PK", "PR", "QA", "SA", "SD",
+    "SV", "SY", "TW", "UM", "US", "VE", "VI", "YE",
+];
+
+// Regions not using the Gregorian calendar by default, from CLDR `calendarPreferenceData`.
+static CALENDARS: &[(&str, &str)] = &[("AF", "persian"), ("IR", "persian"), ("TH", "buddhist")];
+
+// Regions not using the metric system, from CLDR `measurementData`.
+static MEASUREMENT_SYSTEMS: &[(&str, &str)] = &[
+    ("GB", "uksystem"),
+    ("LR", "ussystem"),
+    ("MM", "ussystem"),
+    ("US", "ussystem"),
+];
+
+// Regions not starting the week on Monday, from CLDR 47 `weekData`.
+static FIRST_DAYS: &[(&str, &str)] = &[
+    ("AF", "sat"),
+    ("AG", "sun"),
+    ("AS", "sun"),
+    ("BD", "sun"),
+    ("BH", "sat"),
+    ("BR", "sun"),
+    ("BS", "sun"),
+    ("BT", "sun"),
+    ("BW", "sun"),
+    ("BZ", "sun"),
+    ("CA", "sun"),
+    ("CO", "sun"),
+    ("DJ", "sat"),
+    ("DM", "sun"),
+    ("DO", "sun"),
+    ("DZ", "sat"),
+    ("EG", "sat"),
+    ("ET", "sun"),
+    ("GT", "sun"),
+    ("GU", "sun"),
+    ("HK", "sun"),
+    ("HN", "sun"),
+    ("ID", "sun"),
+    ("IL", "sun"),
+    ("IN", "sun"),
+    ("IQ", "sat"),
+    ("IR", "sat"),
+    ("JM", "sun"),
+    ("JO", "sat"),
+    ("JP", "sun"),
+    ("KE", "sun"),
+    ("KH", "sun"),
+    ("KR", "sun"),
+    ("KW", "sat"),
+    ("LA", "sun"),
+    ("LY", "sat"),
+    ("MH", "sun"),
+    ("MM", "sun"),
+    ("MO", "sun"),
+    ("MT", "sun"),
+    ("MV", "fri"),
+    ("MX", "sun"),
+    ("MZ", "sun"),
+    ("NI", "sun"),
+    ("NP", "sun"),
+    ("OM", "sat"),
+    ("PA", "sun"),
+    ("PE", "sun"),
+    ("PH", "sun"),
+    ("PK", "sun"),
+    ("PR", "sun"),
+    ("PT", "sun"),
+    ("PY", "sun"),
+    ("QA", "sat"),
+    ("SA", "sun"),
+    ("SD", "sat"),
+    ("SG", "sun"),
+    ("SV", "sun"),
+    ("SY", "sat"),
+    ("TH", "sun"),
+    ("TT", "sun"),
+    ("TW", "sun"),
+    ("UM", "sun"),
+    ("US", "sun"),
+    ("VE", "sun"),
+    ("VI", "sun"),
+    ("WS", "sun"),
+    ("YE", "sun"),
+    ("ZA", "sun"),
+    ("ZW", "sun"),
+];
+
+// Languages not using Latin digits by default, with the regions where they do.
+static NUMBERING_SYSTEMS: &[(&str, &str, &[&str])] = &[
+    ("ar", "arab", &["DZ", "EH", "LY", "MA", "TN"]),
+    ("bn", "beng", &[]),
+    ("fa", "arabext", &[]),
+    ("mr", "deva", &[]),
+    ("my", "mymr", &[]),
+    ("ne", "deva", &[]),
+];
+
+// Regions where Chinese is written with traditional characters by default.
+static HANT_REGIONS: &[&str] = &["HK", "MO", "TW"];
+
+/// Preferences for formatting, resolved from a `Locale`.
+///
+/// Each preference comes from the matching unicode extension keyword if present,
+/// and from the defaults of the locale's region otherwise.
+///
+/// The values are the unicode extension keyword values, such as `h23` for the hour
+/// cycle or `mon` for the first day of the week.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Preferences {
+    /// The hour cycle (`hc`).
+    pub hour_cycle: String,
+    /// The calendar (`ca`).
+    pub calendar: String,
+    /// The numbering system (`nu`).
+    pub numbering_system: String,
+    /// The measurement system (`ms`).
+    pub measurement_system: String,
+    /// The first day of the week (`fw`).
+    pub first_weekday: String,
+    /// The region override (`rg`), which takes precedence over the locale's region
+    /// for the regional defaults.
+    pub region_override: Option<Region>,
+}
+
+#[cfg(feature = "extensions")]
+fn keyword(locale: &Locale, key: &str) -> Option<String> {
+    let value = locale
+        .extensions
+        .unicode
+        .keyword(key)
+        .expect("Key is well-formed.")
+        .collect::<Vec<_>>()
+        .join("-");
+    if value.is_empty() {
+        None
+    } else {
+        Some(value)
+    }
+}
+
+#[cfg(not(feature = "extensions"))]
+fn keyword(_locale: &Locale, _key: &str) -> Option<String> {
+    None
+}
+
+fn region(locale: &Locale, region_override: Option<Region>) -> Option<Region> {
+    if region_override.is_some() || locale.id.region.is_some() {
+        return region_override.or(locale.id.region);
+    }
+    #[cfg(feature = "likelysubtags")]
+    {
+        let mut id = locale.id.clone();
+        if id.maximize() {
+            return id.region;
+        }
+    }
+    None
+}
+
+fn lookup(table: &[(&str, &'static str)], region: Option<&str>) -> Option<&'static str> {
+    let region = region?;
+    table
+        .binary_search_by_key(&region, |(r, _)| r)
+        .ok()
+        .map(|idx| table[idx].1)
+}
+
+pub(crate) fn resolve(locale: &Locale) -> Preferences {
+    #[cfg(feature = "extensions")]
+    let region_override = locale.extensions.unicode.region_override();
+    #[cfg(not(feature = "extensions"))]
+    let region_override = None;
+
+    let region = region(locale, region_override);
+    let region = region.as_ref().map(Region::as_str);
+    let language = locale.id.language.as_str();
+
+    let hour_cycle = keyword(locale, "hc").unwrap_or_else(|| {
+        let h12 = region.is_some_and(|r| H12_REGIONS.binary_search(&r).is_ok());
+        String::from(if h12 { "h12" } else { "h23" })
+    });
+    let calendar = keyword(locale, "ca")
+        .unwrap_or_else(|| String::from(lookup(CALENDARS, region).unwrap_or("gregory")));
+    let numbering_system = keyword(locale, "nu").unwrap_or_else(|| {
+        let nu = NUMBERING_SYSTEMS
+            .iter()
+            .find(|(l, _, _)| *l == language)
+            .filter(|(_, _, latn)| !region.is_some_and(|r| latn.contains(&r)))
+            .map_or("latn", |(_, nu, _)| nu);
+        String::from(nu)
+    });
+    let measurement_system = keyword(locale, "ms")
+        .unwrap_or_else(|| String::from(lookup(MEASUREMENT_SYSTEMS, region).unwrap_or("metric")));
+    let first_weekday = keyword(locale, "fw")
+        .unwrap_or_else(|| String::from(lookup(FIRST_DAYS, region).unwrap_or("mon")));
+
+    Preferences {
+        hour_cycle,
+        calendar,
+        numbering_system,
+        measurement_system,
+        first_weekday,
+        region_override,
+    }
+}
+
+/// The default collation of the language, from CLDR 47 `defaultCollation`.
+///
+/// Only Chinese and Cantonese default to something other than `standard`:
+/// `stroke` for traditional characters and `pinyin` for simplified ones.
+/// Chinese is written in simplified characters unless the region uses
+/// traditional ones, Cantonese the other way round outside of China.
+///
+/// The collation data is not bundled, so these are curated by hand.
+fn default_collation(locale: &Locale) -> &'static str {
+    let hant = match (
+        locale.id.language.as_str(),
+        locale.id.script,
+        locale.id.region,
+    ) {
+        ("zh", Some(script), _) | ("yue", Some(script), _) => script.as_str() == "Hant",
+        ("zh", None, Some(region)) => HANT_REGIONS.contains(&region.as_str()),
+        ("zh", None, None) => false,
+        ("yue", None, Some(region)) => region.as_str() != "CN",
+        ("yue", None, None) => true,
+        _ => return "standard",
+    };
+    if hant {
+        "stroke"
+    } else {
+        "pinyin"
+    }
+}
+
+pub(crate) fn resolve_collation(locale: &Locale) -> String {
+    keyword(locale, "co").unwrap_or_else(|| String::from(default_collation(locale)))
+}
+
+#[cfg(feature = "currencydata")]
+pub(crate) fn resolve_currency(locale: &Locale) -> Option<String> {
+    if let Some(cu) = keyword(locale, "cu") {
+        return Some(cu.to_ascii_uppercase());
+    }
+    #[cfg(feature = "extensions")]
+    let region_override = locale.extensions.unicode.region_override();
+    #[cfg(not(feature = "extensions"))]
+    let region_override = None;
+
+    let region = region(locale, region_override)?;
+    crate::currency::currency_for_region(region).map(String::from)
+}
+
+#[cfg(feature = "timezones")]
+pub(crate) fn resolve_time_zone(locale: &Locale) -> Option<&'static str> {
+    if let Some(tz) = keyword(locale, "tz") {
+        return crate::timezone::zone_for_bcp47(&tz);
+    }
+    #[cfg(feature = "extensions")]
+    let region_override = locale.extensions.unicode.region_override();
+    #[cfg(not(feature = "extensions"))]
+    let region_override = None;
+
+    let region = region(locale, region_override)?;
+    crate::timezone::primary_zone_for_region(region)
+}
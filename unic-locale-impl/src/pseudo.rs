@@ -0,0 +1,50 @@
+//! Pseudo-locales used for localization testing, such as `en-XA` and `ar-XB`.
+use crate::{subtags, Locale};
+
+/// A kind of pseudo-locale, identified by one of the private use region
+/// codes reserved for it.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum PseudoLocale {
+    /// Accented and lengthened text, as in `en-XA`, revealing hardcoded strings
+    /// and truncation.
+    Accented,
+    /// Mirrored right-to-left text, as in `ar-XB` or `en-XB`, revealing
+    /// bidirectional layout issues.
+    Bidi,
+}
+
+impl PseudoLocale {
+    /// Returns the region subtag marking the pseudo-locale.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use unic_locale_impl::PseudoLocale;
+    ///
+    /// assert_eq!(PseudoLocale::Accented.region().as_str(), "XA");
+    /// assert_eq!(PseudoLocale::Bidi.region().as_str(), "XB");
+    /// ```
+    pub fn region(self) -> subtags::Region {
+        let region = match self {
+            PseudoLocale::Accented => "XA",
+            PseudoLocale::Bidi => "XB",
+        };
+        region
+            .parse()
+            .expect("Pseudo-locale regions are well-formed.")
+    }
+
+    pub(crate) fn from_region(region: subtags::Region) -> Option<Self> {
+        match region.as_str() {
+            "XA" => Some(PseudoLocale::Accented),
+            "XB" => Some(PseudoLocale::Bidi),
+            _ => None,
+        }
+    }
+}
+
+pub(crate) fn to_pseudo_locale(locale: &Locale, kind: PseudoLocale) -> Locale {
+    let mut result = locale.clone();
+    result.id.region = Some(kind.region());
+    result
+}
@@ -0,0 +1,180 @@
+//! An FFI-safe representation of a `Locale`.
+use crate::errors::LocaleError;
+use crate::extensions::ExtensionsMap;
+use crate::parser::ParserError;
+use crate::{subtags, Locale};
+use std::convert::TryFrom;
+use std::marker::PhantomData;
+
+/// A `#[repr(C)]` view of a `Locale`, which can be passed by value across
+/// an FFI boundary.
+///
+/// The language, script and region subtags are packed into words holding their
+/// ASCII bytes in little-endian order, with `0` standing for a missing subtag
+/// (or `und` for the language). The variants are a pointer to an array of packed
+/// `u64` words, and the extensions are a pointer to their UTF-8 serialization,
+/// such as `-u-hc-h12`. Both pointers may be null when the length is `0`.
+///
+/// The equivalent C declaration is:
+///
+/// ```c
+/// typedef struct {
+///     uint64_t language;
+///     uint32_t script;
+///     uint32_t region;
+///     const uint64_t *variants;
+///     size_t variants_len;
+///     const uint8_t *extensions;
+///     size_t extensions_len;
+/// } RawLocale;
+/// ```
+///
+/// A `RawLocale` borrows from the [`RawLocaleBuf`] it was created from. Foreign
+/// code passing one to Rust must keep both pointers valid for the given lengths.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+/// use unic_locale_impl::{Locale, RawLocaleBuf};
+///
+/// let loc: Locale = "sr-Latn-RS-u-nu-latn".parse()
+///     .expect("Parsing failed.");
+///
+/// let buf = RawLocaleBuf::from(&loc);
+/// let raw = buf.as_raw();
+///
+/// assert_eq!(Locale::try_from(raw), Ok(loc));
+/// ```
+#[repr(C)]
+#[derive(Debug, Clone, Copy)]
+pub struct RawLocale<'a> {
+    language: u64,
+    script: u32,
+    region: u32,
+    variants: *const u64,
+    variants_len: usize,
+    extensions: *const u8,
+    extensions_len: usize,
+    marker: PhantomData<&'a [u8]>,
+}
+
+impl RawLocale<'_> {
+    /// Returns the packed language subtag, `0` for `und`.
+    pub fn language(&self) -> u64 {
+        self.language
+    }
+
+    /// Returns the packed script subtag, `0` if there is none.
+    pub fn script(&self) -> u32 {
+        self.script
+    }
+
+    /// Returns the packed region subtag, `0` if there is none.
+    pub fn region(&self) -> u32 {
+        self.region
+    }
+
+    fn variants(&self) -> &[u64] {
+        if self.variants_len == 0 {
+            return &[];
+        }
+        // The pointer and length were taken from a slice borrowed for `'a`.
+        unsafe { std::slice::from_raw_parts(self.variants, self.variants_len) }
+    }
+
+    fn extensions(&self) -> &[u8] {
+        if self.extensions_len == 0 {
+            return &[];
+        }
+        // The pointer and length were taken from a slice borrowed for `'a`.
+        unsafe { std::slice::from_raw_parts(self.extensions, self.extensions_len) }
+    }
+}
+
+/// The owner of the variants and extensions a [`RawLocale`] points to.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RawLocaleBuf {
+    language: u64,
+    script: u32,
+    region: u32,
+    variants: Box<[u64]>,
+    extensions: Box<str>,
+}
+
+impl RawLocaleBuf {
+    /// Returns a `RawLocale` pointing into this buffer.
+    pub fn as_raw(&self) -> RawLocale<'_> {
+        RawLocale {
+            language: self.language,
+            script: self.script,
+            region: self.region,
+            variants: self.variants.as_ptr(),
+            variants_len: self.variants.len(),
+            extensions: self.extensions.as_ptr(),
+            extensions_len: self.extensions.len(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl From<&Locale> for RawLocaleBuf {
+    fn from(locale: &Locale) -> Self {
+        let id = &locale.id;
+        Self {
+            language: Option::<u64>::from(id.language).unwrap_or(0),
+            script: id.script.map_or(0, u32::from),
+            region: id.region.map_or(0, u32::from),
+            variants: id.variants().map(u64::from).collect(),
+            extensions: locale.extensions.to_string().into_boxed_str(),
+        }
+    }
+}
+
+/// Returns the bytes of a packed subtag, without the trailing zeros.
+fn unpack(bytes: &[u8]) -> &[u8] {
+    let len = bytes.iter().position(|b| *b == 0).unwrap_or(bytes.len());
+    &bytes[..len]
+}
+
+impl TryFrom<RawLocale<'_>> for Locale {
+    type Error = LocaleError;
+
+    /// Validates all subtags of the `RawLocale`, as when parsing.
+    fn try_from(raw: RawLocale<'_>) -> Result<Self, Self::Error> {
+        let language = match raw.language {
+            0 => subtags::Language::default(),
+            v => subtags::Language::from_bytes(unpack(&v.to_le_bytes()))
+                .map_err(|_| ParserError::InvalidLanguage)?,
+        };
+        let script = match raw.script {
+            0 => None,
+            v => Some(
+                subtags::Script::from_bytes(unpack(&v.to_le_bytes()))
+                    .map_err(|_| ParserError::InvalidSubtag)?,
+            ),
+        };
+        let region = match raw.region {
+            0 => None,
+            v => Some(
+                subtags::Region::from_bytes(unpack(&v.to_le_bytes()))
+                    .map_err(|_| ParserError::InvalidSubtag)?,
+            ),
+        };
+        let variants = raw
+            .variants()
+            .iter()
+            .map(|v| subtags::Variant::from_bytes(unpack(&v.to_le_bytes())))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| ParserError::InvalidSubtag)?;
+        let extensions = ExtensionsMap::from_bytes(raw.extensions())?;
+
+        Ok(Locale::from_parts(
+            language,
+            script,
+            region,
+            &variants,
+            Some(extensions),
+        ))
+    }
+}
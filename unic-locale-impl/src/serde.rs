@@ -0,0 +1,75 @@
+use crate::{ExtensionsMap, Locale};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+impl Serialize for Locale {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Locale {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct LocaleVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for LocaleVisitor {
+            type Value = Locale;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "A valid Unicode Locale Identifier")
+            }
+
+            fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                s.parse::<Locale>().map_err(serde::de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_string(LocaleVisitor)
+    }
+}
+
+impl Serialize for ExtensionsMap {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ExtensionsMap {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ExtensionsMapVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for ExtensionsMapVisitor {
+            type Value = ExtensionsMap;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(
+                    formatter,
+                    "A valid list of Unicode Locale Identifier extensions"
+                )
+            }
+
+            fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                s.parse::<ExtensionsMap>().map_err(serde::de::Error::custom)
+            }
+        }
+
+        deserializer.deserialize_string(ExtensionsMapVisitor)
+    }
+}
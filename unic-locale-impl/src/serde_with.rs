@@ -0,0 +1,114 @@
+//! Adapters for `serde_with::serde_as`, for (de)serializing fields holding a `Locale`.
+//!
+//! # Examples
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use serde_with::serde_as;
+//! use unic_locale_impl::serde_with::{LenientLocale, LocaleString};
+//! use unic_locale_impl::Locale;
+//!
+//! #[serde_as]
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde_as(as = "LocaleString")]
+//!     ui: Locale,
+//!     #[serde_as(as = "Vec<LenientLocale>")]
+//!     fallbacks: Vec<Locale>,
+//! }
+//!
+//! let config: Config = serde_json::from_str(
+//!     r#"{ "ui": "en-US", "fallbacks": [" de_AT.UTF-8 ", "fr"] }"#
+//! ).expect("Deserializing failed.");
+//!
+//! assert_eq!(config.ui.to_string(), "en-US");
+//! assert_eq!(config.fallbacks[0].to_string(), "de-AT");
+//! ```
+use crate::parser::{parse_locale, parse_locale_with_options, ParserOptions};
+use crate::Locale;
+use ::serde_with::{DeserializeAs, SerializeAs};
+use serde::{de, Deserializer, Serializer};
+use std::fmt;
+
+/// Serializes a `Locale` as its string form, and deserializes it
+/// from a well-formed locale identifier.
+pub struct LocaleString;
+
+/// Serializes a `Locale` as its string form, and deserializes it leniently.
+///
+/// Surrounding whitespace, and the charset and modifier of POSIX locale names
+/// such as `de_AT.UTF-8@euro`, are ignored. Extension values and private use
+/// subtags are parsed as with `ParserOptions::lenient`.
+pub struct LenientLocale;
+
+fn parse_lenient(s: &str) -> Result<Locale, crate::parser::ParserError> {
+    let s = s.trim();
+    let s = s.split(['.', '@']).next().unwrap_or_default();
+
+    #[cfg(feature = "extensions")]
+    let options = ParserOptions::default().lenient(true);
+    #[cfg(not(feature = "extensions"))]
+    let options = ParserOptions::default();
+
+    parse_locale_with_options(s, options)
+}
+
+struct LocaleVisitor {
+    lenient: bool,
+}
+
+impl<'de> de::Visitor<'de> for LocaleVisitor {
+    type Value = Locale;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "A valid Unicode Locale Identifier")
+    }
+
+    fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let result = if self.lenient {
+            parse_lenient(s)
+        } else {
+            parse_locale(s)
+        };
+        result.map_err(de::Error::custom)
+    }
+}
+
+impl SerializeAs<Locale> for LocaleString {
+    fn serialize_as<S>(source: &Locale, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(source)
+    }
+}
+
+impl<'de> DeserializeAs<'de, Locale> for LocaleString {
+    fn deserialize_as<D>(deserializer: D) -> Result<Locale, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(LocaleVisitor { lenient: false })
+    }
+}
+
+impl SerializeAs<Locale> for LenientLocale {
+    fn serialize_as<S>(source: &Locale, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.collect_str(source)
+    }
+}
+
+impl<'de> DeserializeAs<'de, Locale> for LenientLocale {
+    fn deserialize_as<D>(deserializer: D) -> Result<Locale, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_str(LocaleVisitor { lenient: true })
+    }
+}
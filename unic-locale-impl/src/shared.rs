@@ -0,0 +1,76 @@
+//! A `Locale` variant with shared extensions, for workloads cloning locales often.
+use crate::errors::LocaleError;
+use crate::extensions::SharedExtensionsMap;
+use crate::{LanguageIdentifier, Locale};
+use std::str::FromStr;
+
+/// A `Locale` whose extensions are a [`SharedExtensionsMap`], so cloning it does
+/// not copy the extensions until one of the clones mutates them.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::{Locale, SharedLocale};
+///
+/// let loc: SharedLocale = "de-DE-u-ca-gregory-co-phonebk-hc-h23".parse()
+///     .expect("Parsing failed.");
+///
+/// let mut per_request = loc.clone();
+/// per_request.id.region = Some("AT".parse().unwrap());
+///
+/// assert_eq!(per_request.to_string(), "de-AT-u-ca-gregory-co-phonebk-hc-h23");
+/// assert_eq!(Locale::from(per_request), "de-AT-u-ca-gregory-co-phonebk-hc-h23".parse::<Locale>().unwrap());
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Hash, PartialOrd, Ord)]
+pub struct SharedLocale {
+    pub id: LanguageIdentifier,
+    pub extensions: SharedExtensionsMap,
+}
+
+impl FromStr for SharedLocale {
+    type Err = LocaleError;
+
+    fn from_str(source: &str) -> Result<Self, Self::Err> {
+        Ok(source.parse::<Locale>()?.into())
+    }
+}
+
+impl From<Locale> for SharedLocale {
+    fn from(locale: Locale) -> Self {
+        Self {
+            id: locale.id,
+            extensions: locale.extensions.into(),
+        }
+    }
+}
+
+impl From<&Locale> for SharedLocale {
+    fn from(locale: &Locale) -> Self {
+        Self {
+            id: locale.id.clone(),
+            extensions: locale.extensions.clone().into(),
+        }
+    }
+}
+
+impl From<SharedLocale> for Locale {
+    fn from(locale: SharedLocale) -> Self {
+        Locale {
+            id: locale.id,
+            extensions: locale.extensions.into_inner(),
+        }
+    }
+}
+
+impl From<&SharedLocale> for Locale {
+    fn from(locale: &SharedLocale) -> Self {
+        locale.clone().into()
+    }
+}
+
+impl std::fmt::Display for SharedLocale {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        self.id.fmt(f)?;
+        self.extensions.fmt(f)
+    }
+}
@@ -0,0 +1,94 @@
+//! A versioned binary encoding of `Locale`, for values persisted in databases
+//! or caches.
+use crate::parser::{parse_locale, parse_locale_with_options, ParserError, ParserOptions};
+use crate::Locale;
+use std::error::Error;
+use std::fmt::{self, Display};
+
+/// The first byte of every encoded `Locale`.
+pub(crate) const MAGIC: u8 = 0xB7;
+/// The format version written by `Locale::to_stable_bytes`.
+pub(crate) const CURRENT_VERSION: u8 = 2;
+/// The flag of a version 2 payload recording that the extensions were parsed with
+/// `ParserOptions::lenient`.
+const LENIENT: u8 = 0b1;
+
+/// Errors returned when decoding a `Locale` with `Locale::from_stable_bytes`.
+#[derive(Debug, PartialEq)]
+pub enum StableDecodingError {
+    /// The input is too short to contain the header.
+    Truncated,
+    /// The input does not start with the magic byte, and was not produced
+    /// by `Locale::to_stable_bytes`.
+    InvalidMagic(u8),
+    /// The input was written with a format version this crate version does
+    /// not know, usually by a newer one, and needs to be migrated.
+    UnsupportedVersion(u8),
+    /// The payload is not a well-formed locale, for example because it uses
+    /// extensions while the `extensions` feature is disabled.
+    InvalidPayload(ParserError),
+}
+
+impl Error for StableDecodingError {}
+
+impl Display for StableDecodingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Truncated => f.write_str("The encoded locale is truncated"),
+            Self::InvalidMagic(b) => write!(f, "Invalid magic byte 0x{:02x}", b),
+            Self::UnsupportedVersion(v) => {
+                write!(f, "Unsupported encoding version {}", v)
+            }
+            Self::InvalidPayload(e) => write!(f, "Invalid payload: {}", e),
+        }
+    }
+}
+
+pub(crate) fn encode(locale: &Locale) -> Vec<u8> {
+    let mut result = Vec::with_capacity(locale.serialized_len() + 3);
+    result.push(MAGIC);
+    result.push(CURRENT_VERSION);
+    result.push(if locale.extensions.is_lenient() {
+        LENIENT
+    } else {
+        0
+    });
+    result.extend_from_slice(locale.to_string().as_bytes());
+    result
+}
+
+pub(crate) fn decode(bytes: &[u8]) -> Result<Locale, StableDecodingError> {
+    let (magic, version, payload) = match bytes {
+        [magic, version, payload @ ..] => (*magic, *version, payload),
+        _ => return Err(StableDecodingError::Truncated),
+    };
+    if magic != MAGIC {
+        return Err(StableDecodingError::InvalidMagic(magic));
+    }
+    match version {
+        1 => parse_locale(payload).map_err(StableDecodingError::InvalidPayload),
+        2 => {
+            let (flags, payload) = match payload {
+                [flags, payload @ ..] => (*flags, payload),
+                _ => return Err(StableDecodingError::Truncated),
+            };
+            // Flags this crate version does not know come from a newer writer.
+            if flags & !LENIENT != 0 {
+                return Err(StableDecodingError::UnsupportedVersion(version));
+            }
+            parse_locale_with_options(payload, options(flags))
+                .map_err(StableDecodingError::InvalidPayload)
+        }
+        v => Err(StableDecodingError::UnsupportedVersion(v)),
+    }
+}
+
+#[cfg(feature = "extensions")]
+fn options(flags: u8) -> ParserOptions {
+    ParserOptions::default().lenient(flags & LENIENT != 0)
+}
+
+#[cfg(not(feature = "extensions"))]
+fn options(_flags: u8) -> ParserOptions {
+    ParserOptions::default()
+}
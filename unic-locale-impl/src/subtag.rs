@@ -0,0 +1,101 @@
+//! A typed view of the subtags of a `Locale`, in serialization order.
+#[cfg(feature = "extensions")]
+use crate::LanguageIdentifier;
+use crate::{subtags, ExtensionType, Locale};
+
+/// A single subtag of a `Locale`, as yielded by `Locale::subtags`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Subtag<'a> {
+    /// The language subtag, including `und`.
+    Language(subtags::Language),
+    Script(subtags::Script),
+    Region(subtags::Region),
+    Variant(subtags::Variant),
+    /// The singleton starting an extension, such as `u`.
+    ExtensionSingleton(ExtensionType),
+    /// A unicode extension key or a transform extension tkey.
+    ExtensionKey(&'a str),
+    /// A unicode extension attribute or value, a subtag of a transform
+    /// extension tlang or tvalue, or a subtag of another extension.
+    ///
+    /// Subtags of a tlang keep the casing of `LanguageIdentifier`.
+    ExtensionValue(&'a str),
+    /// A private use subtag.
+    Private(&'a str),
+}
+
+#[cfg(feature = "extensions")]
+fn push_langid<'a>(id: &'a LanguageIdentifier, result: &mut Vec<Subtag<'a>>) {
+    result.push(Subtag::ExtensionValue(id.language.as_str()));
+    if let Some(script) = &id.script {
+        result.push(Subtag::ExtensionValue(script.as_str()));
+    }
+    if let Some(region) = &id.region {
+        result.push(Subtag::ExtensionValue(region.as_str()));
+    }
+    for variant in id.variants() {
+        result.push(Subtag::ExtensionValue(variant.as_str()));
+    }
+}
+
+#[cfg(feature = "extensions")]
+fn push_other<'a>(
+    locale: &'a Locale,
+    singletons: impl std::ops::RangeBounds<char>,
+    result: &mut Vec<Subtag<'a>>,
+) {
+    for (singleton, values) in locale.extensions.other.range(singletons) {
+        result.push(Subtag::ExtensionSingleton(ExtensionType::Other(*singleton)));
+        result.extend(values.iter().map(|v| Subtag::ExtensionValue(v.as_str())));
+    }
+}
+
+pub(crate) fn subtags(locale: &Locale) -> Vec<Subtag<'_>> {
+    let id = &locale.id;
+    let mut result = vec![];
+    if !locale.is_private_use_only() {
+        result.push(Subtag::Language(id.language));
+    }
+    result.extend(id.script.map(Subtag::Script));
+    result.extend(id.region.map(Subtag::Region));
+    result.extend(id.variants().map(|v| Subtag::Variant(*v)));
+
+    #[cfg(feature = "extensions")]
+    {
+        push_other(locale, ..'t', &mut result);
+
+        let transform = &locale.extensions.transform;
+        if !transform.is_empty() {
+            result.push(Subtag::ExtensionSingleton(ExtensionType::Transform));
+            if let Some(tlang) = transform.tlang() {
+                push_langid(tlang, &mut result);
+            }
+            for key in transform.tfield_keys() {
+                result.push(Subtag::ExtensionKey(key));
+                let values = transform.tfield(key).expect("Keys are well-formed.");
+                result.extend(values.map(Subtag::ExtensionValue));
+            }
+        }
+
+        let unicode = &locale.extensions.unicode;
+        if !unicode.is_empty() {
+            result.push(Subtag::ExtensionSingleton(ExtensionType::Unicode));
+            result.extend(unicode.attributes().map(Subtag::ExtensionValue));
+            for key in unicode.keyword_keys() {
+                result.push(Subtag::ExtensionKey(key));
+                let values = unicode.keyword(key).expect("Keys are well-formed.");
+                result.extend(values.map(Subtag::ExtensionValue));
+            }
+        }
+
+        push_other(locale, 'v'.., &mut result);
+
+        let private = &locale.extensions.private;
+        if !private.is_empty() {
+            result.push(Subtag::ExtensionSingleton(ExtensionType::Private));
+            result.extend(private.tags().map(Subtag::Private));
+        }
+    }
+
+    result
+}
@@ -0,0 +1,87 @@
+//! Languages spoken in a region, with their population share and official
+//! status, from CLDR `territoryInfo`.
+//!
+//! The data covers a subset of regions, and only the languages with an official
+//! status in them.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_locale_impl::territory::{languages_for_region, OfficialStatus};
+//!
+//! let languages = languages_for_region("CH".parse().unwrap());
+//!
+//! let tags: Vec<_> = languages.iter()
+//!     .map(|l| l.language.as_str())
+//!     .collect();
+//! assert_eq!(tags, &["de", "fr", "it", "rm"]);
+//! assert_eq!(languages[0].official_status, Some(OfficialStatus::Official));
+//! ```
+use crate::subtags::{Language, Region};
+
+/// The official status of a language in a region.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum OfficialStatus {
+    /// An official language of the region.
+    Official,
+    /// Not an official language by law, but used as one.
+    DeFactoOfficial,
+    /// An official language of a part of the region.
+    OfficialRegional,
+}
+
+/// A language spoken in a region.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct TerritoryLanguage {
+    pub language: Language,
+    /// The share of the population using the language, in percent.
+    pub population_percent: f32,
+    pub official_status: Option<OfficialStatus>,
+}
+
+use OfficialStatus::*;
+
+// The CLDR version the curated table below follows. It isn't generated, as the
+// `territoryInfo` data isn't bundled.
+pub(crate) static CLDR_VERSION: &str = "47";
+
+// A language, its population share and its official status.
+type Entry = (&'static str, f32, Option<OfficialStatus>);
+
+// By region, and by descending population share.
+#[rustfmt::skip]
+static TERRITORY_LANGUAGES: &[(&str, &[Entry])] = &[
+    ("BE", &[("nl", 60.0, Some(Official)), ("fr", 38.0, Some(Official)), ("de", 1.0, Some(Official))]),
+    ("CA", &[("en", 86.0, Some(Official)), ("fr", 30.0, Some(Official))]),
+    ("CH", &[("de", 64.0, Some(Official)), ("fr", 23.0, Some(Official)), ("it", 8.4, Some(Official)), ("rm", 0.6, Some(Official))]),
+    ("DE", &[("de", 91.0, Some(Official))]),
+    ("ES", &[("es", 99.0, Some(Official)), ("ca", 16.0, Some(OfficialRegional)), ("gl", 5.1, Some(OfficialRegional)), ("eu", 1.1, Some(OfficialRegional))]),
+    ("FI", &[("fi", 93.0, Some(Official)), ("sv", 5.5, Some(Official))]),
+    ("FR", &[("fr", 100.0, Some(Official))]),
+    ("GB", &[("en", 99.0, Some(Official)), ("cy", 0.9, Some(OfficialRegional)), ("gd", 0.1, Some(OfficialRegional))]),
+    ("IE", &[("en", 99.0, Some(Official)), ("ga", 28.0, Some(Official))]),
+    ("LU", &[("fr", 90.0, Some(Official)), ("de", 88.0, Some(Official)), ("lb", 77.0, Some(Official))]),
+    ("NZ", &[("en", 91.0, Some(Official)), ("mi", 2.9, Some(Official))]),
+    ("SG", &[("en", 92.0, Some(Official)), ("zh", 77.0, Some(Official)), ("ms", 16.0, Some(Official)), ("ta", 3.3, Some(Official))]),
+    ("US", &[("en", 96.0, Some(DeFactoOfficial)), ("haw", 0.0089, Some(OfficialRegional))]),
+];
+
+/// Returns the languages spoken in a region, by descending population share.
+///
+/// Returns an empty list for regions without data.
+pub fn languages_for_region(region: Region) -> Vec<TerritoryLanguage> {
+    let languages = match TERRITORY_LANGUAGES.binary_search_by_key(&region.as_str(), |(r, _)| r) {
+        Ok(idx) => TERRITORY_LANGUAGES[idx].1,
+        Err(_) => &[],
+    };
+    languages
+        .iter()
+        .map(
+            |(language, population_percent, official_status)| TerritoryLanguage {
+                language: language.parse().expect("Table languages are well-formed."),
+                population_percent: *population_percent,
+                official_status: *official_status,
+            },
+        )
+        .collect()
+}
@@ -0,0 +1,110 @@
+//! Invariant checks for test suites of crates embedding locales.
+//!
+//! The assertions panic with the offending tag, like the `assert!` macros, and
+//! the corpus loaders read newline separated tags to run them over.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_locale_impl::test_util;
+//!
+//! let corpus = "
+//!     ## Tags supported by the application.
+//!     en-US
+//!     sr-Latn-RS
+//! ";
+//! for tag in test_util::parse_corpus(corpus) {
+//!     test_util::assert_roundtrip(tag);
+//!     test_util::assert_canonical_idempotent(tag);
+//! }
+//! ```
+use crate::{canonicalize, Locale};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Asserts that the tag parses, and that its serialization parses back into
+/// an equal `Locale` serialized identically. Returns the parsed `Locale`.
+///
+/// # Panics
+///
+/// Panics if the tag fails to parse or does not round-trip.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::test_util::assert_roundtrip;
+///
+/// let loc = assert_roundtrip("EN_us-u-hc-h12");
+/// assert_eq!(loc.to_string(), "en-US-u-hc-h12");
+/// ```
+pub fn assert_roundtrip<S: AsRef<str>>(tag: S) -> Locale {
+    let tag = tag.as_ref();
+    let loc: Locale = tag
+        .parse()
+        .unwrap_or_else(|e| panic!("Parsing {:?} failed: {}", tag, e));
+    let serialized = loc.to_string();
+    let reparsed: Locale = serialized.parse().unwrap_or_else(|e| {
+        panic!(
+            "Parsing {:?} serialized from {:?} failed: {}",
+            serialized, tag, e
+        )
+    });
+    assert_eq!(loc, reparsed, "{:?} does not round-trip", tag);
+    assert_eq!(
+        reparsed.to_string(),
+        serialized,
+        "{:?} does not serialize identically after a round-trip",
+        tag
+    );
+    loc
+}
+
+/// Asserts that the tag canonicalizes, and that canonicalizing the result
+/// leaves it unchanged. Returns the canonical form.
+///
+/// # Panics
+///
+/// Panics if the tag fails to canonicalize or its canonical form is not stable.
+///
+/// # Examples
+///
+/// ```
+/// use unic_locale_impl::test_util::assert_canonical_idempotent;
+///
+/// assert_eq!(assert_canonical_idempotent("th-u-ca-islamicc"), "th-u-ca-islamic-civil");
+/// ```
+pub fn assert_canonical_idempotent<S: AsRef<str>>(tag: S) -> String {
+    let tag = tag.as_ref();
+    let canonical =
+        canonicalize(tag).unwrap_or_else(|e| panic!("Canonicalizing {:?} failed: {}", tag, e));
+    let again = canonicalize(&canonical).unwrap_or_else(|e| {
+        panic!(
+            "Canonicalizing {:?} from {:?} failed: {}",
+            canonical, tag, e
+        )
+    });
+    assert_eq!(
+        canonical, again,
+        "The canonical form of {:?} is not idempotent",
+        tag
+    );
+    canonical
+}
+
+/// Returns the tags of a corpus, one per line, skipping blank lines and
+/// lines starting with `#`, with surrounding whitespace trimmed.
+pub fn parse_corpus(corpus: &str) -> impl Iterator<Item = &str> {
+    corpus
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+}
+
+/// Reads a corpus file in the format of [`parse_corpus`].
+///
+/// [`parse_corpus`]: fn.parse_corpus.html
+pub fn load_corpus<P: AsRef<Path>>(path: P) -> io::Result<Vec<String>> {
+    let corpus = fs::read_to_string(path)?;
+    Ok(parse_corpus(&corpus).map(String::from).collect())
+}
@@ -0,0 +1,260 @@
+//! The primary time zone of each region, from CLDR `timezone` and `primaryZones`.
+//!
+//! A region has a primary time zone if it has a single one, such as `Europe/Paris`
+//! for `FR`, or if CLDR names one of its zones as the one commonly used, such as
+//! `Asia/Shanghai` for `CN`. Regions spanning several time zones without a primary
+//! one, such as `US`, have none.
+//!
+//! # Examples
+//!
+//! ```
+//! use unic_locale_impl::timezone::primary_zone_for_region;
+//!
+//! assert_eq!(primary_zone_for_region("FR".parse().unwrap()), Some("Europe/Paris"));
+//! assert_eq!(primary_zone_for_region("US".parse().unwrap()), None);
+//! ```
+use crate::subtags::Region;
+
+// The CLDR version the curated table below follows. It isn't generated, as the
+// `primaryZones` data isn't bundled.
+pub(crate) static CLDR_VERSION: &str = "47";
+
+// Sorted by region, with the IANA name and the BCP 47 short id of the zone.
+#[rustfmt::skip]
+static PRIMARY_ZONES: &[(&str, &str, &str)] = &[
+    ("AD", "Europe/Andorra", "adalv"),
+    ("AE", "Asia/Dubai", "aedxb"),
+    ("AF", "Asia/Kabul", "afkbl"),
+    ("AG", "America/Antigua", "aganu"),
+    ("AI", "America/Anguilla", "aiaxa"),
+    ("AL", "Europe/Tirane", "altia"),
+    ("AM", "Asia/Yerevan", "amevn"),
+    ("AO", "Africa/Luanda", "aolad"),
+    ("AT", "Europe/Vienna", "atvie"),
+    ("AW", "America/Aruba", "awaua"),
+    ("AX", "Europe/Mariehamn", "fimhq"),
+    ("AZ", "Asia/Baku", "azbak"),
+    ("BA", "Europe/Sarajevo", "basjj"),
+    ("BB", "America/Barbados", "bbbgi"),
+    ("BD", "Asia/Dhaka", "bddac"),
+    ("BE", "Europe/Brussels", "bebru"),
+    ("BF", "Africa/Ouagadougou", "bfoua"),
+    ("BG", "Europe/Sofia", "bgsof"),
+    ("BH", "Asia/Bahrain", "bhbah"),
+    ("BI", "Africa/Bujumbura", "bibjm"),
+    ("BJ", "Africa/Porto-Novo", "bjptn"),
+    ("BL", "America/St_Barthelemy", "gpsbh"),
+    ("BM", "Atlantic/Bermuda", "bmbda"),
+    ("BN", "Asia/Brunei", "bnbwn"),
+    ("BO", "America/La_Paz", "bolpb"),
+    ("BS", "America/Nassau", "bsnas"),
+    ("BT", "Asia/Thimphu", "btthi"),
+    ("BW", "Africa/Gaborone", "bwgbe"),
+    ("BY", "Europe/Minsk", "bymsq"),
+    ("BZ", "America/Belize", "bzbze"),
+    ("CF", "Africa/Bangui", "cfbgf"),
+    ("CG", "Africa/Brazzaville", "cgbzv"),
+    ("CH", "Europe/Zurich", "chzrh"),
+    ("CI", "Africa/Abidjan", "ciabj"),
+    ("CL", "America/Santiago", "clscl"),
+    ("CM", "Africa/Douala", "cmdla"),
+    ("CN", "Asia/Shanghai", "cnsha"),
+    ("CO", "America/Bogota", "cobog"),
+    ("CR", "America/Costa_Rica", "crsjo"),
+    ("CU", "America/Havana", "cuhav"),
+    ("CV", "Atlantic/Cape_Verde", "cvrai"),
+    ("CW", "America/Curacao", "ancur"),
+    ("CX", "Indian/Christmas", "cxxch"),
+    ("CZ", "Europe/Prague", "czprg"),
+    ("DE", "Europe/Berlin", "deber"),
+    ("DJ", "Africa/Djibouti", "djjib"),
+    ("DK", "Europe/Copenhagen", "dkcph"),
+    ("DM", "America/Dominica", "dmdom"),
+    ("DO", "America/Santo_Domingo", "dosdq"),
+    ("DZ", "Africa/Algiers", "dzalg"),
+    ("EC", "America/Guayaquil", "ecgye"),
+    ("EE", "Europe/Tallinn", "eetll"),
+    ("EG", "Africa/Cairo", "egcai"),
+    ("ER", "Africa/Asmera", "erasm"),
+    ("ES", "Europe/Madrid", "esmad"),
+    ("ET", "Africa/Addis_Ababa", "etadd"),
+    ("FI", "Europe/Helsinki", "fihel"),
+    ("FJ", "Pacific/Fiji", "fjsuv"),
+    ("FO", "Atlantic/Faeroe", "fotho"),
+    ("FR", "Europe/Paris", "frpar"),
+    ("GA", "Africa/Libreville", "galbv"),
+    ("GB", "Europe/London", "gblon"),
+    ("GD", "America/Grenada", "gdgnd"),
+    ("GE", "Asia/Tbilisi", "getbs"),
+    ("GF", "America/Cayenne", "gfcay"),
+    ("GG", "Europe/Guernsey", "gggci"),
+    ("GH", "Africa/Accra", "ghacc"),
+    ("GI", "Europe/Gibraltar", "gigib"),
+    ("GM", "Africa/Banjul", "gmbjl"),
+    ("GN", "Africa/Conakry", "gncky"),
+    ("GP", "America/Guadeloupe", "gpbbr"),
+    ("GQ", "Africa/Malabo", "gqssg"),
+    ("GR", "Europe/Athens", "grath"),
+    ("GT", "America/Guatemala", "gtgua"),
+    ("GU", "Pacific/Guam", "gugum"),
+    ("GW", "Africa/Bissau", "gwoxb"),
+    ("GY", "America/Guyana", "gygeo"),
+    ("HK", "Asia/Hong_Kong", "hkhkg"),
+    ("HN", "America/Tegucigalpa", "hntgu"),
+    ("HR", "Europe/Zagreb", "hrzag"),
+    ("HT", "America/Port-au-Prince", "htpap"),
+    ("HU", "Europe/Budapest", "hubud"),
+    ("IE", "Europe/Dublin", "iedub"),
+    ("IL", "Asia/Jerusalem", "jeruslm"),
+    ("IM", "Europe/Isle_of_Man", "imdgs"),
+    ("IN", "Asia/Calcutta", "inccu"),
+    ("IQ", "Asia/Baghdad", "iqbgw"),
+    ("IR", "Asia/Tehran", "irthr"),
+    ("IS", "Atlantic/Reykjavik", "isrey"),
+    ("IT", "Europe/Rome", "itrom"),
+    ("JE", "Europe/Jersey", "jesth"),
+    ("JM", "America/Jamaica", "jmkin"),
+    ("JO", "Asia/Amman", "joamm"),
+    ("JP", "Asia/Tokyo", "jptyo"),
+    ("KE", "Africa/Nairobi", "kenbo"),
+    ("KG", "Asia/Bishkek", "kgfru"),
+    ("KH", "Asia/Phnom_Penh", "khpnh"),
+    ("KM", "Indian/Comoro", "kmyva"),
+    ("KN", "America/St_Kitts", "knbas"),
+    ("KP", "Asia/Pyongyang", "kpfnj"),
+    ("KR", "Asia/Seoul", "krsel"),
+    ("KW", "Asia/Kuwait", "kwkwi"),
+    ("KY", "America/Cayman", "kygec"),
+    ("LA", "Asia/Vientiane", "lavte"),
+    ("LB", "Asia/Beirut", "lbbey"),
+    ("LC", "America/St_Lucia", "lccas"),
+    ("LI", "Europe/Vaduz", "livdz"),
+    ("LK", "Asia/Colombo", "lkcmb"),
+    ("LR", "Africa/Monrovia", "lrmlw"),
+    ("LS", "Africa/Maseru", "lsmsu"),
+    ("LT", "Europe/Vilnius", "ltvno"),
+    ("LU", "Europe/Luxembourg", "lulux"),
+    ("LV", "Europe/Riga", "lvrix"),
+    ("LY", "Africa/Tripoli", "lytip"),
+    ("MA", "Africa/Casablanca", "macas"),
+    ("MC", "Europe/Monaco", "mcmon"),
+    ("MD", "Europe/Chisinau", "mdkiv"),
+    ("ME", "Europe/Podgorica", "metgd"),
+    ("MF", "America/Marigot", "gpmsb"),
+    ("MG", "Indian/Antananarivo", "mgtnr"),
+    ("MH", "Pacific/Majuro", "mhmaj"),
+    ("MK", "Europe/Skopje", "mkskp"),
+    ("ML", "Africa/Bamako", "mlbko"),
+    ("MM", "Asia/Rangoon", "mmrgn"),
+    ("MN", "Asia/Ulaanbaatar", "mnuln"),
+    ("MO", "Asia/Macau", "momfm"),
+    ("MQ", "America/Martinique", "mqfdf"),
+    ("MR", "Africa/Nouakchott", "mrnkc"),
+    ("MS", "America/Montserrat", "msmni"),
+    ("MT", "Europe/Malta", "mtmla"),
+    ("MU", "Indian/Mauritius", "muplu"),
+    ("MV", "Indian/Maldives", "mvmle"),
+    ("MW", "Africa/Blantyre", "mwblz"),
+    ("MX", "America/Mexico_City", "mxmex"),
+    ("MY", "Asia/Kuala_Lumpur", "mykul"),
+    ("MZ", "Africa/Maputo", "mzmpm"),
+    ("NA", "Africa/Windhoek", "nawdh"),
+    ("NC", "Pacific/Noumea", "ncnou"),
+    ("NE", "Africa/Niamey", "nenim"),
+    ("NF", "Pacific/Norfolk", "nfnlk"),
+    ("NG", "Africa/Lagos", "nglos"),
+    ("NI", "America/Managua", "nimga"),
+    ("NL", "Europe/Amsterdam", "nlams"),
+    ("NO", "Europe/Oslo", "noosl"),
+    ("NP", "Asia/Katmandu", "npktm"),
+    ("NR", "Pacific/Nauru", "nrinu"),
+    ("NU", "Pacific/Niue", "nuiue"),
+    ("NZ", "Pacific/Auckland", "nzakl"),
+    ("OM", "Asia/Muscat", "ommct"),
+    ("PA", "America/Panama", "papty"),
+    ("PE", "America/Lima", "pelim"),
+    ("PF", "Pacific/Tahiti", "pfppt"),
+    ("PH", "Asia/Manila", "phmnl"),
+    ("PK", "Asia/Karachi", "pkkhi"),
+    ("PL", "Europe/Warsaw", "plwaw"),
+    ("PM", "America/Miquelon", "pmmqc"),
+    ("PN", "Pacific/Pitcairn", "pnpcn"),
+    ("PR", "America/Puerto_Rico", "prsju"),
+    ("PT", "Europe/Lisbon", "ptlis"),
+    ("PW", "Pacific/Palau", "pwror"),
+    ("PY", "America/Asuncion", "pyasu"),
+    ("QA", "Asia/Qatar", "qadoh"),
+    ("RE", "Indian/Reunion", "rereu"),
+    ("RO", "Europe/Bucharest", "robuh"),
+    ("RS", "Europe/Belgrade", "rsbeg"),
+    ("RW", "Africa/Kigali", "rwkgl"),
+    ("SA", "Asia/Riyadh", "saruh"),
+    ("SB", "Pacific/Guadalcanal", "sbhir"),
+    ("SC", "Indian/Mahe", "scmaw"),
+    ("SD", "Africa/Khartoum", "sdkrt"),
+    ("SE", "Europe/Stockholm", "sesto"),
+    ("SG", "Asia/Singapore", "sgsin"),
+    ("SH", "Atlantic/St_Helena", "shshn"),
+    ("SI", "Europe/Ljubljana", "silju"),
+    ("SK", "Europe/Bratislava", "skbts"),
+    ("SL", "Africa/Freetown", "slfna"),
+    ("SM", "Europe/San_Marino", "smsai"),
+    ("SN", "Africa/Dakar", "sndkr"),
+    ("SO", "Africa/Mogadishu", "somgq"),
+    ("SR", "America/Paramaribo", "srpbm"),
+    ("SS", "Africa/Juba", "ssjub"),
+    ("ST", "Africa/Sao_Tome", "sttms"),
+    ("SV", "America/El_Salvador", "svsal"),
+    ("SX", "America/Lower_Princes", "sxphi"),
+    ("SY", "Asia/Damascus", "sydam"),
+    ("SZ", "Africa/Mbabane", "szqmn"),
+    ("TC", "America/Grand_Turk", "tcgdt"),
+    ("TD", "Africa/Ndjamena", "tdndj"),
+    ("TG", "Africa/Lome", "tglfw"),
+    ("TH", "Asia/Bangkok", "thbkk"),
+    ("TJ", "Asia/Dushanbe", "tjdyu"),
+    ("TL", "Asia/Dili", "tldil"),
+    ("TM", "Asia/Ashgabat", "tmasb"),
+    ("TN", "Africa/Tunis", "tntun"),
+    ("TO", "Pacific/Tongatapu", "totbu"),
+    ("TR", "Europe/Istanbul", "trist"),
+    ("TT", "America/Port_of_Spain", "ttpos"),
+    ("TV", "Pacific/Funafuti", "tvfun"),
+    ("TW", "Asia/Taipei", "twtpe"),
+    ("TZ", "Africa/Dar_es_Salaam", "tzdar"),
+    ("UA", "Europe/Kiev", "uaiev"),
+    ("UG", "Africa/Kampala", "ugkla"),
+    ("UY", "America/Montevideo", "uymvd"),
+    ("UZ", "Asia/Tashkent", "uztas"),
+    ("VA", "Europe/Vatican", "vavat"),
+    ("VC", "America/St_Vincent", "vcsvd"),
+    ("VE", "America/Caracas", "veccs"),
+    ("VG", "America/Tortola", "vgtov"),
+    ("VI", "America/St_Thomas", "vistt"),
+    ("VN", "Asia/Saigon", "vnsgn"),
+    ("VU", "Pacific/Efate", "vuvli"),
+    ("WF", "Pacific/Wallis", "wfmau"),
+    ("WS", "Pacific/Apia", "wsapw"),
+    ("YE", "Asia/Aden", "yeade"),
+    ("YT", "Indian/Mayotte", "ytmam"),
+    ("ZA", "Africa/Johannesburg", "zajnb"),
+    ("ZM", "Africa/Lusaka", "zmlun"),
+    ("ZW", "Africa/Harare", "zwhre"),
+];
+
+/// Returns the IANA name of the primary time zone of a region.
+pub fn primary_zone_for_region(region: Region) -> Option<&'static str> {
+    PRIMARY_ZONES
+        .binary_search_by_key(&region.as_str(), |(r, _, _)| r)
+        .ok()
+        .map(|idx| PRIMARY_ZONES[idx].1)
+}
+
+/// Returns the IANA name of a primary time zone from its BCP 47 short id,
+/// as used by the `tz` keyword.
+pub(crate) fn zone_for_bcp47(id: &str) -> Option<&'static str> {
+    PRIMARY_ZONES
+        .iter()
+        .find(|(_, _, bcp47)| *bcp47 == id)
+        .map(|(_, iana, _)| *iana)
+}
@@ -0,0 +1,66 @@
+//! Conversion between `Locale` and Windows locale names, such as `de-DE_phoneb`,
+//! whose alternate sort suffix maps to the `co` unicode extension keyword.
+use crate::errors::LocaleError;
+use crate::parser::ParserError;
+use crate::Locale;
+
+// Windows alternate sort suffixes and the collations they select, if CLDR
+// defines one.
+static SORT_SUFFIXES: &[(&str, Option<&str>)] = &[
+    ("modern", None),
+    ("phoneb", Some("phonebk")),
+    ("pronun", Some("zhuyin")),
+    ("radstr", Some("unihan")),
+    ("stroke", Some("stroke")),
+    ("technl", None),
+    ("tradnl", Some("trad")),
+];
+
+/// Returns the `co` keyword value of a Windows alternate sort suffix, which is
+/// `Some(None)` for the suffixes of Windows specific sorts.
+pub(crate) fn collation_for_sort_suffix(suffix: &str) -> Option<Option<&'static str>> {
+    SORT_SUFFIXES
+        .iter()
+        .find(|(s, _)| s.eq_ignore_ascii_case(suffix))
+        .map(|(_, collation)| *collation)
+}
+
+/// Returns the Windows alternate sort suffix of a `co` keyword value.
+pub(crate) fn sort_suffix_for_collation(collation: &str) -> Option<&'static str> {
+    SORT_SUFFIXES
+        .iter()
+        .find(|(_, c)| *c == Some(collation))
+        .map(|(suffix, _)| *suffix)
+}
+
+pub(crate) fn from_windows_name(name: &str) -> Result<Locale, LocaleError> {
+    let (tag, suffix) = match name.split_once('_') {
+        Some((tag, suffix)) => (tag, Some(suffix)),
+        None => (name, None),
+    };
+    let mut locale: Locale = tag.parse()?;
+    if let Some(suffix) = suffix {
+        let collation = collation_for_sort_suffix(suffix).ok_or(ParserError::InvalidSubtag)?;
+        if let Some(collation) = collation {
+            locale.extensions.unicode.set_keyword("co", &[collation])?;
+        }
+    }
+    Ok(locale)
+}
+
+pub(crate) fn to_windows_name(locale: &Locale) -> String {
+    let mut result = locale.id.to_string();
+    let collation = locale
+        .extensions
+        .unicode
+        .keyword("co")
+        .expect("The key is well-formed.")
+        .collect::<Vec<_>>();
+    if let [collation] = collation[..] {
+        if let Some(suffix) = sort_suffix_for_collation(collation) {
+            result.push('_');
+            result.push_str(suffix);
+        }
+    }
+    result
+}
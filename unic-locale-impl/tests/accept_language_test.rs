@@ -0,0 +1,554 @@
+use unic_locale_impl::accept_language::{
+    self, AcceptLanguage, AcceptLanguageError, AvailableLocales, DefaultsPolicy,
+    LanguagePriorityList, LanguageRange, Outcome, Rejection, ScoreWeighting,
+};
+use unic_locale_impl::Locale;
+
+fn entries(input: &[(&str, f32)]) -> Vec<(Locale, f32)> {
+    input
+        .iter()
+        .map(|(loc, q)| (loc.parse().unwrap(), *q))
+        .collect()
+}
+
+fn ranges(input: &[(&str, f32)]) -> Vec<(LanguageRange, f32)> {
+    input
+        .iter()
+        .map(|(range, q)| match *range {
+            "*" => (LanguageRange::Wildcard, *q),
+            tag => (LanguageRange::Locale(tag.parse().unwrap()), *q),
+        })
+        .collect()
+}
+
+#[test]
+fn test_serialize() {
+    for (input, expected) in &[
+        (vec![], ""),
+        (vec![("en-US", 1.0)], "en-US"),
+        (vec![("en-US", 1.0), ("en", 0.9)], "en-US, en;q=0.9"),
+        (vec![("fr", 0.5), ("de", 0.75)], "fr;q=0.5, de;q=0.75"),
+        (vec![("fr", 0.0)], "fr;q=0"),
+        (vec![("fr", 0.0004)], "fr;q=0"),
+        (vec![("fr", 0.9996)], "fr"),
+        (vec![("fr", 0.001)], "fr;q=0.001"),
+        (vec![("fr", 0.12345)], "fr;q=0.123"),
+        (vec![("fr", 2.0), ("de", -1.0)], "fr, de;q=0"),
+        (vec![("fr", f32::NAN)], "fr;q=0"),
+        (
+            vec![("sr-Latn-RS-u-nu-latn", 0.8)],
+            "sr-Latn-RS-u-nu-latn;q=0.8",
+        ),
+    ] {
+        assert_eq!(&accept_language::serialize(&entries(input)), expected);
+    }
+}
+
+#[test]
+fn test_serialize_references() {
+    let en: Locale = "en".parse().unwrap();
+    let pl: Locale = "pl".parse().unwrap();
+    assert_eq!(
+        accept_language::serialize(&[(&en, 1.0), (&pl, 0.3)]),
+        "en, pl;q=0.3"
+    );
+}
+
+#[test]
+fn test_parse() {
+    for (input, expected) in &[
+        ("", vec![]),
+        ("en-US", vec![("en-US", 1.0)]),
+        (
+            "fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5",
+            vec![("fr-CH", 1.0), ("fr", 0.9), ("en", 0.8), ("*", 0.5)],
+        ),
+        (
+            " de ;q=0.5 ,pl , en_us;Q=0.7",
+            vec![("pl", 1.0), ("en-US", 0.7), ("de", 0.5)],
+        ),
+        ("en;q=0.5, fr;q=0.5", vec![("en", 0.5), ("fr", 0.5)]),
+        ("en;q=0, fr", vec![("fr", 1.0), ("en", 0.0)]),
+        ("en;q=1.000, fr;q=0.001", vec![("en", 1.0), ("fr", 0.001)]),
+        (
+            "en;q=2, fr;q=0.5555, de;q=abc, es;q=-1, it;q=1.5, pl;q=.5, ja",
+            vec![("ja", 1.0)],
+        ),
+        ("en--US, 123, ,;q=0.5, x@y", vec![]),
+    ] {
+        assert_eq!(
+            AcceptLanguage::parse(input).0,
+            ranges(expected),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_parse_strict() {
+    for (input, expected) in &[
+        ("", vec![]),
+        (
+            "fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5",
+            vec![("fr-CH", 1.0), ("fr", 0.9), ("en", 0.8), ("*", 0.5)],
+        ),
+        ("en,, fr;q=0.5 ,", vec![("en", 1.0), ("fr", 0.5)]),
+    ] {
+        assert_eq!(
+            AcceptLanguage::parse_strict(input).unwrap().0,
+            ranges(expected),
+            "{}",
+            input
+        );
+    }
+
+    for (input, error) in &[
+        (
+            "en, en--US",
+            AcceptLanguageError::InvalidRange("en--US".to_string()),
+        ),
+        (
+            "en, ;q=0.5",
+            AcceptLanguageError::InvalidRange(";q=0.5".to_string()),
+        ),
+        (
+            "en;q=0.5555, fr",
+            AcceptLanguageError::InvalidWeight("en;q=0.5555".to_string()),
+        ),
+        (
+            "en, *;q=abc",
+            AcceptLanguageError::InvalidWeight("*;q=abc".to_string()),
+        ),
+    ] {
+        assert_eq!(
+            AcceptLanguage::parse_strict(input).as_ref(),
+            Err(error),
+            "{}",
+            input
+        );
+    }
+    assert_eq!(
+        AcceptLanguageError::InvalidWeight("en;q=2".to_string()).to_string(),
+        "Invalid weight in \"en;q=2\""
+    );
+}
+
+#[test]
+fn test_parse_lenient() {
+    for (input, expected) in &[
+        ("", vec![]),
+        (
+            "en;q=2, fr;q=0.5555, de;q=abc, es;q=-1, it;q=1.5, pl;q=.5, ja",
+            vec![
+                ("en", 1.0),
+                ("it", 1.0),
+                ("ja", 1.0),
+                ("fr", 0.556),
+                ("pl", 0.5),
+                ("es", 0.0),
+            ],
+        ),
+        (
+            "\tde ; q = 0.8 ;level=1, *; Q=0.1",
+            vec![("de", 0.8), ("*", 0.1)],
+        ),
+        ("en--US, 123, ,;q=0.5, x@y", vec![]),
+    ] {
+        assert_eq!(
+            AcceptLanguage::parse_lenient(input).0,
+            ranges(expected),
+            "{}",
+            input
+        );
+    }
+
+    let list = AcceptLanguage::parse_lenient("fr-CH, *;q=0.5, fr;q=0.9");
+    assert_eq!(
+        list.locales()
+            .map(|(locale, q)| (locale.clone(), q))
+            .collect::<Vec<_>>(),
+        entries(&[("fr-CH", 1.0), ("fr", 0.9)])
+    );
+}
+
+#[test]
+fn test_best_from_accept_language() {
+    let available: Vec<Locale> = ["en", "en-GB", "fr", "sr-Latn", "de-u-co-phonebk"]
+        .iter()
+        .map(|l| l.parse().unwrap())
+        .collect();
+    let default: Locale = "und".parse().unwrap();
+
+    for (header, expected) in &[
+        ("", "und"),
+        ("en-GB", "en-GB"),
+        ("en-US", "en"),
+        ("en-GB-oxendict", "en-GB"),
+        ("fr;q=0.5, en-GB;q=0.9", "en-GB"),
+        ("pl, fr-CA;q=0.1", "fr"),
+        ("fr;q=0, pl", "und"),
+        ("sr-Latn-RS", "sr-Latn"),
+        ("sr-Cyrl-RS", "und"),
+        ("de-CH", "de-u-co-phonebk"),
+        ("*;q=0.1, en", "en"),
+        ("*, en;q=0.1", "fr"),
+        ("en, fr;q=0, *;q=0.5", "en"),
+        ("pl, fr;q=0, en;q=0, *;q=0.5", "sr-Latn"),
+        ("de-CH, *;q=0", "de-u-co-phonebk"),
+    ] {
+        assert_eq!(
+            Locale::best_from_accept_language(header, &available, &default).to_string(),
+            *expected,
+            "{}",
+            header
+        );
+    }
+}
+
+#[test]
+fn test_display() {
+    let list = AcceptLanguage::parse("en;q=0.5, *;q=0.1, pl");
+    assert_eq!(list.to_string(), "pl, en;q=0.5, *;q=0.1");
+    assert_eq!(AcceptLanguage::default().to_string(), "");
+}
+
+#[test]
+fn test_negotiate_accept_language() {
+    let available: Vec<Locale> = ["en-US", "fr", "fr-CA", "de-AT", "sr-Latn"]
+        .iter()
+        .map(|l| l.parse().unwrap())
+        .collect();
+    let defaults: Vec<Locale> = ["en-US", "en"].iter().map(|l| l.parse().unwrap()).collect();
+
+    for (header, policy, expected) in &[
+        ("", DefaultsPolicy::OnlyIfEmpty, vec!["en-US", "en"]),
+        ("", DefaultsPolicy::Never, vec![]),
+        (
+            "fr-CA, fr;q=0.8",
+            DefaultsPolicy::OnlyIfEmpty,
+            vec!["fr-CA", "fr"],
+        ),
+        ("fr-CH", DefaultsPolicy::Always, vec!["fr", "en-US", "en"]),
+        (
+            "en-US, de-AT;q=0.5",
+            DefaultsPolicy::Always,
+            vec!["en-US", "de-AT", "en"],
+        ),
+        (
+            "de, *;q=0.5",
+            DefaultsPolicy::Never,
+            vec!["en-US", "fr", "fr-CA", "sr-Latn"],
+        ),
+        ("fr;q=0, pl", DefaultsPolicy::Never, vec![]),
+    ] {
+        assert_eq!(
+            Locale::negotiate_accept_language(header, &available, &defaults, *policy)
+                .iter()
+                .map(|l| l.to_string())
+                .collect::<Vec<_>>(),
+            *expected,
+            "{}",
+            header
+        );
+    }
+
+    let default: Locale = "und".parse().unwrap();
+    for header in &["fr-CH, de;q=0.9", "pl, *;q=0.1", "sr-Latn-RS"] {
+        assert_eq!(
+            Locale::negotiate_accept_language(header, &available, &[], DefaultsPolicy::Never)[0],
+            Locale::best_from_accept_language(header, &available, &default),
+            "{}",
+            header
+        );
+    }
+    assert_eq!(DefaultsPolicy::default(), DefaultsPolicy::OnlyIfEmpty);
+}
+
+#[test]
+fn test_best_from_accept_language_weighted() {
+    let available: Vec<Locale> = ["en", "fr-CA", "sr-Latn", "de-AT", "pt-BR"]
+        .iter()
+        .map(|l| l.parse().unwrap())
+        .collect();
+    let default: Locale = "und".parse().unwrap();
+
+    for (header, expected) in &[
+        ("", "und"),
+        ("fr;q=0.9, en;q=0.8", "fr-CA"),
+        ("fr-FR;q=0.9, en;q=0.8", "en"),
+        ("fr-FR, en;q=0.8", "fr-CA"),
+        ("sr-Cyrl, pl", "und"),
+        ("sr", "sr-Latn"),
+        ("de-AT;q=0.5, de-DE", "de-AT"),
+        ("pt-PT;q=0, pl", "und"),
+        ("en;q=0.5, *;q=0.6", "fr-CA"),
+    ] {
+        assert_eq!(
+            Locale::best_from_accept_language_weighted(
+                header,
+                &available,
+                &default,
+                ScoreWeighting::default()
+            )
+            .to_string(),
+            *expected,
+            "{}",
+            header
+        );
+    }
+
+    let weighting = ScoreWeighting {
+        distance_factor: 0.0,
+    };
+    assert_eq!(
+        Locale::best_from_accept_language_weighted(
+            "fr-FR;q=0.9, en;q=0.8",
+            &available,
+            &default,
+            weighting
+        )
+        .to_string(),
+        "fr-CA"
+    );
+}
+
+#[test]
+fn test_best_from_accept_language_prioritized() {
+    let available: Vec<(Locale, f32)> = [
+        ("en", 1.0),
+        ("fr-CA", 0.5),
+        ("fr", 0.9),
+        ("de-AT", 1.0),
+        ("pt-BR", 0.2),
+    ]
+    .iter()
+    .map(|(l, p)| (l.parse().unwrap(), *p))
+    .collect();
+    let default: Locale = "und".parse().unwrap();
+
+    for (header, expected) in &[
+        ("", "und"),
+        ("fr-CA, en;q=0.5", "fr"),
+        ("fr-CA, en;q=0.4", "fr"),
+        ("fr-CA;q=0.5, en", "en"),
+        ("pt-BR, de;q=0.5", "de-AT"),
+        ("pt-BR, pl", "pt-BR"),
+    ] {
+        assert_eq!(
+            Locale::best_from_accept_language_prioritized(
+                header,
+                &available,
+                &default,
+                ScoreWeighting::default()
+            )
+            .to_string(),
+            *expected,
+            "{}",
+            header
+        );
+    }
+
+    let equal: Vec<(Locale, f32)> = available.iter().map(|(l, _)| (l.clone(), 1.0)).collect();
+    let locales: Vec<Locale> = available.iter().map(|(l, _)| l.clone()).collect();
+    for header in &["fr-CA, en;q=0.5", "pt-BR, de;q=0.5", "*"] {
+        assert_eq!(
+            Locale::best_from_accept_language_prioritized(
+                header,
+                &equal,
+                &default,
+                ScoreWeighting::default()
+            ),
+            Locale::best_from_accept_language_weighted(
+                header,
+                &locales,
+                &default,
+                ScoreWeighting::default()
+            ),
+            "{}",
+            header
+        );
+    }
+}
+
+#[test]
+fn test_best_from_accept_language_traced() {
+    let available: Vec<Locale> = ["en", "fr-CA", "sr-Latn", "de-AT", "pt-BR"]
+        .iter()
+        .map(|l| l.parse().unwrap())
+        .collect();
+    let default: Locale = "und".parse().unwrap();
+
+    for (header, _) in &[
+        ("", "und"),
+        ("fr;q=0.9, en;q=0.8", "fr-CA"),
+        ("sr-Cyrl, pl", "und"),
+        ("en;q=0.5, *;q=0.6", "fr-CA"),
+    ] {
+        let (best, trace) = Locale::best_from_accept_language_traced(
+            header,
+            &available,
+            &default,
+            ScoreWeighting::default(),
+        );
+        assert_eq!(
+            best,
+            Locale::best_from_accept_language_weighted(
+                header,
+                &available,
+                &default,
+                ScoreWeighting::default()
+            ),
+            "{}",
+            header
+        );
+        assert_eq!(trace.chosen.unwrap_or(&default), best, "{}", header);
+    }
+
+    let (best, trace) = Locale::best_from_accept_language_traced(
+        "de-DE, sr-Cyrl;q=0.9, *;q=0.5, pt;q=0",
+        &available,
+        &default,
+        ScoreWeighting::default(),
+    );
+    assert_eq!(best.to_string(), "de-AT");
+    assert_eq!(trace.entries.len(), 4 * available.len());
+
+    let outcomes = |locale: &Locale| {
+        trace
+            .entries_for(locale)
+            .map(|entry| (entry.range.to_string(), entry.outcome))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        outcomes(&available[4]),
+        vec![
+            (
+                "de-DE".to_string(),
+                Outcome::Rejected(Rejection::LanguageMismatch)
+            ),
+            (
+                "sr-Cyrl".to_string(),
+                Outcome::Rejected(Rejection::LanguageMismatch)
+            ),
+            (
+                "*".to_string(),
+                Outcome::Rejected(Rejection::ExplicitlyRequested)
+            ),
+            ("pt".to_string(), Outcome::Rejected(Rejection::ZeroWeight)),
+        ]
+    );
+    assert_eq!(
+        outcomes(&available[2])[1].1,
+        Outcome::Rejected(Rejection::ScriptMismatch)
+    );
+    assert_eq!(
+        outcomes(&available[3])[0].1,
+        Outcome::Scored {
+            distance: 0.3,
+            score: 0.85
+        }
+    );
+    assert_eq!(
+        outcomes(&available[0])[2].1,
+        Outcome::Scored {
+            distance: 0.0,
+            score: 0.5
+        }
+    );
+    assert_eq!(Rejection::ScriptMismatch.to_string(), "the script differs");
+}
+
+#[test]
+fn test_available_locales() {
+    let available: Vec<Locale> = ["en-US", "fr", "de-AT", "de-AT", "sr-Latn"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    let default = &available[0];
+    let index = AvailableLocales::new(&available);
+
+    for header in &[
+        "fr-CH, de;q=0.9",
+        "de-AT-1996",
+        "sr-Latn-RS, en-US;q=0.5",
+        "pl, *;q=0.1",
+        "*, en-US;q=0.1, fr;q=0",
+        "",
+        "pl",
+    ] {
+        let expected = Locale::best_from_accept_language(header, &available, default);
+        let best = index.best(header, default);
+        assert_eq!(best, expected, "{}", header);
+        assert!(std::ptr::eq(best, expected), "{}", header);
+    }
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_available_locales_par_best() {
+    let available: Vec<Locale> = ["en", "fr", "de"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+    let index = AvailableLocales::new(&available);
+
+    let headers: Vec<String> = (0..100)
+        .map(|i| ["fr-CA", "de;q=0.5, pl", "pl"][i % 3].to_string())
+        .collect();
+    let best = index.par_best(&headers, &available[0]);
+    assert_eq!(best.len(), headers.len());
+    for (header, best) in headers.iter().zip(best) {
+        assert_eq!(best, index.best(header, &available[0]), "{}", header);
+    }
+}
+
+#[test]
+fn test_language_priority_list() {
+    let mut list = LanguagePriorityList::from_locales(
+        entries(&[("sr-Latn", 1.0), ("sr-Cyrl", 1.0), ("en-GB", 1.0)])
+            .into_iter()
+            .map(|(locale, _)| locale),
+    );
+    list.push(LanguageRange::Wildcard, 0.1);
+    list.push("en-US".parse::<Locale>().unwrap(), 1.5);
+    list.push("de".parse::<Locale>().unwrap(), f32::NAN);
+    assert_eq!(
+        list.iter()
+            .map(|(range, q)| (range.clone(), q))
+            .collect::<Vec<_>>(),
+        ranges(&[
+            ("sr-Latn", 1.0),
+            ("sr-Cyrl", 1.0),
+            ("en-GB", 1.0),
+            ("en-US", 1.0),
+            ("*", 0.1),
+            ("de", 0.0),
+        ])
+    );
+
+    let available: Vec<Locale> = entries(&[("en-US", 1.0), ("fr", 1.0), ("sr-Cyrl", 1.0)])
+        .into_iter()
+        .map(|(locale, _)| locale)
+        .collect();
+    assert_eq!(list.best(&available, &available[1]).to_string(), "sr-Cyrl");
+    assert_eq!(
+        list.negotiate(&available, &[], DefaultsPolicy::Never)
+            .iter()
+            .map(|l| l.to_string())
+            .collect::<Vec<_>>(),
+        vec!["sr-Cyrl", "en-US", "fr"]
+    );
+
+    list.dedup_by_language();
+    assert_eq!(list.to_string(), "sr-Latn, en-GB, *;q=0.1, de;q=0");
+    assert_eq!(list.best(&available, &available[1]).to_string(), "en-US");
+    list.truncate(1);
+    assert_eq!(list.len(), 1);
+    assert_eq!(list.best(&available, &available[1]).to_string(), "fr");
+
+    let header = "de-AT, de;q=0.8, en;q=0.5";
+    let list = LanguagePriorityList::from(AcceptLanguage::parse(header));
+    assert_eq!(AcceptLanguage::from(list.clone()).to_string(), header);
+    assert!(LanguagePriorityList::new().is_empty());
+}
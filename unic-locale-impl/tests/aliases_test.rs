@@ -0,0 +1,73 @@
+use unic_locale_impl::{canonicalize_with_mode, CanonicalizationMode, Locale};
+
+#[test]
+fn test_canonicalize_aliases() {
+    for (input, expected) in &[
+        ("iw-IL", "he-IL"),
+        ("in-u-nu-latn", "id-u-nu-latn"),
+        ("ji-US-x-foo", "yi-US-x-foo"),
+        ("sh-BA-u-ca-gregory", "sr-Latn-BA-u-ca-gregory"),
+        ("tl-PH", "fil-PH"),
+        ("my-BU", "my-MM"),
+        ("en-US", "en-US"),
+    ] {
+        let mut loc: Locale = input.parse().unwrap();
+        assert_eq!(loc.canonicalize_aliases(), input != expected, "{}", input);
+        assert_eq!(loc.to_string(), *expected, "{}", input);
+    }
+
+    // Two spellings of the same locale compare equal once canonicalized.
+    let mut first: Locale = "mo-MD".parse().unwrap();
+    let mut second: Locale = "ro-MD".parse().unwrap();
+    assert_ne!(first, second);
+    first.canonicalize_aliases();
+    second.canonicalize_aliases();
+    assert_eq!(first, second);
+}
+
+#[test]
+fn test_canonicalize_with_mode() {
+    for (input, syntax, aliases) in &[
+        (
+            "iw_il-U-CA-HEBREW",
+            Some("iw-IL-u-ca-hebrew"),
+            "he-IL-u-ca-hebrew",
+        ),
+        ("i-klingon", None, "tlh"),
+        ("I_NAVAJO", None, "nv"),
+        ("zh-min-nan", None, "nan"),
+        ("en-GB-oed", None, "en-GB-oxendict"),
+        ("art-lojban", Some("art-lojban"), "jbo"),
+        ("en-UK", Some("en-UK"), "en-GB"),
+        ("de-DE", Some("de-DE"), "de-DE"),
+    ] {
+        assert_eq!(
+            canonicalize_with_mode(input, CanonicalizationMode::Syntax).ok(),
+            syntax.map(String::from),
+            "{}",
+            input
+        );
+        assert_eq!(
+            canonicalize_with_mode(input, CanonicalizationMode::Aliases),
+            Ok(aliases.to_string()),
+            "{}",
+            input
+        );
+    }
+
+    // A region split into several is replaced with the likely one of the language.
+    #[cfg(feature = "likelysubtags")]
+    let expected = "hy-AM";
+    #[cfg(not(feature = "likelysubtags"))]
+    let expected = "hy-RU";
+    assert_eq!(
+        canonicalize_with_mode("hy-SU", CanonicalizationMode::Aliases),
+        Ok(expected.to_string())
+    );
+
+    assert!(canonicalize_with_mode("i-foo", CanonicalizationMode::Aliases).is_err());
+    assert_eq!(
+        CanonicalizationMode::default(),
+        CanonicalizationMode::Syntax
+    );
+}
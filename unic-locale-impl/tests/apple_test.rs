@@ -0,0 +1,52 @@
+use unic_locale_impl::Locale;
+
+#[test]
+fn test_from_apple_identifier() {
+    for (input, expected) in &[
+        ("en_US", "en-US"),
+        ("zh_Hant_TW", "zh-Hant-TW"),
+        ("th_TH@calendar=buddhist", "th-TH-u-ca-buddhist"),
+        (
+            "ar_EG@numbers=latn;calendar=islamic-civil",
+            "ar-EG-u-ca-islamic-civil-nu-latn",
+        ),
+        ("de_DE@collation=phonebook", "de-DE-u-co-phonebk"),
+        ("en_US@currency=EUR", "en-US-u-cu-eur"),
+        ("en_US@Calendar=Gregorian", "en-US-u-ca-gregory"),
+        ("am_ET@calendar=ethiopic-amete-alem", "am-ET-u-ca-ethioaa"),
+    ] {
+        let loc = Locale::from_apple_identifier(input).unwrap();
+        assert_eq!(loc.to_string(), *expected, "{}", input);
+    }
+
+    assert!(Locale::from_apple_identifier("en_US@hours=h12").is_err());
+    assert!(Locale::from_apple_identifier("en_US@calendar").is_err());
+    assert!(Locale::from_apple_identifier("en_US@calendar=").is_err());
+}
+
+#[test]
+fn test_to_apple_identifier() {
+    for (input, expected) in &[
+        ("en-US", "en_US"),
+        ("sr-Latn-RS", "sr_Latn_RS"),
+        (
+            "th-TH-u-nu-thai-ca-buddhist",
+            "th_TH@calendar=buddhist;numbers=thai",
+        ),
+        ("en-US-u-cu-eur-hc-h12-x-foo", "en_US@currency=eur"),
+        ("ar-u-ca-islamic-civil", "ar@calendar=islamic-civil"),
+        (
+            "es-ES-u-co-trad-ca-gregory",
+            "es_ES@calendar=gregorian;collation=traditional",
+        ),
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(loc.to_apple_identifier(), *expected, "{}", input);
+    }
+
+    let loc: Locale = "ja-JP-u-ca-japanese-co-unihan-nu-jpan".parse().unwrap();
+    assert_eq!(
+        Locale::from_apple_identifier(&loc.to_apple_identifier()).unwrap(),
+        loc
+    );
+}
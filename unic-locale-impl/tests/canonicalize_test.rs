@@ -15,10 +15,10 @@ fn test_canonicalize_langid() {
 fn test_canonicalize_locale() {
     assert_canonicalize("pl-U-HC-H12", "pl-u-hc-h12");
     assert_canonicalize("eN-uS-X_Private", "en-US-x-private");
-    assert_canonicalize("eN-uS-t-pl-PL", "en-US-t-pl-PL");
+    assert_canonicalize("eN-uS-t-pl-PL", "en-US-t-pl-pl");
     assert_canonicalize(
         "en-US-t-ES-ar-u-CA-Buddhist-x-foo",
-        "en-US-t-es-AR-u-ca-buddhist-x-foo",
+        "en-US-t-es-ar-u-ca-buddhist-x-foo",
     );
     assert_canonicalize(
         "fr-t-t0-windows-h0-hybrid-k0-googlevk-extended",
@@ -28,7 +28,82 @@ fn test_canonicalize_locale() {
         "en-u-foo-bar-nu-thai-ca-buddhist-kk-true",
         "en-u-bar-foo-ca-buddhist-kk-nu-thai",
     );
-    assert_canonicalize("en-US-u-foo-t-es-AR-x-bar", "en-US-t-es-AR-u-foo-x-bar");
+    assert_canonicalize("en-US-u-foo-t-es-AR-x-bar", "en-US-t-es-ar-u-foo-x-bar");
+    assert_canonicalize("ja-t-IT-latn-h0-hybrid", "ja-t-it-latn-h0-hybrid");
     assert_canonicalize("de-u-kn-true", "de-u-kn");
     assert_canonicalize("fr-t-t0-windows-True", "fr-t-t0-windows");
 }
+
+#[test]
+fn test_canonicalize_deprecated_keywords() {
+    assert_canonicalize("en-u-ca-islamicc", "en-u-ca-islamic-civil");
+    assert_canonicalize("am-u-ca-ethiopic-amete-alem", "am-u-ca-ethioaa");
+    assert_canonicalize("en-GB-u-ms-imperial", "en-GB-u-ms-uksystem");
+    assert_canonicalize("de-u-co-phonebk-ks-primary", "de-u-co-phonebk-ks-level1");
+    assert_canonicalize("de-u-kn-yes", "de-u-kn");
+    assert_canonicalize("zh-u-tz-cnckg", "zh-u-tz-cnsha");
+    assert_canonicalize("en-u-tz-pst8pdt", "en-u-tz-uslax");
+    assert_canonicalize("ja-u-kh-vt-0061-co-unihan", "ja-u-co-unihan");
+    assert_canonicalize("en-u-tz-uslax", "en-u-tz-uslax");
+}
+
+#[test]
+fn test_canonicalize_with_report() {
+    use unic_locale_impl::{canonicalize_with_report, Transformation};
+
+    for input in &[
+        "pL_latn_pl-U-HC-H12",
+        "en-u-foo-bar-nu-thai-ca-buddhist-kk-true",
+        "ja-u-kh-vt-0061-co-unihan",
+        "x-Foo",
+    ] {
+        let (loc, _) = canonicalize_with_report(input).unwrap();
+        assert_eq!(loc.to_string(), canonicalize(input).unwrap(), "{}", input);
+    }
+
+    for (input, expected) in &[
+        ("en-US-u-ca-buddhist", vec![]),
+        (
+            "EN_us",
+            vec![
+                Transformation::SeparatorNormalized,
+                Transformation::CasingFixed,
+            ],
+        ),
+        ("de-u-kn-true", vec![Transformation::ExtensionsReordered]),
+        (
+            "de-DE-1996-1901-U-CO-phonebk",
+            vec![
+                Transformation::CasingFixed,
+                Transformation::ExtensionsReordered,
+            ],
+        ),
+        (
+            "ja-u-co-unihan-vt-0061",
+            vec![Transformation::DeprecatedKeywordRemoved {
+                keyword: "vt-0061".to_string(),
+            }],
+        ),
+        (
+            "zh-u-tz-cnckg",
+            vec![Transformation::AliasReplaced {
+                from: "tz-cnckg".to_string(),
+                to: "tz-cnsha".to_string(),
+            }],
+        ),
+    ] {
+        let (_, report) = canonicalize_with_report(input).unwrap();
+        assert_eq!(report, *expected, "{}", input);
+    }
+
+    #[cfg(feature = "iso639")]
+    assert_eq!(
+        canonicalize_with_report("eng-US").unwrap().1,
+        vec![Transformation::AliasReplaced {
+            from: "eng".to_string(),
+            to: "en".to_string(),
+        }]
+    );
+
+    assert!(canonicalize_with_report("e").is_err());
+}
@@ -0,0 +1,42 @@
+use unic_locale_impl::currency::currency_for_region;
+use unic_locale_impl::Locale;
+
+#[test]
+fn test_currency_for_region() {
+    for (region, expected) in &[
+        ("US", Some("USD")),
+        ("de", Some("EUR")),
+        ("HR", Some("EUR")),
+        ("ZW", Some("ZWG")),
+        ("AQ", None),
+        ("419", None),
+    ] {
+        assert_eq!(
+            currency_for_region(region.parse().unwrap()),
+            *expected,
+            "{}",
+            region
+        );
+    }
+}
+
+#[test]
+fn test_resolve_currency() {
+    for (input, expected) in &[
+        ("en-US", Some("USD")),
+        ("fr-CH", Some("CHF")),
+        ("en-US-u-cu-jpy", Some("JPY")),
+        ("en-US-u-rg-gbzzzz", Some("GBP")),
+        ("en-US-u-cu-eur-rg-gbzzzz", Some("EUR")),
+        ("en-AQ", None),
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(loc.resolve_currency().as_deref(), *expected, "{}", input);
+    }
+
+    let loc: Locale = "ja".parse().unwrap();
+    #[cfg(feature = "likelysubtags")]
+    assert_eq!(loc.resolve_currency().as_deref(), Some("JPY"));
+    #[cfg(not(feature = "likelysubtags"))]
+    assert_eq!(loc.resolve_currency(), None);
+}
@@ -0,0 +1,46 @@
+use unic_locale_impl::parser::{parse_locale_with_options, EmptyInputPolicy, ParserOptions};
+use unic_locale_impl::{default_locale, set_default_locale, Locale, PosixOptions};
+
+// The default locale is process-wide, so all the steps share a single test.
+#[test]
+fn test_default_locale() {
+    std::env::remove_var("LC_ALL");
+    std::env::remove_var("LC_MESSAGES");
+
+    std::env::set_var("LANG", "C.UTF-8");
+    assert_eq!(Locale::current(), Locale::default());
+    let posix = PosixOptions {
+        c_locale: "en-US-posix".parse().unwrap(),
+    };
+    assert_eq!(Locale::current_with_options(&posix), posix.c_locale);
+
+    std::env::set_var("LANG", "de_AT.UTF-8@euro");
+    #[cfg(feature = "extensions")]
+    assert_eq!(Locale::current().to_string(), "de-AT-u-cu-eur");
+    #[cfg(not(feature = "extensions"))]
+    assert_eq!(Locale::current().to_string(), "de-AT");
+
+    std::env::set_var("LC_MESSAGES", "sr_RS@latin");
+    assert_eq!(Locale::current().to_string(), "sr-Latn-RS");
+
+    std::env::set_var("LC_ALL", "pl_PL");
+    assert_eq!(Locale::current().to_string(), "pl-PL");
+
+    assert_eq!(default_locale(), None);
+
+    let options = ParserOptions::default().empty_input(EmptyInputPolicy::DefaultLocale);
+    assert_eq!(
+        parse_locale_with_options("", options),
+        Ok(Locale::default())
+    );
+
+    let loc: Locale = "fr-CA".parse().unwrap();
+    assert_eq!(set_default_locale(loc.clone()), Ok(()));
+    assert_eq!(default_locale(), Some(&loc));
+    assert_eq!(Locale::current(), loc);
+    assert_eq!(parse_locale_with_options("", options), Ok(loc.clone()));
+
+    let other: Locale = "es".parse().unwrap();
+    assert_eq!(set_default_locale(other.clone()), Err(other));
+    assert_eq!(Locale::current(), loc);
+}
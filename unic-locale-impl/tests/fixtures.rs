@@ -1,11 +1,14 @@
 use std::collections::HashMap;
 use std::convert::TryInto;
 use std::error::Error;
-use std::fs::File;
+use std::fs::{self, File};
+use std::ops::ControlFlow;
 use std::path::Path;
 
 use unic_langid_impl::LanguageIdentifier;
-use unic_locale_impl::{ExtensionType, ExtensionsMap, Locale};
+use unic_locale_impl::extensions::PrivateSubtagOrder;
+use unic_locale_impl::parser::{parse_locale, parse_with_visitor};
+use unic_locale_impl::{ExtensionType, ExtensionsMap, Locale, Subtag};
 
 use serde::{Deserialize, Serialize};
 
@@ -47,7 +50,7 @@ fn read_locale_testsets<P: AsRef<Path>>(path: P) -> Result<Vec<LocaleTestSet>, B
 fn create_extensions_map(map: HashMap<String, HashMap<String, String>>) -> ExtensionsMap {
     let mut result = ExtensionsMap::default();
     for (key, map) in map {
-        let t: ExtensionType = ExtensionType::from_byte(key.chars().nth(0).unwrap() as u8)
+        let t: ExtensionType = ExtensionType::from_byte(key.chars().next().unwrap() as u8)
             .expect("Failed to format extension type.");
         match t {
             ExtensionType::Unicode => {
@@ -69,6 +72,7 @@ fn create_extensions_map(map: HashMap<String, HashMap<String, String>>) -> Exten
                 }
             }
             ExtensionType::Private => {
+                result.private.set_order(PrivateSubtagOrder::Canonical);
                 for (key, _) in map {
                     result
                         .private
@@ -104,6 +108,11 @@ fn test_locale_fixtures(path: &str) {
 
         match test.output {
             LocaleTestOutput::Object(o) => {
+                // Fixtures store private use subtags as a set.
+                locale
+                    .extensions
+                    .private
+                    .set_order(PrivateSubtagOrder::Canonical);
                 let expected = Locale::from_parts(
                     o.language.try_into().unwrap(),
                     o.script.as_ref().map(|s| s.parse().unwrap()),
@@ -133,3 +142,77 @@ fn parse() {
 fn serialize() {
     test_locale_fixtures("./tests/fixtures/serialize.json");
 }
+
+fn assert_visitor_matches_parser(input: &str) {
+    let mut id = LanguageIdentifier::default();
+    let mut variants = vec![];
+    let visited = parse_with_visitor(input, &mut |subtag| {
+        match subtag {
+            Subtag::Language(language) => id.language = language,
+            Subtag::Script(script) => id.script = Some(script),
+            Subtag::Region(region) => id.region = Some(region),
+            Subtag::Variant(variant) => variants.push(variant),
+            _ => {}
+        }
+        ControlFlow::Continue(())
+    });
+    id.set_variants(&variants);
+
+    match parse_locale(input) {
+        Ok(locale) => {
+            assert_eq!(visited, Ok(()), "{}", input);
+            assert_eq!(id, locale.id, "{}", input);
+        }
+        Err(error) => assert_eq!(visited, Err(error), "{}", input),
+    }
+}
+
+#[test]
+fn visitor() {
+    for path in &[
+        "./tests/fixtures/parsing.json",
+        "./tests/fixtures/serialize.json",
+    ] {
+        for test in read_locale_testsets(path).unwrap() {
+            assert_visitor_matches_parser(&test.input.string);
+        }
+    }
+
+    let corpus = fs::read_to_string("./tests/fixtures/corpus.txt").unwrap();
+    for line in corpus
+        .lines()
+        .filter(|l| !l.is_empty() && !l.starts_with('#'))
+    {
+        assert_visitor_matches_parser(line);
+    }
+
+    for input in &[
+        "",
+        "en-",
+        "en--US",
+        "en-US-",
+        "-en",
+        "x",
+        "x-foo",
+        "en-x",
+        "en-u",
+        "en-u-ca",
+        "en-u-ca-",
+        "en-u-a1-buddhist",
+        "en-u-ca-toolongvalue",
+        "en-u-ab-u-cd",
+        "en-t-h0",
+        "en-t-h0-hy",
+        "en-t-en-fr",
+        "en-t-en-$$$-h0-hybrid",
+        "en-t-abcd-h0-hybrid",
+        "en-a",
+        "en-a-b",
+        "en-a-ba$",
+        "en-x-abcdefghi",
+        "en-$",
+        "en-US-foobarbazqux",
+    ] {
+        assert_visitor_matches_parser(input);
+    }
+}
@@ -0,0 +1,37 @@
+use http::HeaderValue;
+use std::convert::TryFrom;
+use unic_locale_impl::accept_language::AcceptLanguage;
+use unic_locale_impl::Locale;
+
+#[test]
+fn test_accept_language_from_header_value() {
+    let header = HeaderValue::from_static("fr-CH, fr;q=0.9, en;q=0.8, *;q=0.5");
+    let list = AcceptLanguage::try_from(&header).unwrap();
+    assert_eq!(
+        list.0
+            .iter()
+            .map(|(l, q)| (l.to_string(), *q))
+            .collect::<Vec<_>>(),
+        &[
+            ("fr-CH".to_string(), 1.0),
+            ("fr".to_string(), 0.9),
+            ("en".to_string(), 0.8),
+            ("*".to_string(), 0.5)
+        ]
+    );
+
+    let header = HeaderValue::from_bytes(b"fr-CH, \xff").unwrap();
+    assert!(AcceptLanguage::try_from(&header).is_err());
+}
+
+#[test]
+fn test_header_value_from_accept_language() {
+    let list = AcceptLanguage::parse("en;q=0.5, pl");
+    assert_eq!(HeaderValue::try_from(&list).unwrap(), "pl, en;q=0.5");
+}
+
+#[test]
+fn test_header_value_from_locale() {
+    let loc: Locale = "sr-Latn-RS-u-nu-latn".parse().unwrap();
+    assert_eq!(HeaderValue::try_from(&loc).unwrap(), "sr-Latn-RS-u-nu-latn");
+}
@@ -0,0 +1,90 @@
+use unic_locale_impl::Locale;
+
+#[test]
+fn test_to_java_string() {
+    for (input, expected) in &[
+        ("und", ""),
+        ("en", "en"),
+        ("de-DE", "de_DE"),
+        ("und-GB", "_GB"),
+        ("en-US-posix", "en_US_posix"),
+        ("de-posix", "de__posix"),
+        ("sl-IT-rozaj-nedis", "sl_IT_nedis_rozaj"),
+        ("zh-Hans-CN", "zh_CN_#Hans"),
+        ("zh-Hant", "zh__#Hant"),
+        ("zh-Hant-TW-x-java", "zh_TW_#Hant_x-java"),
+        ("th-TH-u-nu-thai", "th_TH_#u-nu-thai"),
+        ("ja-JP-u-ca-japanese-x-foo", "ja_JP_#u-ca-japanese-x-foo"),
+        ("en-t-es-u-hc-h12", "en__#t-es-u-hc-h12"),
+        ("und-Latn", ""),
+        ("und-u-nu-thai", ""),
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(&loc.to_java_string(), expected, "{}", input);
+    }
+}
+
+#[test]
+fn test_to_java_string_lvariant() {
+    for (input, expected) in &[
+        ("th-TH-u-nu-thai-x-lvariant-th", "th_TH_th_#u-nu-thai"),
+        (
+            "ja-JP-u-ca-japanese-x-lvariant-jp",
+            "ja_JP_jp_#u-ca-japanese",
+        ),
+        ("de-x-lvariant-posix", "de__posix"),
+        (
+            "en-US-posix-x-foo-lvariant-abc-def",
+            "en_US_posix_abc_def_#x-foo",
+        ),
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(&loc.to_java_string(), expected, "{}", input);
+    }
+}
+
+#[test]
+fn test_from_java_string() {
+    for (input, expected) in &[
+        ("", "und"),
+        ("en", "en"),
+        ("de_DE", "de-DE"),
+        ("_GB", "und-GB"),
+        ("en_US_posix", "en-US-posix"),
+        ("de__posix", "de-posix"),
+        ("sl_IT_nedis_rozaj", "sl-IT-nedis-rozaj"),
+        ("zh_CN_#Hans", "zh-Hans-CN"),
+        ("zh__#Hant", "zh-Hant"),
+        ("zh_TW_#Hant_x-java", "zh-Hant-TW-x-java"),
+        ("th_TH_#u-nu-thai", "th-TH-u-nu-thai"),
+        ("en__#t-es-u-hc-h12", "en-t-es-u-hc-h12"),
+        ("th_TH_TH_#u-nu-thai", "th-TH-u-nu-thai-x-lvariant-th"),
+        (
+            "th_TH_#u-nu-thai_x-lvariant-TH",
+            "th-TH-u-nu-thai-x-lvariant-th",
+        ),
+        ("ja_JP_JP", "ja-JP-x-lvariant-jp"),
+        ("en_US_posix_ab_cd", "en-US-posix-x-lvariant-ab-cd"),
+        ("en_US_ab_posix_#x-foo", "en-US-x-foo-lvariant-ab-posix"),
+    ] {
+        let loc = Locale::from_java_string(input).unwrap();
+        assert_eq!(loc.to_string(), *expected, "{}", input);
+    }
+
+    assert!(Locale::from_java_string("e_US").is_err());
+    assert!(Locale::from_java_string("en_US_#Latn_u-c").is_err());
+}
+
+#[test]
+fn test_java_string_roundtrip() {
+    for input in &[
+        "en_US",
+        "zh_TW_#Hant",
+        "th_TH_th_#u-nu-thai",
+        "en_US_posix_abc_def_#x-foo",
+        "sr_RS_#Latn_u-nu-latn",
+    ] {
+        let loc = Locale::from_java_string(input).unwrap();
+        assert_eq!(&loc.to_java_string(), input);
+    }
+}
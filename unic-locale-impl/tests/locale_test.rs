@@ -1,6 +1,22 @@
+#![allow(clippy::bool_assert_comparison)]
+
+use std::convert::TryFrom;
+use std::ops::ControlFlow;
 use unic_langid_impl::LanguageIdentifier;
-use unic_locale_impl::parser::parse_locale;
-use unic_locale_impl::{CharacterDirection, ExtensionsMap, Locale};
+use unic_locale_impl::extensions::{
+    KeywordValues, PrivateSubtagOrder, SharedExtensionsMap, UnicodeExtensionKey,
+};
+use unic_locale_impl::parser::{
+    parse_extension_units, parse_langid_only, parse_locale, parse_locale_spanned,
+    parse_locale_with_options, parse_locale_with_original, parse_with_visitor, Component,
+    EmptyInputPolicy, InvalidSubtagReason, ParserError, ParserOptions,
+};
+#[cfg(feature = "character_direction")]
+use unic_locale_impl::CharacterDirection;
+use unic_locale_impl::{
+    canonicalize, data_version, ExtensionType, ExtensionsMap, Locale, LocaleInterner, PseudoLocale,
+    RawLocaleBuf, SharedLocale, StableDecodingError, Subtag,
+};
 
 fn assert_locale_extensions(loc: &Locale, extensions: &ExtensionsMap) {
     assert_eq!(&loc.extensions, extensions);
@@ -109,10 +125,21 @@ fn test_serialize_locale() {
 #[test]
 fn test_from_langid() {
     let langid: LanguageIdentifier = "en-US".parse().unwrap();
+    let loc = Locale::from(&langid);
+    assert_eq!(&loc.to_string(), "en-US");
+    assert_eq!(loc.id, langid);
+
     let loc = Locale::from(langid);
     assert_eq!(&loc.to_string(), "en-US");
 }
 
+#[test]
+fn test_into_string() {
+    let loc: Locale = "EN-latn-us-u-NU-thai".parse().unwrap();
+    let s: String = loc.into();
+    assert_eq!(s, "en-Latn-US-u-nu-thai");
+}
+
 #[test]
 fn test_to_langid() {
     let loc: Locale = "en-US-u-hc-h12".parse().unwrap();
@@ -120,6 +147,138 @@ fn test_to_langid() {
     assert_eq!(langid.to_string(), "en-US");
 }
 
+#[test]
+fn test_set_langid() {
+    let mut loc = parse_locale("EN-us-u-ca-buddhist-x-foo").unwrap();
+    let extensions = loc.extensions.clone();
+
+    let previous = loc.set_langid("sr-Cyrl-RS".parse().unwrap());
+    assert_eq!(previous.to_string(), "en-US");
+    assert_eq!(loc.to_string(), "sr-Cyrl-RS-u-ca-buddhist-x-foo");
+    assert_eq!(loc.extensions, extensions);
+
+    loc.set_langid(LanguageIdentifier::default());
+    assert_eq!(loc.to_string(), "und-u-ca-buddhist-x-foo");
+}
+
+#[test]
+fn test_locale_interner() {
+    let mut interner = LocaleInterner::new();
+    assert!(interner.is_empty());
+
+    let handles: Vec<_> = ["en-US", "fr", "en_us", "fr-u-ca-buddhist", "FR"]
+        .iter()
+        .map(|s| interner.intern_str(s).unwrap())
+        .collect();
+    assert_eq!(handles[0], handles[2]);
+    assert_eq!(handles[1], handles[4]);
+    assert_ne!(handles[1], handles[3]);
+    assert_eq!(interner.len(), 3);
+    assert_eq!(
+        handles.iter().map(|h| h.index()).collect::<Vec<_>>(),
+        vec![0, 1, 0, 2, 1]
+    );
+
+    assert_eq!(interner[handles[3]].to_string(), "fr-u-ca-buddhist");
+    assert_eq!(
+        interner
+            .resolve(handles[0])
+            .map(|l| l.to_string())
+            .as_deref(),
+        Some("en-US")
+    );
+    assert_eq!(interner.get(&"de".parse().unwrap()), None);
+    assert!(interner.intern_str("e").is_err());
+
+    interner.extend(vec![
+        "de".parse::<LanguageIdentifier>().unwrap(),
+        "en-US".parse().unwrap(),
+    ]);
+    assert_eq!(
+        interner
+            .iter()
+            .map(|(h, l)| (h.index(), l.to_string()))
+            .collect::<Vec<_>>(),
+        vec![
+            (0, "en-US".to_string()),
+            (1, "fr".to_string()),
+            (2, "fr-u-ca-buddhist".to_string()),
+            (3, "de".to_string()),
+        ]
+    );
+
+    let other = LocaleInterner::new();
+    assert_eq!(other.resolve(handles[0]), None);
+}
+
+#[test]
+fn test_shared_locale() {
+    let loc: Locale = "sr-Latn-RS-t-h0-hybrid-u-ca-gregory-nu-latn-x-foo"
+        .parse()
+        .unwrap();
+    let shared = SharedLocale::from(&loc);
+    assert_eq!(shared.to_string(), loc.to_string());
+
+    let mut copy = shared.clone();
+    assert!(SharedExtensionsMap::ptr_eq(
+        &shared.extensions,
+        &copy.extensions
+    ));
+    assert_eq!(copy.extensions.unicode.keyword("ca").unwrap().count(), 1);
+
+    copy.extensions.make_mut().private.clear_tags();
+    assert!(!SharedExtensionsMap::ptr_eq(
+        &shared.extensions,
+        &copy.extensions
+    ));
+    assert_eq!(shared.to_string(), loc.to_string());
+    assert_eq!(
+        copy.to_string(),
+        "sr-Latn-RS-t-h0-hybrid-u-ca-gregory-nu-latn"
+    );
+
+    assert_eq!(Locale::from(&shared), loc);
+    assert_eq!(Locale::from(shared), loc);
+    assert_eq!(
+        "EN-us-u-HC-h12"
+            .parse::<SharedLocale>()
+            .unwrap()
+            .to_string(),
+        "en-US-u-hc-h12"
+    );
+    assert!("en-US-u".parse::<SharedLocale>().is_err());
+}
+
+#[test]
+fn test_map_extensions() {
+    let loc = parse_locale("sr-Latn-u-nu-latn-x-foo").unwrap();
+
+    let loc = loc.map_extensions(|mut extensions| {
+        extensions.unicode.remove_keyword("nu").unwrap();
+        extensions.transform.set_tfield("h0", &["hybrid"]).unwrap();
+        extensions
+    });
+    assert_eq!(loc.to_string(), "sr-Latn-t-h0-hybrid-x-foo");
+
+    let loc = loc.map_extensions(|_| ExtensionsMap::default());
+    assert_eq!(loc.to_string(), "sr-Latn");
+
+    let loc = loc
+        .try_map_extensions(|mut extensions| {
+            extensions.unicode.set_keyword("hc", &["h23"])?;
+            Ok::<_, Box<dyn std::error::Error>>(extensions)
+        })
+        .unwrap();
+    assert_eq!(loc.to_string(), "sr-Latn-u-hc-h23");
+
+    let loc: Locale = "en".parse().unwrap();
+    let result = loc.try_map_extensions(|mut extensions| {
+        extensions.unicode.set_keyword("hc", &["toolongvalue"])?;
+        Ok::<_, Box<dyn std::error::Error>>(extensions)
+    });
+    assert!(result.is_err());
+}
+
 // #[test]
 // fn test_from_parts_unchecked() {
 //     let loc: Locale = "en-US".parse().unwrap();
@@ -157,6 +316,91 @@ fn test_matches() {
     );
 }
 
+#[test]
+fn test_presence() {
+    let loc: Locale = "en-US".parse().unwrap();
+    assert_eq!(loc.presence(), LanguageIdentifier::PRESENCE_REGION);
+
+    let loc2: Locale = "en-US-t-h0-hybrid-u-hc-h12-x-foo".parse().unwrap();
+    assert_eq!(
+        loc2.presence(),
+        LanguageIdentifier::PRESENCE_REGION
+            | Locale::PRESENCE_UNICODE
+            | Locale::PRESENCE_TRANSFORM
+            | Locale::PRESENCE_PRIVATE
+    );
+    assert_ne!(loc, loc2);
+
+    let mut loc3 = loc2.clone();
+    loc3.extensions.private.clear_tags();
+    assert_eq!(loc3.presence() & Locale::PRESENCE_PRIVATE, 0);
+    assert_ne!(loc2, loc3);
+    assert_eq!(
+        loc3,
+        "en-US-t-h0-hybrid-u-hc-h12".parse::<Locale>().unwrap()
+    );
+}
+
+#[test]
+fn test_matches_any_all() {
+    let available: Vec<Locale> = vec![
+        "en-US".parse().unwrap(),
+        "de".parse().unwrap(),
+        "sr-Cyrl".parse().unwrap(),
+    ];
+    let en: Locale = "en".parse().unwrap();
+    let de_at: Locale = "de-AT".parse().unwrap();
+    let sr: Locale = "sr".parse().unwrap();
+    let pl: Locale = "pl".parse().unwrap();
+
+    assert!(!en.matches_any(&available, false, false));
+    assert!(en.matches_any(&available, true, false));
+    assert!(de_at.matches_any(&available, false, true));
+    assert!(!de_at.matches_any(&available, true, false));
+    assert!(sr.matches_any(&available, true, true));
+    assert!(!pl.matches_any(&available, true, true));
+    assert!(!pl.matches_any::<Locale>(&[], true, true));
+
+    assert!(!en.matches_all(&available, true, true));
+    assert!(en.matches_all(&available[..1], true, false));
+    assert!(pl.matches_all::<Locale>(&[], false, false));
+
+    let private: Locale = "en-x-foo".parse().unwrap();
+    assert!(!private.matches_any(&available, true, true));
+}
+
+#[test]
+fn test_to_language_only() {
+    for (input, expected) in &[
+        ("sr-Latn-RS-u-nu-latn", "sr"),
+        ("en-US-t-h0-hybrid-x-foo", "en"),
+        ("de-CH-1996", "de"),
+        ("und-Latn", "und"),
+        ("pl", "pl"),
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        let language = loc.to_language_only();
+        assert_eq!(language.to_string(), *expected, "{}", input);
+        assert!(language.extensions.is_empty(), "{}", input);
+    }
+}
+
+#[test]
+fn test_matches_language() {
+    let sr_latn: Locale = "sr-Latn-RS-u-nu-latn".parse().unwrap();
+    let sr: Locale = "sr".parse().unwrap();
+    let hr: Locale = "hr".parse().unwrap();
+    let und: Locale = "und-Latn".parse().unwrap();
+
+    assert!(sr_latn.matches_language(&sr, false));
+    assert!(sr.matches_language(&sr_latn, false));
+    assert!(!sr.matches_language(&hr, true));
+    assert!(!und.matches_language(&sr, false));
+    assert!(und.matches_language(&sr, true));
+    assert!(sr.matches_language(&und, true));
+    assert!(und.matches_language(&und, false));
+}
+
 #[test]
 fn test_set_fields() {
     let mut loc = Locale::default();
@@ -185,6 +429,53 @@ fn test_set_fields() {
     assert_eq!(&loc.to_string(), "und");
 }
 
+#[test]
+fn test_setters_normalize_case() {
+    let parsed: Locale = "DE-latn-at-MACOS-T-ES-ar-H0-HYBRID-U-ATTR-CA-Islamic-CIVIL-X-FOO"
+        .parse()
+        .unwrap();
+
+    let mut loc = Locale::default();
+    loc.id.language = "DE".parse().unwrap();
+    loc.id.script = Some("latn".parse().unwrap());
+    loc.id.region = Some("at".parse().unwrap());
+    loc.id.set_variants(&["MACOS".parse().unwrap()]);
+    loc.extensions
+        .transform
+        .set_tlang("ES-ar".parse().unwrap())
+        .unwrap();
+    loc.extensions
+        .transform
+        .set_tfield("H0", &["HYBRID"])
+        .unwrap();
+    loc.extensions.unicode.set_attribute("ATTR").unwrap();
+    loc.extensions
+        .unicode
+        .set_keyword("CA", &["Islamic-CIVIL"])
+        .unwrap();
+    loc.extensions.private.add_tag("FOO").unwrap();
+
+    assert_eq!(loc, parsed);
+    assert_eq!(
+        loc.to_string(),
+        "de-Latn-AT-macos-t-es-ar-h0-hybrid-u-attr-ca-islamic-civil-x-foo"
+    );
+
+    // Getters and removers accept mixed case too.
+    assert!(loc.extensions.unicode.has_attribute("Attr").unwrap());
+    assert!(loc.extensions.private.has_tag("Foo").unwrap());
+    assert!(loc.extensions.transform.remove_tfield("h0").unwrap());
+    assert!(loc.extensions.unicode.remove_keyword("Ca").unwrap());
+
+    // Only invalid content is rejected.
+    assert!(loc
+        .extensions
+        .unicode
+        .set_keyword("C", &["Buddhist"])
+        .is_err());
+    assert!(loc.extensions.private.add_tag("F$O").is_err());
+}
+
 #[cfg(feature = "likelysubtags")]
 #[test]
 fn test_likelysubtags() {
@@ -205,13 +496,59 @@ fn test_likelysubtags() {
     assert_eq!(loc_zh_hant.to_string(), "zh-TW-u-hc-h12");
 }
 
+#[cfg(feature = "likelysubtags")]
+#[test]
+fn test_likely_subtags_without_mutation() {
+    for (input, expected) in &[
+        ("en", Some(("en", "Latn", "US"))),
+        ("und-DE", Some(("de", "Latn", "DE"))),
+        ("zh-TW-u-hc-h12", Some(("zh", "Hant", "TW"))),
+        ("sr-Latn-BA", Some(("sr", "Latn", "BA"))),
+        ("qaa", None),
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        let result = loc.likely_subtags();
+        assert_eq!(
+            result.map(|(l, s, r)| (l.to_string(), s.to_string(), r.to_string())),
+            expected.map(|(l, s, r)| (l.to_string(), s.to_string(), r.to_string())),
+            "{}",
+            input
+        );
+        assert_eq!(&loc.to_string(), input);
+    }
+}
+
 #[test]
+#[cfg(feature = "character_direction")]
 fn test_character_direction() {
     let loc_en: Locale = "en-u-hc-h12".parse().unwrap();
     assert_eq!(loc_en.id.character_direction(), CharacterDirection::LTR);
 
     let loc_ar: Locale = "ar-AF-u-hc-h12".parse().unwrap();
     assert_eq!(loc_ar.id.character_direction(), CharacterDirection::RTL);
+    assert!(loc_ar.is_rtl());
+    assert!(!loc_ar.is_ltr());
+
+    for (input, rtl) in &[
+        ("en-u-hc-h12", false),
+        ("he", true),
+        ("pa", false),
+        ("pa-Arab-PK", true),
+        ("az-Latn-IR", false),
+        ("ur-Latn", false),
+        ("und-Arab", true),
+        ("en-x-foo", false),
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(loc.is_rtl(), *rtl, "{}", input);
+        assert_eq!(loc.is_ltr(), !*rtl, "{}", input);
+    }
+
+    #[cfg(feature = "likelysubtags")]
+    for (input, rtl) in &[("pa-PK", true), ("az-IR", true), ("und-IL", true)] {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(loc.is_rtl(), *rtl, "{}", input);
+    }
 }
 
 #[test]
@@ -225,3 +562,1870 @@ fn test_unicode_attributes_ordering() {
         .expect("Can't set attribute");
     assert_eq!(&loc.to_string(), "en-u-bar-baz-foo");
 }
+
+#[test]
+fn test_unicode_multi_subtag_values() {
+    let mut loc: Locale = "en-u-ca-islamic-civil-tz-america-los_angeles"
+        .parse()
+        .unwrap();
+    assert_eq!(
+        loc.extensions
+            .unicode
+            .keyword("ca")
+            .unwrap()
+            .collect::<Vec<_>>(),
+        &["islamic", "civil"]
+    );
+    assert_eq!(
+        &loc.to_string(),
+        "en-u-ca-islamic-civil-tz-america-los-angeles"
+    );
+
+    loc.extensions
+        .unicode
+        .set_keyword("ca", &["islamic-umalqura"])
+        .unwrap();
+    assert_eq!(
+        &loc.to_string(),
+        "en-u-ca-islamic-umalqura-tz-america-los-angeles"
+    );
+
+    loc.extensions
+        .unicode
+        .set_keyword("ca", &["islamic", "tbla"])
+        .unwrap();
+    assert_eq!(
+        loc.extensions
+            .unicode
+            .keyword("ca")
+            .unwrap()
+            .collect::<Vec<_>>(),
+        &["islamic", "tbla"]
+    );
+
+    assert!(loc
+        .extensions
+        .unicode
+        .set_keyword("ca", &["islamic-toolongvalue"])
+        .is_err());
+
+    // Only a lone `true` value is dropped.
+    let loc: Locale = "en-u-kn-true-co-true-foo".parse().unwrap();
+    assert_eq!(&loc.to_string(), "en-u-co-true-foo-kn");
+}
+
+#[test]
+fn test_strict_keyword_values() {
+    let strict = ParserOptions::default().strict(true);
+
+    for input in &[
+        "en-u-hc-h23",
+        "en-u-kn",
+        "en-u-kn-false",
+        "en-u-cu-usd",
+        "en-u-rg-gbzzzz",
+        "en-u-rg-419zzzz",
+        "en-u-ca-buddhist",
+    ] {
+        assert!(
+            parse_locale_with_options(input, strict).is_ok(),
+            "{}",
+            input
+        );
+    }
+
+    assert_eq!(
+        parse_locale_with_options("en-u-hc-h13", strict),
+        Err(ParserError::InvalidKeywordValue {
+            key: "hc".to_string(),
+            value: "h13".to_string(),
+        })
+    );
+    assert_eq!(
+        parse_locale_with_options("en-u-kn-maybe", strict),
+        Err(ParserError::InvalidKeywordValue {
+            key: "kn".to_string(),
+            value: "maybe".to_string(),
+        })
+    );
+    assert!(parse_locale_with_options("en-u-cu-usdollar", strict).is_err());
+    assert!(parse_locale_with_options("en-u-rg-gb", strict).is_err());
+    assert!(parse_locale_with_options("en-u-hc", strict).is_err());
+
+    // Without strict mode any well-formed value is accepted.
+    assert!(parse_locale("en-u-hc-h13").is_ok());
+
+    let mut loc = Locale::default();
+    assert!(loc
+        .extensions
+        .unicode
+        .set_keyword_strict("rg", &["uszzzz"])
+        .is_ok());
+    assert!(loc
+        .extensions
+        .unicode
+        .set_keyword_strict("cu", &["us-d"])
+        .is_err());
+    assert_eq!(&loc.to_string(), "und-u-rg-uszzzz");
+}
+
+#[test]
+fn test_private_subtag_validation() {
+    let loc: Locale = "en-x-a-B-cc".parse().unwrap();
+    assert_eq!(&loc.to_string(), "en-x-a-b-cc");
+
+    let mut loc = Locale::default();
+    loc.extensions.private.add_tag("z").unwrap();
+    assert_eq!(&loc.to_string(), "und-x-z");
+
+    assert_eq!(
+        parse_locale("en-x-toolongvalue"),
+        Err(ParserError::InvalidPrivateSubtag {
+            subtag: "toolongvalue".to_string(),
+            reason: InvalidSubtagReason::TooLong,
+        })
+    );
+    assert_eq!(
+        parse_locale("en-x-fo\u{e9}"),
+        Err(ParserError::InvalidPrivateSubtag {
+            subtag: "fo\u{e9}".to_string(),
+            reason: InvalidSubtagReason::InvalidCharacter,
+        })
+    );
+    assert_eq!(
+        parse_locale("en-x-foo--bar"),
+        Err(ParserError::InvalidPrivateSubtag {
+            subtag: "".to_string(),
+            reason: InvalidSubtagReason::Empty,
+        })
+    );
+
+    let err = loc.extensions.private.add_tag("foo!").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Parser error: Invalid private use subtag \"foo!\": it contains an invalid character"
+    );
+}
+
+#[test]
+fn test_extension_subtag_validation() {
+    let mut ignore = |_| ControlFlow::Continue(());
+    for (input, extension, subtag, reason) in &[
+        (
+            "en-u-ca-toolongvalue",
+            ExtensionType::Unicode,
+            "toolongvalue",
+            InvalidSubtagReason::TooLong,
+        ),
+        (
+            "en-u-ca-buddhist-b$d",
+            ExtensionType::Unicode,
+            "b$d",
+            InvalidSubtagReason::InvalidCharacter,
+        ),
+        (
+            "en-u-ca--gregory",
+            ExtensionType::Unicode,
+            "",
+            InvalidSubtagReason::Empty,
+        ),
+        (
+            "en-u-toolongattr",
+            ExtensionType::Unicode,
+            "toolongattr",
+            InvalidSubtagReason::TooLong,
+        ),
+        (
+            "en-t-h0-hy",
+            ExtensionType::Transform,
+            "hy",
+            InvalidSubtagReason::TooShort,
+        ),
+        (
+            "en-t-h0-hybr!d",
+            ExtensionType::Transform,
+            "hybr!d",
+            InvalidSubtagReason::InvalidCharacter,
+        ),
+    ] {
+        let expected = Err(ParserError::InvalidExtensionSubtag {
+            extension: *extension,
+            subtag: subtag.to_string(),
+            reason: *reason,
+        });
+        assert_eq!(parse_locale(input), expected, "{}", input);
+        assert_eq!(
+            parse_with_visitor(input, &mut ignore).map(|_| ()),
+            expected.map(|_: Locale| ()),
+            "{}",
+            input
+        );
+    }
+    assert_eq!(
+        parse_locale("en-t-h0-hy").unwrap_err().to_string(),
+        "Invalid subtag \"hy\" in extension \"t\": it is too short"
+    );
+
+    let mut loc = Locale::default();
+    let err = loc
+        .extensions
+        .unicode
+        .set_keyword("ca", &["calendar12"])
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Parser error: Invalid subtag \"calendar12\" in extension \"u\": it is too long"
+    );
+    assert!(loc.extensions.unicode.set_attribute("ab").is_err());
+    assert!(loc.extensions.transform.set_tfield("h0", &["hy"]).is_err());
+    assert!(loc.extensions.is_empty());
+
+    #[cfg(feature = "long_subtags")]
+    {
+        let lenient = ParserOptions::default().lenient(true);
+        assert!(parse_locale_with_options("en-u-ca-toolongvalue", lenient).is_ok());
+    }
+}
+
+#[test]
+fn test_private_subtag_order() {
+    let loc1: Locale = "en-x-foo-bar".parse().unwrap();
+    let loc2: Locale = "en-x-bar-foo".parse().unwrap();
+    assert_eq!(&loc1.to_string(), "en-x-foo-bar");
+    assert_ne!(loc1, loc2);
+
+    let mut loc = loc1.clone();
+    loc.extensions.private.add_tag("baz").unwrap();
+    assert_eq!(&loc.to_string(), "en-x-foo-bar-baz");
+    assert!(loc.extensions.private.remove_tag("bar").unwrap());
+    assert_eq!(&loc.to_string(), "en-x-foo-baz");
+
+    let canonical = ParserOptions::default().private_order(PrivateSubtagOrder::Canonical);
+    let mut loc1 = parse_locale_with_options("en-x-foo-bar", canonical).unwrap();
+    let loc2 = parse_locale_with_options("en-x-bar-foo", canonical).unwrap();
+    assert_eq!(&loc1.to_string(), "en-x-bar-foo");
+    assert_eq!(loc1, loc2);
+
+    loc1.extensions.private.add_tag("baz").unwrap();
+    assert_eq!(&loc1.to_string(), "en-x-bar-baz-foo");
+
+    // Equality only depends on the stored order, not on the policy.
+    let preserved: Locale = "en-x-bar-foo".parse().unwrap();
+    assert_eq!(preserved, loc2);
+}
+
+#[test]
+fn test_private_subtag_order_is_significant() {
+    // The order of private use subtags may carry meaning, so it is kept by default.
+    let ba: Locale = "en-x-b-a".parse().unwrap();
+    let ab: Locale = "en-x-a-b".parse().unwrap();
+    assert_ne!(ba, ab);
+    assert_ne!(ba.cmp(&ab), std::cmp::Ordering::Equal);
+    assert_ne!(ba.extensions.private, ab.extensions.private);
+    assert_eq!(ba.to_string(), "en-x-b-a");
+}
+
+#[test]
+fn test_data_version() {
+    let version = data_version();
+    #[cfg(feature = "character_direction")]
+    assert_eq!(version.character_direction, Some("37"));
+    #[cfg(not(feature = "character_direction"))]
+    assert_eq!(version.character_direction, None);
+    assert_eq!(version.unicode_extensions, Some("47"));
+    assert_eq!(version.regional_preferences, "47");
+
+    #[cfg(feature = "likelysubtags")]
+    assert_eq!(version.likely_subtags, Some("37"));
+    #[cfg(not(feature = "likelysubtags"))]
+    assert_eq!(version.likely_subtags, None);
+
+    #[cfg(feature = "territoryinfo")]
+    assert_eq!(version.territory_info, Some("47"));
+    #[cfg(not(feature = "territoryinfo"))]
+    assert_eq!(version.territory_info, None);
+
+    #[cfg(feature = "currencydata")]
+    assert_eq!(version.currency, Some("47"));
+    #[cfg(not(feature = "currencydata"))]
+    assert_eq!(version.currency, None);
+
+    #[cfg(feature = "timezones")]
+    assert_eq!(version.time_zones, Some("47"));
+    #[cfg(not(feature = "timezones"))]
+    assert_eq!(version.time_zones, None);
+
+    #[cfg(feature = "aliases")]
+    assert_eq!(version.aliases, Some("47"));
+    #[cfg(not(feature = "aliases"))]
+    assert_eq!(version.aliases, None);
+}
+
+#[cfg(not(feature = "aliases"))]
+#[test]
+fn test_canonicalize_without_aliases() {
+    use unic_locale_impl::{canonicalize_with_mode, CanonicalizationMode};
+
+    for input in &["iw_il", "en-US"] {
+        assert_eq!(
+            canonicalize_with_mode(input, CanonicalizationMode::Aliases),
+            canonicalize(input),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_region_override() {
+    for (input, expected) in &[
+        ("en-u-rg-gbzzzz", Some("GB")),
+        ("en-US-u-rg-001zzzz", Some("001")),
+        ("en-u-rg-gbxxxx", None),
+        ("en-u-rg-gbzzzz-ca-buddhist", Some("GB")),
+        ("en-u-ca-buddhist", None),
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(
+            loc.extensions.unicode.region_override(),
+            expected.map(|r| r.parse().unwrap()),
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+#[cfg(feature = "likelysubtags")]
+fn test_maximize_with_region_override() {
+    for (input, expected) in &[
+        ("en-u-rg-gbzzzz", "en-Latn-GB-u-rg-gbzzzz"),
+        ("sr-u-rg-mezzzz", "sr-Latn-ME-u-rg-mezzzz"),
+        ("en-US-u-rg-gbzzzz", "en-Latn-US-u-rg-gbzzzz"),
+        ("und-u-rg-plzzzz", "pl-Latn-PL-u-rg-plzzzz"),
+        ("en-u-rg-gbxxxx", "en-Latn-US-u-rg-gbxxxx"),
+        ("de", "de-Latn-DE"),
+    ] {
+        let mut loc: Locale = input.parse().unwrap();
+        assert!(loc.maximize_with_region_override(), "{}", input);
+        assert_eq!(&loc.to_string(), expected);
+    }
+}
+
+#[test]
+fn test_serialized_len() {
+    for input in &[
+        "und",
+        "en",
+        "en-US",
+        "zh-Hant-TW",
+        "sl-rozaj-biske-1994",
+        "en-US-u-hc-h12",
+        "de-u-co-phonebk-ka-shifted",
+        "en-t-es-AR-h0-hybrid",
+        "en-x-foo-bar",
+        "ar-Arab-EG-t-en-u-nu-latn-x-private",
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(loc.serialized_len(), loc.to_string().len(), "{}", input);
+    }
+
+    let mut loc = Locale::default();
+    loc.extensions.private.add_tag("foo").unwrap();
+    assert_eq!(loc.serialized_len(), "und-x-foo".len());
+}
+
+#[test]
+#[cfg(feature = "long_subtags")]
+fn test_lenient_parsing() {
+    let lenient = ParserOptions::default().lenient(true);
+
+    for input in &[
+        "en-u-ca-verylongvalue",
+        "en-t-h0-verylongvalue",
+        "en-x-verylongvalue",
+    ] {
+        assert!(parse_locale(input).is_err(), "{}", input);
+        let loc = parse_locale_with_options(input, lenient).unwrap();
+        assert_eq!(&loc.to_string(), input);
+    }
+
+    let loc =
+        parse_locale_with_options("en-u-CA-SixteenCharValue-x-SixteenCharValue", lenient).unwrap();
+    assert_eq!(
+        loc.extensions
+            .unicode
+            .keyword("ca")
+            .unwrap()
+            .collect::<Vec<_>>(),
+        &["sixteencharvalue"]
+    );
+    assert_eq!(
+        loc.extensions.private.tags().collect::<Vec<_>>(),
+        &["sixteencharvalue"]
+    );
+
+    assert!(parse_locale_with_options("en-u-ca-seventeencharvalue", lenient).is_err());
+    assert_eq!(
+        parse_locale_with_options("en-x-seventeencharvalue", lenient),
+        Err(ParserError::InvalidPrivateSubtag {
+            subtag: "seventeencharvalue".to_string(),
+            reason: InvalidSubtagReason::TooLong,
+        })
+    );
+
+    // Variants and attributes are not affected.
+    assert!(parse_locale_with_options("en-verylongvariant", lenient).is_err());
+    assert!(parse_locale_with_options("en-u-verylongattribute", lenient).is_err());
+
+    // The setters of a leniently parsed locale accept the same lengths as the parser.
+    let mut loc = parse_locale_with_options("en", lenient).unwrap();
+    assert!(loc.extensions.is_lenient());
+    loc.extensions.private.add_tag("verylongvalue").unwrap();
+    assert!(loc.extensions.private.has_tag("verylongvalue").unwrap());
+    loc.extensions
+        .unicode
+        .set_keyword("ca", &["verylongvalue"])
+        .unwrap();
+    loc.extensions
+        .transform
+        .set_tfield("h0", &["verylongvalue"])
+        .unwrap();
+    assert_eq!(
+        loc.to_string(),
+        "en-t-h0-verylongvalue-u-ca-verylongvalue-x-verylongvalue"
+    );
+    assert_eq!(
+        parse_locale_with_options(loc.to_string(), lenient).unwrap(),
+        loc
+    );
+    assert!(loc.extensions.private.remove_tag("verylongvalue").unwrap());
+
+    let mut loc: Locale = "en".parse().unwrap();
+    assert!(!loc.extensions.is_lenient());
+    assert!(loc.extensions.private.add_tag("verylongvalue").is_err());
+    assert!(loc.extensions.private.has_tag("verylongvalue").is_err());
+}
+
+#[test]
+#[cfg(not(feature = "long_subtags"))]
+fn test_lenient_parsing_without_long_subtags() {
+    use unic_locale_impl::extensions::ValueStr;
+
+    let lenient = ParserOptions::default().lenient(true);
+
+    assert!(parse_locale_with_options("en-x-verylongvalue", lenient).is_err());
+    let mut loc = parse_locale_with_options("en-x-foo", lenient).unwrap();
+    assert!(loc.extensions.is_lenient());
+    assert!(loc.extensions.private.add_tag("verylongvalue").is_err());
+
+    assert_eq!(std::mem::size_of::<ValueStr>(), 8);
+}
+
+#[test]
+fn test_reserved_language() {
+    let options = ParserOptions::default().allow_reserved_language(true);
+
+    assert!(parse_locale("abcd-US-u-hc-h12").is_err());
+
+    let loc = parse_locale_with_options("abcd-US-u-hc-h12", options).unwrap();
+    assert_eq!(&loc.to_string(), "abcd-US-u-hc-h12");
+    assert!(loc.id.language.is_reserved());
+}
+
+#[test]
+fn test_parse_extension_units() {
+    assert!(parse_extension_units("en-u-ca-verylongvalue", ParserOptions::default()).is_err());
+
+    let input = "en-US-u-attr-nu-thai-ca-islamic-civil-kn-t-en-Latn-h0-hybrid-x-foo-a";
+    let units: Vec<_> = parse_extension_units(input, ParserOptions::default())
+        .unwrap()
+        .map(|unit| (unit.extension, unit.key, unit.value, &input[unit.span]))
+        .collect();
+
+    assert_eq!(
+        units,
+        &[
+            (ExtensionType::Unicode, None, "attr", "attr"),
+            (ExtensionType::Unicode, Some("nu"), "thai", "nu-thai"),
+            (
+                ExtensionType::Unicode,
+                Some("ca"),
+                "islamic-civil",
+                "ca-islamic-civil"
+            ),
+            (ExtensionType::Unicode, Some("kn"), "", "kn"),
+            (ExtensionType::Transform, None, "en-Latn", "en-Latn"),
+            (ExtensionType::Transform, Some("h0"), "hybrid", "h0-hybrid"),
+            (ExtensionType::Private, None, "foo", "foo"),
+            (ExtensionType::Private, None, "a", "a"),
+        ]
+    );
+
+    assert_eq!(
+        parse_extension_units("en-US", ParserOptions::default())
+            .unwrap()
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn test_transform_followed_by_extension() {
+    let loc = parse_locale("en-t-h0-hybrid-u-ca-buddhist-x-foo").unwrap();
+    assert_eq!(&loc.to_string(), "en-t-h0-hybrid-u-ca-buddhist-x-foo");
+    assert_eq!(
+        loc.extensions
+            .transform
+            .tfield("h0")
+            .unwrap()
+            .collect::<Vec<_>>(),
+        &["hybrid"]
+    );
+}
+
+#[test]
+fn test_parse_locale_with_original() {
+    let parsed =
+        parse_locale_with_original("EN_latn_us-U-CA-Buddhist", ParserOptions::default()).unwrap();
+    assert_eq!(parsed.original(), "EN_latn_us-U-CA-Buddhist");
+    assert_eq!(&parsed.locale.to_string(), "en-Latn-US-u-ca-buddhist");
+
+    let normalized = parse_locale("en-Latn-US-u-ca-buddhist").unwrap();
+    assert_eq!(parsed.locale, normalized);
+    assert_eq!(Locale::from(parsed), normalized);
+
+    assert!(parse_locale_with_original("en-US-u", ParserOptions::default()).is_err());
+}
+
+#[test]
+fn test_parsed_locale_compares_normalized() {
+    use std::collections::HashSet;
+
+    let mixed = parse_locale_with_original("EN_us", ParserOptions::default()).unwrap();
+    let canonical = parse_locale_with_original("en-US", ParserOptions::default()).unwrap();
+    assert_eq!(mixed, canonical);
+    assert_ne!(mixed.original(), canonical.original());
+
+    let mut set = HashSet::new();
+    set.insert(mixed);
+    assert!(!set.insert(canonical));
+}
+
+#[test]
+fn test_canonical_equality() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    fn hash(loc: &Locale) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        loc.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    let pairs = &[
+        ("en-u-ca-buddhist-nu-thai", "en-u-nu-thai-ca-buddhist"),
+        ("en-u-foo-bar-kn", "en-u-bar-foo-foo-kn-true"),
+        ("en-u-ca-buddhist", "en-u-ca-buddhist-ca-gregory"),
+        (
+            "en-t-h0-hybrid-k0-dvorak",
+            "en-t-k0-dvorak-h0-hybrid-h0-true",
+        ),
+        (
+            "en-u-ca-buddhist-t-h0-hybrid",
+            "en-t-h0-hybrid-u-ca-buddhist",
+        ),
+        ("en-b-foo-a-bar-x-baz", "en-a-bar-b-foo-x-baz"),
+    ];
+
+    for (input1, input2) in pairs {
+        let loc1 = parse_locale(input1).unwrap();
+        let loc2 = parse_locale(input2).unwrap();
+        assert_eq!(loc1, loc2, "{} != {}", input1, input2);
+        assert_eq!(hash(&loc1), hash(&loc2), "{} != {}", input1, input2);
+        assert_eq!(loc1.cmp(&loc2), std::cmp::Ordering::Equal);
+        assert_eq!(loc1.to_string(), loc2.to_string());
+    }
+
+    // Private use subtags are only reordered with `PrivateSubtagOrder::Canonical`.
+    let options = ParserOptions::default().private_order(PrivateSubtagOrder::Canonical);
+    let loc1 = parse_locale_with_options("en-x-foo-bar", options).unwrap();
+    let loc2 = parse_locale_with_options("en-x-bar-foo", options).unwrap();
+    assert_eq!(loc1, loc2);
+    assert_eq!(hash(&loc1), hash(&loc2));
+    assert_eq!(loc1.cmp(&loc2), std::cmp::Ordering::Equal);
+    assert_ne!(
+        parse_locale("en-x-foo-bar").unwrap(),
+        parse_locale("en-x-bar-foo").unwrap()
+    );
+
+    let mut loc = parse_locale("en").unwrap();
+    loc.extensions.unicode.set_keyword("nu", &["thai"]).unwrap();
+    loc.extensions
+        .unicode
+        .set_keyword("ca", &["buddhist"])
+        .unwrap();
+    assert_eq!(loc, parse_locale(pairs[0].0).unwrap());
+    assert_eq!(hash(&loc), hash(&parse_locale(pairs[0].0).unwrap()));
+}
+
+#[test]
+fn test_ordering() {
+    let mut locales: Vec<Locale> = [
+        "fr",
+        "en-US-u-nu-thai",
+        "en-US",
+        "en",
+        "en-u-ca-buddhist-nu-thai",
+        "en-u-nu-thai-ca-buddhist",
+        "en-Latn",
+        "x-custom",
+    ]
+    .iter()
+    .map(|s| parse_locale(s).unwrap())
+    .collect();
+    let mut reversed = locales.clone();
+    reversed.reverse();
+    locales.sort();
+    reversed.sort();
+
+    // The order does not depend on the input order nor on the keyword order.
+    assert_eq!(locales, reversed);
+    assert_eq!(
+        locales
+            .iter()
+            .map(|l| l.to_string())
+            .collect::<Vec<_>>()
+            .windows(2)
+            .filter(|w| w[0] == w[1])
+            .count(),
+        1
+    );
+
+    for (idx, a) in locales.iter().enumerate() {
+        for b in &locales[idx..] {
+            assert_eq!(a.cmp(b) == std::cmp::Ordering::Equal, a == b, "{} {}", a, b);
+            assert_eq!(a.partial_cmp(b), Some(a.cmp(b)));
+        }
+    }
+
+    let map: std::collections::BTreeMap<Locale, usize> = locales
+        .iter()
+        .cloned()
+        .enumerate()
+        .map(|(idx, locale)| (locale, idx))
+        .collect();
+    assert_eq!(map.len(), locales.len() - 1);
+    assert!(map.contains_key(&parse_locale("en-u-nu-thai-ca-buddhist").unwrap()));
+    assert!(!map.contains_key(&parse_locale("en-u-nu-thai").unwrap()));
+}
+
+#[test]
+fn test_resolve_preferences() {
+    let prefs = parse_locale("de-AT").unwrap().resolve_preferences();
+    assert_eq!(prefs.hour_cycle, "h23");
+    assert_eq!(prefs.calendar, "gregory");
+    assert_eq!(prefs.numbering_system, "latn");
+    assert_eq!(prefs.measurement_system, "metric");
+    assert_eq!(prefs.first_weekday, "mon");
+    assert_eq!(prefs.region_override, None);
+
+    let prefs = parse_locale("th-TH").unwrap().resolve_preferences();
+    assert_eq!(prefs.calendar, "buddhist");
+    assert_eq!(prefs.first_weekday, "sun");
+
+    let prefs = parse_locale("ar-EG").unwrap().resolve_preferences();
+    assert_eq!(prefs.hour_cycle, "h12");
+    assert_eq!(prefs.numbering_system, "arab");
+    assert_eq!(prefs.first_weekday, "sat");
+    assert_eq!(
+        parse_locale("ar-MA")
+            .unwrap()
+            .resolve_preferences()
+            .numbering_system,
+        "latn"
+    );
+
+    let prefs = parse_locale("en-US-u-rg-gbzzzz")
+        .unwrap()
+        .resolve_preferences();
+    assert_eq!(prefs.hour_cycle, "h23");
+    assert_eq!(prefs.measurement_system, "uksystem");
+    assert_eq!(prefs.first_weekday, "mon");
+    assert_eq!(prefs.region_override, Some("GB".parse().unwrap()));
+
+    let prefs = parse_locale("en-GB-u-hc-h12-ca-islamic-civil-nu-arab-ms-ussystem-fw-fri")
+        .unwrap()
+        .resolve_preferences();
+    assert_eq!(prefs.hour_cycle, "h12");
+    assert_eq!(prefs.calendar, "islamic-civil");
+    assert_eq!(prefs.numbering_system, "arab");
+    assert_eq!(prefs.measurement_system, "ussystem");
+    assert_eq!(prefs.first_weekday, "fri");
+
+    #[cfg(feature = "likelysubtags")]
+    {
+        let prefs = parse_locale("en").unwrap().resolve_preferences();
+        assert_eq!(prefs.hour_cycle, "h12");
+        assert_eq!(prefs.first_weekday, "sun");
+    }
+}
+
+#[test]
+fn test_resolve_collation() {
+    for (input, expected) in &[
+        ("zh", "pinyin"),
+        ("zh-CN", "pinyin"),
+        ("zh-Hans-HK", "pinyin"),
+        ("zh-TW", "stroke"),
+        ("zh-HK", "stroke"),
+        ("zh-Hant", "stroke"),
+        ("yue", "stroke"),
+        ("yue-HK", "stroke"),
+        ("yue-CN", "pinyin"),
+        ("yue-Hans", "pinyin"),
+        ("yue-Hans-CN", "pinyin"),
+        ("zh-TW-u-co-zhuyin", "zhuyin"),
+        ("es-u-co-trad", "trad"),
+        ("de-AT", "standard"),
+        ("und", "standard"),
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(loc.resolve_collation(), *expected, "{}", input);
+    }
+}
+
+#[test]
+fn test_allowed_values() {
+    let key: UnicodeExtensionKey = "CA".parse().unwrap();
+    assert_eq!(key.as_str(), "ca");
+    match key.allowed_values() {
+        Some(KeywordValues::List(values)) => {
+            assert!(values.contains(&"gregory"));
+            assert!(values.contains(&"islamic-civil"));
+        }
+        other => panic!("Unexpected allowed values: {:?}", other),
+    }
+    assert_eq!(
+        "kn".parse::<UnicodeExtensionKey>()
+            .unwrap()
+            .allowed_values(),
+        Some(KeywordValues::Boolean)
+    );
+    assert_eq!(
+        "va".parse::<UnicodeExtensionKey>()
+            .unwrap()
+            .allowed_values(),
+        None
+    );
+    assert!("c".parse::<UnicodeExtensionKey>().is_err());
+
+    let strict = ParserOptions::default().strict(true);
+
+    // Every listed value passes strict validation.
+    for key in UnicodeExtensionKey::restricted() {
+        if let Some(KeywordValues::List(values)) = key.allowed_values() {
+            for value in values {
+                let input = format!("en-u-{}-{}", key, value);
+                assert!(
+                    parse_locale_with_options(&input, strict).is_ok(),
+                    "{}",
+                    input
+                );
+            }
+        }
+    }
+    assert!(parse_locale_with_options("en-u-ca-islamic-foo", strict).is_err());
+    assert!(parse_locale_with_options("en-u-co-foo", strict).is_err());
+}
+
+#[test]
+fn test_private_tags() {
+    let mut loc = parse_locale("en-x-foo-bar-baz").unwrap();
+    assert_eq!(
+        loc.extensions.private.tags().collect::<Vec<_>>(),
+        &["foo", "bar", "baz"]
+    );
+
+    assert!(loc.extensions.private.has_tag("BAR").unwrap());
+    assert!(loc.extensions.private.remove_tag("bar").unwrap());
+    assert!(!loc.extensions.private.has_tag("bar").unwrap());
+    assert!(!loc.extensions.private.remove_tag("bar").unwrap());
+    assert_eq!(
+        loc.extensions.private.tags().collect::<Vec<_>>(),
+        &["foo", "baz"]
+    );
+
+    assert_eq!(Locale::default().extensions.private.tags().len(), 0);
+}
+
+#[test]
+fn test_transform_casing() {
+    let loc = parse_locale("EN-t-EN-gb-H0-Hybrid").unwrap();
+    assert_eq!(&loc.to_string(), "en-t-en-gb-h0-hybrid");
+    assert_eq!(
+        loc.extensions.transform.tlang().map(|l| l.to_string()),
+        Some("en-GB".to_string())
+    );
+    assert_eq!(parse_locale(loc.to_string()).unwrap(), loc);
+    assert_eq!(loc.serialized_len(), loc.to_string().len());
+}
+
+#[test]
+fn test_dictionary_break_exclusions() {
+    let mut loc = parse_locale("th-u-dx-THAI-laoo").unwrap();
+    assert_eq!(&loc.to_string(), "th-u-dx-thai-laoo");
+    assert_eq!(
+        loc.extensions
+            .unicode
+            .dictionary_break_exclusions()
+            .iter()
+            .map(|s| s.as_str())
+            .collect::<Vec<_>>(),
+        &["Thai", "Laoo"]
+    );
+
+    loc.extensions
+        .unicode
+        .set_dictionary_break_exclusions(&["Hani".parse().unwrap(), "Khmr".parse().unwrap()]);
+    assert_eq!(&loc.to_string(), "th-u-dx-hani-khmr");
+
+    loc.extensions.unicode.set_dictionary_break_exclusions(&[]);
+    assert_eq!(&loc.to_string(), "th");
+    assert!(loc
+        .extensions
+        .unicode
+        .dictionary_break_exclusions()
+        .is_empty());
+
+    let strict = ParserOptions::default().strict(true);
+    assert!(parse_locale_with_options("th-u-dx-thai-laoo", strict).is_ok());
+    assert!(parse_locale_with_options("th-u-dx-thai-foo", strict).is_err());
+    assert!(parse_locale_with_options("th-u-dx", strict).is_err());
+}
+
+#[test]
+fn test_malformed_extension_errors() {
+    for (input, extension) in &[
+        ("en-u", ExtensionType::Unicode),
+        ("en-t", ExtensionType::Transform),
+        ("en-x", ExtensionType::Private),
+        ("en-u-x-foo", ExtensionType::Unicode),
+        ("en-t-u-ca-buddhist", ExtensionType::Transform),
+        ("en-u-ca-buddhist-t", ExtensionType::Transform),
+    ] {
+        assert_eq!(
+            parse_locale(input),
+            Err(ParserError::EmptyExtension {
+                extension: *extension
+            }),
+            "{}",
+            input
+        );
+    }
+    assert_eq!(
+        parse_locale("en-u").unwrap_err().to_string(),
+        "Extension \"u\" has no subtags"
+    );
+
+    for (input, key) in &[
+        ("en-t-h0", "h0"),
+        ("en-t-h0-k0-dvorak", "h0"),
+        ("en-t-k0-dvorak-h0-u-ca-buddhist", "h0"),
+    ] {
+        assert_eq!(
+            parse_locale(input),
+            Err(ParserError::KeyWithoutValue {
+                key: key.to_string()
+            }),
+            "{}",
+            input
+        );
+    }
+    assert_eq!(
+        parse_locale("en-t-h0").unwrap_err().to_string(),
+        "Key \"h0\" has no value"
+    );
+
+    let strict = ParserOptions::default().strict(true);
+    assert_eq!(
+        parse_locale_with_options("en-u-hc", strict),
+        Err(ParserError::KeyWithoutValue {
+            key: "hc".to_string()
+        })
+    );
+
+    // A single character private use subtag is not a singleton.
+    assert!(parse_locale("en-x-a").is_ok());
+    assert!(parse_locale("en-t-h0-true").is_ok());
+    assert!(parse_locale("en-u-kn").is_ok());
+}
+
+#[test]
+fn test_subtags() {
+    for input in &[
+        "und",
+        "sl-Latn-IT-rozaj-biske",
+        "en-US-t-en-Latn-GB-h0-hybrid-u-attr-ca-islamic-civil-kn-x-foo-a",
+    ] {
+        let loc = parse_locale(input).unwrap();
+        let serialized = loc
+            .subtags()
+            .map(|subtag| match subtag {
+                Subtag::Language(l) => l.as_str().to_string(),
+                Subtag::Script(s) => s.as_str().to_string(),
+                Subtag::Region(r) => r.as_str().to_string(),
+                Subtag::Variant(v) => v.as_str().to_string(),
+                Subtag::ExtensionSingleton(e) => e.to_string(),
+                Subtag::ExtensionKey(k) => k.to_string(),
+                Subtag::ExtensionValue(v) => v.to_ascii_lowercase(),
+                Subtag::Private(p) => p.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("-");
+        assert_eq!(serialized, loc.to_string());
+    }
+
+    let loc = parse_locale("en-t-h0-hybrid-x-foo").unwrap();
+    assert_eq!(
+        loc.subtags().collect::<Vec<_>>(),
+        &[
+            Subtag::Language("en".parse().unwrap()),
+            Subtag::ExtensionSingleton(ExtensionType::Transform),
+            Subtag::ExtensionKey("h0"),
+            Subtag::ExtensionValue("hybrid"),
+            Subtag::ExtensionSingleton(ExtensionType::Private),
+            Subtag::Private("foo"),
+        ]
+    );
+}
+
+#[test]
+fn test_parse_with_visitor() {
+    let input = "sl-Latn-IT-rozaj-t-h0-hybrid-u-attr-ca-islamic-civil-kn-x-foo-a";
+    let mut visited = vec![];
+    parse_with_visitor(input, &mut |subtag| {
+        visited.push(subtag);
+        ControlFlow::Continue(())
+    })
+    .unwrap();
+    let loc = parse_locale(input).unwrap();
+    assert_eq!(visited, loc.subtags().collect::<Vec<_>>());
+
+    // Subtags are visited in input order, and the visitor can stop early.
+    let mut visited = vec![];
+    parse_with_visitor("en-x-foo-bar-baz", &mut |subtag| {
+        visited.push(subtag);
+        if visited.len() == 3 {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .unwrap();
+    assert_eq!(
+        visited,
+        &[
+            Subtag::Language("en".parse().unwrap()),
+            Subtag::ExtensionSingleton(ExtensionType::Private),
+            Subtag::Private("foo"),
+        ]
+    );
+
+    let mut ignore = |_| ControlFlow::Continue(());
+    assert_eq!(
+        parse_with_visitor("en-u", &mut ignore),
+        Err(ParserError::EmptyExtension {
+            extension: ExtensionType::Unicode
+        })
+    );
+    assert_eq!(
+        parse_with_visitor("en-t-h0", &mut ignore),
+        Err(ParserError::KeyWithoutValue {
+            key: "h0".to_string()
+        })
+    );
+    assert_eq!(
+        parse_with_visitor("en-US-foobarbazqux", &mut ignore),
+        Err(ParserError::InvalidExtension)
+    );
+
+    // `parse_locale` builds on the same grammar, so a trailing separator is
+    // rejected by both.
+    assert_eq!(
+        parse_with_visitor("en-", &mut ignore),
+        Err(ParserError::InvalidExtension)
+    );
+    assert_eq!(parse_locale("en-"), Err(ParserError::InvalidExtension));
+}
+
+#[test]
+fn test_from_bytes_bounded() {
+    let loc =
+        Locale::from_bytes_bounded::<12>(b"SL_latn_it_ROZAJ-U-CA-Islamic-Civil-x-Foo").unwrap();
+    assert_eq!(loc.language(), "sl");
+    assert_eq!(loc.script(), Some("Latn".parse().unwrap()));
+    assert_eq!(loc.region(), Some("IT".parse().unwrap()));
+    assert_eq!(loc.variants().collect::<Vec<_>>(), &["rozaj"]);
+    assert_eq!(
+        loc.extensions().collect::<Vec<_>>(),
+        &["u", "ca", "islamic", "civil", "x", "foo"]
+    );
+    assert_eq!(loc.len(), 7);
+    assert_eq!(loc.to_string(), "sl-Latn-IT-rozaj-u-ca-islamic-civil-x-foo");
+
+    // The extensions keep the input order, while `Locale` sorts them.
+    let loc = Locale::from_bytes_bounded::<8>(b"en-u-hc-h12-t-h0-hybrid").unwrap();
+    assert_eq!(loc.to_string(), "en-u-hc-h12-t-h0-hybrid");
+    assert_eq!(Locale::from(&loc).to_string(), "en-t-h0-hybrid-u-hc-h12");
+
+    let loc = Locale::from_bytes_bounded::<0>(b"en-US").unwrap();
+    assert!(loc.is_empty());
+    assert_eq!(Locale::from(&loc), Locale::from_bytes(b"en-US").unwrap());
+
+    let loc = Locale::from_bytes_bounded::<2>(b"x-foo").unwrap();
+    assert_eq!(Locale::from(&loc), Locale::from_bytes(b"x-foo").unwrap());
+    assert!(Locale::from(&loc).is_private_use_only());
+    assert_eq!(loc.to_string(), "x-foo");
+
+    let loc = Locale::from_bytes_bounded::<2>(b"und-x-foo").unwrap();
+    assert!(!Locale::from(&loc).is_private_use_only());
+    assert_eq!(loc.to_string(), "und-x-foo");
+
+    assert_eq!(
+        Locale::from_bytes_bounded::<3>(b"en-u-hc-h12-nu-latn")
+            .unwrap_err()
+            .to_string(),
+        "Parser error: The input has more than 3 subtags"
+    );
+    assert_eq!(
+        Locale::from_bytes_bounded::<1>(b"ca-valencia-posix")
+            .unwrap_err()
+            .to_string(),
+        "Parser error: The input has more than 1 subtags"
+    );
+    assert_eq!(
+        Locale::from_bytes_bounded::<8>(b"en-u")
+            .unwrap_err()
+            .to_string(),
+        "Parser error: Extension \"u\" has no subtags"
+    );
+}
+
+#[test]
+fn test_raw_locale() {
+    for input in &[
+        "und",
+        "en-US",
+        "sl-Latn-IT-biske-rozaj",
+        "und-t-en-h0-hybrid-u-ca-buddhist-x-foo",
+    ] {
+        let loc = parse_locale(input).unwrap();
+        let buf = RawLocaleBuf::from(&loc);
+        let raw = buf.as_raw();
+        assert_eq!(Locale::try_from(raw).unwrap(), loc);
+    }
+
+    let loc = parse_locale("en-Latn-US").unwrap();
+    let buf = RawLocaleBuf::from(&loc);
+    let raw = buf.as_raw();
+    assert_eq!(raw.language(), u64::from_le_bytes(*b"en\0\0\0\0\0\0"));
+    assert_eq!(raw.script(), u32::from_le_bytes(*b"Latn"));
+    assert_eq!(raw.region(), u32::from_le_bytes(*b"US\0\0"));
+    assert_eq!(
+        Locale::try_from(RawLocaleBuf::default().as_raw()).unwrap(),
+        Locale::default()
+    );
+}
+
+#[test]
+fn test_stable_encoding() {
+    for input in &[
+        "und",
+        "sl-Latn-IT-biske-rozaj",
+        "en-t-en-h0-hybrid-u-ca-buddhist-x-foo",
+    ] {
+        let loc = parse_locale(input).unwrap();
+        assert_eq!(Locale::from_stable_bytes(&loc.to_stable_bytes()), Ok(loc));
+    }
+
+    // Values written by version 1 must stay readable.
+    assert_eq!(
+        Locale::from_stable_bytes(b"\xB7\x01en-US-u-hc-h12"),
+        Ok(parse_locale("en-US-u-hc-h12").unwrap())
+    );
+
+    assert_eq!(
+        Locale::from_stable_bytes(&[0xB7]),
+        Err(StableDecodingError::Truncated)
+    );
+    assert_eq!(
+        Locale::from_stable_bytes(b"en-US"),
+        Err(StableDecodingError::InvalidMagic(b'e'))
+    );
+    assert_eq!(
+        Locale::from_stable_bytes(b"\xB7\x03en-US"),
+        Err(StableDecodingError::UnsupportedVersion(3))
+    );
+    assert_eq!(
+        Locale::from_stable_bytes(b"\xB7\x02"),
+        Err(StableDecodingError::Truncated)
+    );
+    assert_eq!(
+        Locale::from_stable_bytes(b"\xB7\x02\x02en-US"),
+        Err(StableDecodingError::UnsupportedVersion(2))
+    );
+    assert!(matches!(
+        Locale::from_stable_bytes(b"\xB7\x01not a locale"),
+        Err(StableDecodingError::InvalidPayload(_))
+    ));
+}
+
+#[cfg(feature = "long_subtags")]
+#[test]
+fn test_stable_encoding_lenient() {
+    let options = ParserOptions::default().lenient(true);
+    for input in &["en-x-verylongtag", "en-u-ca-verylongvalue", "en-US"] {
+        let loc = parse_locale_with_options(input, options).unwrap();
+        let decoded = Locale::from_stable_bytes(&loc.to_stable_bytes()).unwrap();
+        assert_eq!(decoded, loc, "{}", input);
+        assert!(decoded.extensions.is_lenient(), "{}", input);
+    }
+
+    let loc = parse_locale("en-x-foo").unwrap();
+    let decoded = Locale::from_stable_bytes(&loc.to_stable_bytes()).unwrap();
+    assert!(!decoded.extensions.is_lenient());
+}
+
+#[test]
+fn test_pseudo_locale() {
+    for (input, expected) in &[
+        ("en-XA", Some(PseudoLocale::Accented)),
+        ("en-xa", Some(PseudoLocale::Accented)),
+        ("ar-XB", Some(PseudoLocale::Bidi)),
+        ("en-XB", Some(PseudoLocale::Bidi)),
+        ("en-Latn-XB-u-nu-latn", Some(PseudoLocale::Bidi)),
+        ("en", None),
+        ("en-XC", None),
+    ] {
+        let loc = parse_locale(input).unwrap();
+        assert_eq!(loc.pseudo_locale(), *expected, "{}", input);
+        assert_eq!(loc.is_pseudo_locale(), expected.is_some(), "{}", input);
+    }
+
+    let en: Locale = "en-US".parse().unwrap();
+    let accented = en.to_pseudo_locale(PseudoLocale::Accented);
+    assert_eq!(accented.to_string(), "en-XA");
+    assert_eq!(accented.pseudo_locale(), Some(PseudoLocale::Accented));
+    #[cfg(feature = "character_direction")]
+    assert_eq!(accented.character_direction(), CharacterDirection::LTR);
+
+    let bidi = en.to_pseudo_locale(PseudoLocale::Bidi);
+    assert_eq!(bidi.to_string(), "en-XB");
+    #[cfg(feature = "character_direction")]
+    assert_eq!(bidi.character_direction(), CharacterDirection::RTL);
+
+    let ar: Locale = "ar".parse().unwrap();
+    assert_eq!(ar.to_pseudo_locale(PseudoLocale::Bidi).to_string(), "ar-XB");
+}
+
+#[test]
+fn test_empty_input() {
+    assert_eq!(parse_locale(""), Err(ParserError::EmptyInput));
+    assert_eq!(
+        "".parse::<Locale>().unwrap_err().to_string(),
+        "Parser error: The input is empty"
+    );
+    assert_eq!(
+        canonicalize("").unwrap_err().to_string(),
+        "Parser error: The input is empty"
+    );
+    assert_eq!(
+        parse_with_visitor("", &mut |_| ControlFlow::Continue(())),
+        Err(ParserError::EmptyInput)
+    );
+    assert!(matches!(
+        parse_extension_units("", ParserOptions::default()),
+        Err(ParserError::EmptyInput)
+    ));
+    // Only an empty input is affected.
+    assert_eq!(parse_locale(" "), Err(ParserError::InvalidLanguage));
+
+    let options = ParserOptions::default().empty_input(EmptyInputPolicy::Und);
+    assert_eq!(
+        parse_locale_with_options("", options),
+        Ok(Locale::default())
+    );
+    assert_eq!(
+        parse_extension_units("", options).map(|units| units.count()),
+        Ok(0)
+    );
+}
+
+#[test]
+fn test_eq_ignore_extensions() {
+    for (a, b, expected) in &[
+        ("en-US-u-hc-h12", "en-US", true),
+        ("en-US-x-foo", "en-US-t-h0-hybrid", true),
+        ("sr-Latn-RS-u-nu-latn", "sr-latn-rs", true),
+        ("de-CH-1996-u-co-phonebk", "de-CH-1996", true),
+        ("en-US-u-hc-h12", "en-u-hc-h12", false),
+        ("de-CH-1996", "de-CH", false),
+        ("und", "en", false),
+    ] {
+        let a: Locale = a.parse().unwrap();
+        let b: Locale = b.parse().unwrap();
+        assert_eq!(a.eq_ignore_extensions(&b), *expected, "{} {}", a, b);
+        assert_eq!(b.eq_ignore_extensions(&a), *expected, "{} {}", b, a);
+    }
+}
+
+#[test]
+fn test_eq_ignore_case() {
+    let loc: Locale = "sr-Latn-RS-u-nu-latn-x-foo".parse().unwrap();
+    for (other, expected) in &[
+        ("sr-Latn-RS-u-nu-latn-x-foo", true),
+        ("SR_latn_rs_U_NU_LATN_X_FOO", true),
+        ("sr-Latn-RS-u-nu-latn-x-FOO", true),
+        ("sr-Latn-RS-u-nu-latn", false),
+        ("sr-Cyrl-RS-u-nu-latn-x-foo", false),
+        ("", false),
+        ("sr--RS", false),
+    ] {
+        assert_eq!(loc.eq_ignore_case(other), *expected, "{}", other);
+    }
+
+    let en: Locale = "en-u-ca-gregory-hc-h12".parse().unwrap();
+    assert!(en.eq_ignore_case("EN-U-HC-H12-CA-GREGORY"));
+}
+
+#[test]
+fn test_is_valid_extension_key() {
+    use unic_locale_impl::extensions::is_valid_extension_key;
+
+    for key in &["ca", "hc", "NU", "1a", "h0", "m0"] {
+        assert!(is_valid_extension_key(key.as_bytes()), "{}", key);
+    }
+    for key in &["", "c", "cal", "12", "c-", "h00"] {
+        assert!(!is_valid_extension_key(key.as_bytes()), "{}", key);
+    }
+}
+
+#[test]
+fn test_to_extension_case() {
+    use unic_locale_impl::extensions::to_extension_case;
+
+    assert_eq!(to_extension_case("U"), "u");
+    assert_eq!(to_extension_case("CA"), "ca");
+    assert_eq!(to_extension_case("H0"), "h0");
+    assert_eq!(to_extension_case("Hybrid"), "hybrid");
+}
+
+#[cfg(feature = "iso639")]
+#[test]
+fn test_iso639() {
+    let loc: Locale = "en-US-u-hc-h12".parse().unwrap();
+    assert_eq!(loc.iso639_3(), Some("eng".parse().unwrap()));
+
+    let loc: Locale = "und-US".parse().unwrap();
+    assert_eq!(loc.iso639_3(), None);
+
+    assert_eq!(
+        unic_locale_impl::canonicalize("eng-US-u-hc-h12"),
+        Ok("en-US-u-hc-h12".to_string())
+    );
+}
+
+#[cfg(feature = "iso639")]
+#[test]
+fn test_lenient_bibliographic() {
+    let lenient = ParserOptions::default().lenient(true);
+
+    assert!(parse_locale_with_options("ger-DE-u-hc-h23", lenient)
+        .unwrap()
+        .eq_ignore_case("de-DE-u-hc-h23"));
+    assert_eq!(
+        unic_locale_impl::canonicalize("GER-de"),
+        Ok("de-DE".to_string())
+    );
+}
+
+#[cfg(feature = "iso3166")]
+#[test]
+fn test_lenient_alpha3_region() {
+    use unic_locale_impl::iso3166;
+
+    let lenient = ParserOptions::default().lenient(true);
+
+    assert!(parse_locale("en-USA-u-hc-h12").is_err());
+    let loc = parse_locale_with_options("en-USA-u-hc-h12", lenient).unwrap();
+    assert_eq!(loc.to_string(), "en-US-u-hc-h12");
+    assert_eq!(loc.id.region.and_then(iso3166::to_alpha3), Some("USA"));
+}
+
+#[test]
+fn test_to_html_lang() {
+    for (input, expected) in &[
+        ("en-US", "en-US"),
+        ("EN_us-u-hc-h12", "en-US"),
+        ("sr-Latn-RS-t-en-x-foo", "sr-Latn-RS"),
+        ("de-CH-1996-u-co-phonebk", "de-CH-1996"),
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(loc.to_html_lang(), *expected, "{}", input);
+    }
+}
+
+#[cfg(feature = "likelysubtags")]
+#[test]
+fn test_to_html_lang_und() {
+    for (input, expected) in &[
+        // Without a script or region, there is nothing to infer the language from.
+        ("und-u-ca-buddhist", "und"),
+        ("und-TH-u-ca-buddhist", "th-TH"),
+        ("und-Grek", "el-Grek"),
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(loc.to_html_lang(), *expected, "{}", input);
+    }
+}
+
+#[test]
+fn test_lenient_script_numeric() {
+    let lenient = ParserOptions::default().lenient(true);
+
+    assert!(parse_locale("sr-215-RS-u-nu-latn").is_err());
+    let loc = parse_locale_with_options("sr-215-RS-u-nu-latn", lenient).unwrap();
+    assert_eq!(loc.to_string(), "sr-Latn-RS-u-nu-latn");
+}
+
+#[test]
+fn test_private_use_only() {
+    for (input, expected) in &[
+        ("x-custom-pseudo", "x-custom-pseudo"),
+        ("X-Custom", "x-custom"),
+        ("x_a_b", "x-a-b"),
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        assert!(loc.is_private_use_only(), "{}", input);
+        assert_eq!(loc.language(), None, "{}", input);
+        assert_eq!(loc.to_string(), *expected, "{}", input);
+        assert_eq!(loc.serialized_len(), expected.len(), "{}", input);
+        assert_eq!(loc.to_string().parse::<Locale>().unwrap(), loc, "{}", input);
+    }
+
+    // `und-x-foo` keeps being written as it was parsed, and is the same locale.
+    let private: Locale = "x-foo".parse().unwrap();
+    let und: Locale = "und-x-foo".parse().unwrap();
+    assert!(!und.is_private_use_only());
+    assert_eq!(und.to_string(), "und-x-foo");
+    assert_eq!(und.language(), Some(LanguageIdentifier::default().language));
+    assert_eq!(private, und);
+    assert_eq!(private.cmp(&und), std::cmp::Ordering::Equal);
+
+    let mut extensions = ExtensionsMap::default();
+    extensions.private.add_tag("foo").unwrap();
+    let from_parts = Locale::from_parts(Default::default(), None, None, &[], Some(extensions));
+    assert!(!from_parts.is_private_use_only());
+    assert_eq!(from_parts.to_string(), "und-x-foo");
+
+    let from_parts = Locale::from_parts(
+        Default::default(),
+        None,
+        None,
+        &[],
+        Some(private.extensions.clone()),
+    );
+    assert!(from_parts.is_private_use_only());
+    assert_eq!(from_parts.to_string(), "x-foo");
+
+    let shared = Locale::from(SharedLocale::from(&private));
+    assert!(shared.is_private_use_only());
+    assert_eq!(shared.to_string(), "x-foo");
+
+    let und_us: Locale = "und-US-x-foo".parse().unwrap();
+    assert!(!und_us.is_private_use_only());
+    assert_eq!(
+        und_us.language(),
+        Some(LanguageIdentifier::default().language)
+    );
+    assert_eq!(
+        private.subtags().collect::<Vec<_>>(),
+        vec![
+            Subtag::ExtensionSingleton(ExtensionType::Private),
+            Subtag::Private("foo")
+        ]
+    );
+
+    let mut visited = vec![];
+    parse_with_visitor("x-foo", &mut |subtag| {
+        visited.push(subtag);
+        ControlFlow::Continue(())
+    })
+    .unwrap();
+    assert_eq!(visited, private.subtags().collect::<Vec<_>>());
+
+    let mut loc = private.clone();
+    loc.extensions
+        .unicode
+        .set_keyword("ca", &["buddhist"])
+        .unwrap();
+    assert_eq!(loc.to_string(), "und-u-ca-buddhist-x-foo");
+    assert_eq!(loc.language(), Some(LanguageIdentifier::default().language));
+
+    assert_eq!(
+        parse_locale("x"),
+        Err(ParserError::EmptyExtension {
+            extension: ExtensionType::Private
+        })
+    );
+    assert!(parse_locale("x-toolongvalue").is_err());
+}
+
+#[test]
+fn test_parse_langid_only() {
+    for input in &[
+        "en",
+        "EN_us",
+        "sr-Latn-RS-ekavsk",
+        "de-CH-1996-1901-u-co-phonebk",
+        "en-US-t-h0-hybrid-u-ca-buddhist-x-foo",
+        "x-foo",
+    ] {
+        assert_eq!(
+            parse_langid_only(input),
+            parse_locale(input).map(|loc| loc.id),
+            "{}",
+            input
+        );
+    }
+
+    for input in &["", "e", "en-u", "en-t-h0", "en-u-ca-toolongvalue", "en-x-"] {
+        assert_eq!(
+            parse_langid_only(input).unwrap_err(),
+            parse_locale(input).unwrap_err(),
+            "{}",
+            input
+        );
+    }
+    assert_eq!(
+        parse_langid_only(b"en-\xff"),
+        Err(ParserError::InvalidLanguage)
+    );
+}
+
+#[test]
+fn test_check() {
+    use unic_locale_impl::parser::ParserError;
+    use unic_locale_impl::{CheckLevel, Finding, Transformation};
+
+    let loc: Locale = "de-DE-u-co-phonebk-hc-h23".parse().unwrap();
+    for level in &[
+        CheckLevel::WellFormed,
+        CheckLevel::Valid,
+        CheckLevel::Canonical,
+    ] {
+        assert_eq!(loc.check(*level), vec![]);
+    }
+
+    let loc: Locale = "ja-u-hc-h10-kn-yes-vt-0061".parse().unwrap();
+    assert_eq!(loc.check(CheckLevel::WellFormed), vec![]);
+    assert_eq!(
+        loc.check(CheckLevel::Valid),
+        vec![
+            Finding::InvalidKeyword(ParserError::InvalidKeywordValue {
+                key: "hc".to_string(),
+                value: "h10".to_string(),
+            }),
+            Finding::InvalidKeyword(ParserError::InvalidKeywordValue {
+                key: "kn".to_string(),
+                value: "yes".to_string(),
+            }),
+        ]
+    );
+    let findings = loc.check(CheckLevel::Canonical);
+    assert_eq!(
+        findings[2..],
+        [
+            Finding::NotCanonical(Transformation::AliasReplaced {
+                from: "kn-yes".to_string(),
+                to: "kn".to_string(),
+            }),
+            Finding::NotCanonical(Transformation::DeprecatedKeywordRemoved {
+                keyword: "vt-0061".to_string(),
+            }),
+        ]
+    );
+    assert_eq!(
+        findings.iter().map(Finding::level).collect::<Vec<_>>(),
+        vec![
+            CheckLevel::Valid,
+            CheckLevel::Valid,
+            CheckLevel::Canonical,
+            CheckLevel::Canonical
+        ]
+    );
+
+    #[cfg(feature = "iso3166")]
+    {
+        let loc: Locale = "en-QQ".parse().unwrap();
+        assert_eq!(
+            loc.check(CheckLevel::Valid),
+            vec![Finding::UnknownRegion("QQ".parse().unwrap())]
+        );
+        let loc: Locale = "es-419".parse().unwrap();
+        assert_eq!(loc.check(CheckLevel::Valid), vec![]);
+    }
+}
+
+#[cfg(feature = "unstable")]
+#[test]
+fn test_raw_extensions() {
+    use unic_locale_impl::extensions::ValueStr;
+    use unic_locale_impl::tinystr::{TinyStr4, TinyStr8};
+
+    let loc: Locale = "en-t-es-h0-hybrid-u-foo-nu-thai-kn-x-bar-aaa"
+        .parse()
+        .unwrap();
+
+    let keywords: Vec<_> = loc.extensions.unicode.raw_keywords().collect();
+    let thai: ValueStr = "thai".parse().unwrap();
+    assert_eq!(
+        keywords,
+        vec![
+            ("kn".parse::<TinyStr4>().unwrap(), &[][..]),
+            ("nu".parse().unwrap(), &[thai][..]),
+        ]
+    );
+    assert_eq!(
+        loc.extensions.unicode.raw_attributes(),
+        &["foo".parse::<TinyStr8>().unwrap()]
+    );
+
+    let tfields: Vec<_> = loc.extensions.transform.raw_tfields().collect();
+    assert_eq!(tfields.len(), 1);
+    assert_eq!(tfields[0].0, "h0");
+    assert_eq!(tfields[0].1, &["hybrid".parse::<ValueStr>().unwrap()]);
+
+    let tags: Vec<&str> = loc
+        .extensions
+        .private
+        .raw_tags()
+        .iter()
+        .map(|t| t.as_str())
+        .collect();
+    assert_eq!(tags, vec!["bar", "aaa"]);
+}
+
+#[test]
+fn test_locale_set() {
+    use unic_locale_impl::LocaleSet;
+
+    let mut set = LocaleSet::new();
+    assert!(set.insert_str("de-AT-u-kn-yes").unwrap());
+    assert!(!set.insert_str("DE_at-u-kn").unwrap());
+    assert!(set.insert_str("de").unwrap());
+    assert!(set.insert_str("fr-CA").unwrap());
+    assert!(set.insert_str("e").is_err());
+    assert_eq!(set.len(), 3);
+
+    let loc: Locale = "de-AT-u-kn-yes".parse().unwrap();
+    assert!(set.contains(&loc));
+    assert_eq!(
+        set.iter().map(|l| l.to_string()).collect::<Vec<_>>(),
+        vec!["de", "de-AT-u-kn", "fr-CA"]
+    );
+
+    let range: Locale = "de".parse().unwrap();
+    assert_eq!(
+        set.filter(&range)
+            .map(|l| l.to_string())
+            .collect::<Vec<_>>(),
+        vec!["de", "de-AT-u-kn"]
+    );
+    let requested: Locale = "fr-CA-1694acad".parse().unwrap();
+    assert_eq!(set.lookup(&requested).unwrap().to_string(), "fr-CA");
+    let requested: Locale = "fr-BE".parse().unwrap();
+    assert_eq!(set.lookup(&requested), None);
+
+    let other: LocaleSet = vec!["fr-CA".parse::<Locale>().unwrap(), "it".parse().unwrap()]
+        .into_iter()
+        .collect();
+    assert_eq!(
+        set.union(&other)
+            .iter()
+            .map(|l| l.to_string())
+            .collect::<Vec<_>>(),
+        vec!["de", "de-AT-u-kn", "fr-CA", "it"]
+    );
+    assert_eq!(
+        (&set.intersection(&other))
+            .into_iter()
+            .map(|l| l.to_string())
+            .collect::<Vec<_>>(),
+        vec!["fr-CA"]
+    );
+
+    assert!(set.remove(&loc));
+    assert!(!set.remove(&loc));
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn test_as_tuple() {
+    for (input, expected) in &[
+        ("en", (Some("en"), None, None)),
+        (
+            "sr-Cyrl-RS-u-nu-latn",
+            (Some("sr"), Some("Cyrl"), Some("RS")),
+        ),
+        ("es-419", (Some("es"), None, Some("419"))),
+        ("und-Latn", (None, Some("Latn"), None)),
+        ("x-foo", (None, None, None)),
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(loc.as_tuple(), *expected, "{}", input);
+    }
+
+    let loc: Locale = "sl-IT-rozaj-biske-1994".parse().unwrap();
+    let variants: Vec<&str> = loc.variants().iter().map(|v| v.as_str()).collect();
+    assert_eq!(variants, vec!["1994", "biske", "rozaj"]);
+    let loc: Locale = "sl".parse().unwrap();
+    assert!(loc.variants().is_empty());
+}
+
+#[test]
+fn test_transform_extension_api() {
+    use unic_locale_impl::extensions::TransformExtensionKey;
+
+    let mut loc: Locale = "ja-t-it-m0-ungegn".parse().unwrap();
+    assert_eq!(loc.to_string(), "ja-t-it-m0-ungegn");
+    assert_eq!(
+        loc.extensions.transform_language(),
+        Some(&"it".parse::<LanguageIdentifier>().unwrap())
+    );
+
+    let m0: TransformExtensionKey = "m0".parse().unwrap();
+    let h0: TransformExtensionKey = "H0".parse().unwrap();
+    assert_eq!(h0.to_string(), "h0");
+    assert_eq!(
+        loc.extensions.transform_field(m0).collect::<Vec<_>>(),
+        vec!["ungegn"]
+    );
+    assert_eq!(loc.extensions.transform_field(h0).len(), 0);
+
+    loc.extensions.set_transform_field(h0, &["hybrid"]).unwrap();
+    assert!(loc.extensions.set_transform_field(h0, &["x"]).is_err());
+    loc.extensions
+        .set_transform_language(Some("sr-Cyrl".parse().unwrap()));
+    assert_eq!(loc.to_string(), "ja-t-sr-cyrl-h0-hybrid-m0-ungegn");
+    assert_eq!(loc.to_string().parse::<Locale>().unwrap(), loc);
+
+    assert!(loc.extensions.remove_transform_field(m0));
+    assert!(!loc.extensions.remove_transform_field(m0));
+    loc.extensions.set_transform_language(None);
+    assert_eq!(loc.to_string(), "ja-t-h0-hybrid");
+
+    for key in &["m", "0m", "m00", ""] {
+        assert!(key.parse::<TransformExtensionKey>().is_err(), "{}", key);
+    }
+}
+
+#[test]
+fn test_other_extensions() {
+    for (input, expected) in &[
+        ("en-a-bar-x-u-foo", "en-a-bar-x-u-foo"),
+        ("en-B-Foo-A-bar", "en-a-bar-b-foo"),
+        (
+            "de-z-zz-u-ca-gregory-t-fr-0-abc",
+            "de-0-abc-t-fr-u-ca-gregory-z-zz",
+        ),
+        ("en-w-12345678-v-ab", "en-v-ab-w-12345678"),
+        ("en-a-first-a-second", "en-a-first"),
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(loc.to_string(), *expected, "{}", input);
+        assert_eq!(expected.parse::<Locale>().unwrap(), loc, "{}", input);
+        assert_eq!(canonicalize(input).unwrap(), *expected, "{}", input);
+        assert_eq!(loc.serialized_len(), expected.len());
+    }
+
+    for (input, error) in &[
+        ("en-a", "Parser error: Extension \"a\" has no subtags"),
+        ("en-a-x-foo", "Parser error: Extension \"a\" has no subtags"),
+        ("en-a-b", "Parser error: Extension \"a\" has no subtags"),
+        (
+            "en-a-ba$",
+            "Parser error: Invalid subtag \"ba$\" in extension \"a\": it contains an invalid character",
+        ),
+        (
+            "en-a-bar-toolongvalue",
+            "Parser error: Invalid subtag \"toolongvalue\" in extension \"a\": it is too long",
+        ),
+    ] {
+        assert_eq!(
+            input.parse::<Locale>().unwrap_err().to_string(),
+            *error,
+            "{}",
+            input
+        );
+    }
+    assert!("en-$".parse::<Locale>().is_err());
+    assert!(Locale::from_bytes_bounded::<4>(b"en-a-b").is_err());
+
+    let mut loc: Locale = "en-US-a-bar-x-foo".parse().unwrap();
+    assert!(!loc.extensions.is_empty());
+    assert_eq!(
+        loc.subtags().skip(2).collect::<Vec<_>>(),
+        vec![
+            Subtag::ExtensionSingleton(ExtensionType::Other('a')),
+            Subtag::ExtensionValue("bar"),
+            Subtag::ExtensionSingleton(ExtensionType::Private),
+            Subtag::Private("foo"),
+        ]
+    );
+    let bounded = Locale::from_bytes_bounded::<4>(b"en-US-a-bar-x-foo").unwrap();
+    assert_eq!(Locale::from(&bounded), loc);
+
+    assert_eq!(loc.extensions.other_extension('A').unwrap().len(), 1);
+    assert_eq!(loc.extensions.other_extension('c').unwrap().len(), 0);
+    assert!(loc.extensions.other_extension('x').is_err());
+    assert!(loc.extensions.other_extension('é').is_err());
+    assert!(loc.extensions.set_other_extension('c', &["a"]).is_err());
+    assert!(loc
+        .extensions
+        .set_other_extension::<&str>('c', &[])
+        .is_err());
+    loc.extensions
+        .set_other_extension('Z', &["Qux", "baz"])
+        .unwrap();
+    assert_eq!(
+        loc.extensions.other_singletons().collect::<Vec<_>>(),
+        vec!['a', 'z']
+    );
+    assert_eq!(loc.to_string(), "en-US-a-bar-z-qux-baz-x-foo");
+    assert!(loc.extensions.remove_other_extension('a').unwrap());
+    assert!(!loc.extensions.remove_other_extension('a').unwrap());
+    assert_eq!(loc.to_string(), "en-US-z-qux-baz-x-foo");
+}
+
+#[test]
+fn test_typed_keywords() {
+    use unic_locale_impl::extensions::{
+        CalendarAlgorithm, Collation, FirstDayOfWeek, HourCycle, NumberingSystem,
+    };
+
+    let mut loc: Locale = "ja-JP-u-ca-japanese-co-unihan-fw-mon-hc-h11-nu-latn"
+        .parse()
+        .unwrap();
+    let unicode = &mut loc.extensions.unicode;
+    assert_eq!(unicode.calendar(), Some(CalendarAlgorithm::Japanese));
+    assert_eq!(unicode.collation(), Some(Collation::Unihan));
+    assert_eq!(unicode.first_day_of_week(), Some(FirstDayOfWeek::Mon));
+    assert_eq!(unicode.hour_cycle(), Some(HourCycle::H11));
+    assert_eq!(unicode.numbering_system(), Some(NumberingSystem::Latn));
+
+    unicode.set_calendar(Some(CalendarAlgorithm::IslamicCivil));
+    unicode.set_collation(None);
+    unicode.set_numbering_system(Some(NumberingSystem::Hanidec));
+    assert_eq!(
+        loc.to_string(),
+        "ja-JP-u-ca-islamic-civil-fw-mon-hc-h11-nu-hanidec"
+    );
+
+    // Absent and unknown values.
+    let loc: Locale = "en-u-hc-h25-nu-roman".parse().unwrap();
+    assert_eq!(loc.extensions.unicode.hour_cycle(), None);
+    assert_eq!(loc.extensions.unicode.numbering_system(), None);
+    assert_eq!(loc.extensions.unicode.calendar(), None);
+
+    assert_eq!("H12".parse::<HourCycle>(), Ok(HourCycle::H12));
+    assert!("h25".parse::<HourCycle>().is_err());
+    assert!("islamic".parse::<FirstDayOfWeek>().is_err());
+
+    // Every value allowed by strict validation has a typed value.
+    for (key, check) in &[
+        (
+            "ca",
+            &(|v: &str| v.parse::<CalendarAlgorithm>().is_ok()) as &dyn Fn(&str) -> bool,
+        ),
+        ("co", &|v: &str| v.parse::<Collation>().is_ok()),
+        ("fw", &|v: &str| v.parse::<FirstDayOfWeek>().is_ok()),
+        ("hc", &|v: &str| v.parse::<HourCycle>().is_ok()),
+    ] {
+        let key: UnicodeExtensionKey = key.parse().unwrap();
+        match key.allowed_values() {
+            Some(KeywordValues::List(values)) => {
+                for value in values {
+                    assert!(check(value), "{}-{}", key, value);
+                }
+            }
+            other => panic!("Unexpected values for {}: {:?}", key, other),
+        }
+    }
+}
+
+#[test]
+fn test_parse_locale_spanned() {
+    for (input, span, component, found) in &[
+        ("", 0..0, Component::Language, ""),
+        ("e", 0..1, Component::Language, "e"),
+        ("en-$", 3..4, Component::ExtensionSingleton, "$"),
+        ("en-Latn-$$$", 8..11, Component::Region, "$$$"),
+        ("en-abcdefghi", 3..12, Component::Variant, "abcdefghi"),
+        ("en-US-u", 6..7, Component::ExtensionSingleton, "u"),
+        (
+            "en_US_u_ca_gregorian1",
+            11..21,
+            Component::ExtensionValue,
+            "gregorian1",
+        ),
+        ("en-u-c$-gregory", 5..7, Component::ExtensionKey, "c$"),
+        ("en-t-h0", 5..7, Component::ExtensionKey, "h0"),
+        ("en-t-h0-m0-abc", 5..7, Component::ExtensionKey, "h0"),
+        (
+            "en-t-en-$$$-h0-hybrid",
+            8..11,
+            Component::ExtensionValue,
+            "$$$",
+        ),
+        (
+            "en-t-abcd-h0-hybrid",
+            5..9,
+            Component::ExtensionValue,
+            "abcd",
+        ),
+        (
+            "en-x-foo-abcdefghi",
+            9..18,
+            Component::PrivateUse,
+            "abcdefghi",
+        ),
+        ("en-a-ba$", 5..8, Component::ExtensionValue, "ba$"),
+        (
+            "en-US-u-ca-buddhist-",
+            20..20,
+            Component::ExtensionValue,
+            "",
+        ),
+    ] {
+        let error = parse_locale_spanned(input).unwrap_err();
+        assert_eq!(error.error, parse_locale(input).unwrap_err(), "{}", input);
+        assert_eq!(
+            (error.span.clone(), error.component, error.found.as_str()),
+            (span.clone(), *component, *found),
+            "{}",
+            input
+        );
+        assert_eq!(&input[error.span.clone()], *found, "{}", input);
+    }
+
+    assert_eq!(
+        parse_locale_spanned("en-US-u-ca-buddhist").unwrap(),
+        parse_locale("en-US-u-ca-buddhist").unwrap()
+    );
+    assert_eq!(
+        parse_locale_spanned("en-Latn-$$$").unwrap_err().to_string(),
+        "Invalid extension at bytes 8..11, in region subtag \"$$$\""
+    );
+}
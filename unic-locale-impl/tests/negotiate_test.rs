@@ -0,0 +1,236 @@
+use unic_locale_impl::accept_language::{
+    AcceptLanguage, DefaultsPolicy, LanguagePriorityList, ScoreWeighting,
+};
+use unic_locale_impl::negotiate::{
+    negotiate_languages, Filtering, Lookup, Matching, NegotiationStrategy,
+};
+use unic_locale_impl::Locale;
+
+fn locales(input: &[&str]) -> Vec<Locale> {
+    input.iter().map(|s| s.parse().unwrap()).collect()
+}
+
+fn negotiated<S: NegotiationStrategy>(
+    strategy: S,
+    requested: &[&str],
+    available: &[Locale],
+) -> Vec<String> {
+    strategy
+        .negotiate(&locales(requested), available)
+        .iter()
+        .map(|locale| locale.to_string())
+        .collect()
+}
+
+#[test]
+fn test_builtin_strategies() {
+    let available = locales(&["en-GB", "en", "fr-CA", "fr-FR", "sr-Latn-BA", "de-DE"]);
+
+    for (requested, filtering, matching, lookup) in &[
+        (vec![], vec![], vec![], vec![]),
+        (vec!["pl"], vec![], vec![], vec![]),
+        (vec!["en"], vec!["en", "en-GB"], vec!["en"], vec!["en"]),
+        (vec!["en-US"], vec!["en", "en-GB"], vec!["en"], vec!["en"]),
+        (
+            vec!["fr", "de-AT"],
+            vec!["fr-CA", "fr-FR", "de-DE"],
+            vec!["fr-CA", "de-DE"],
+            vec![],
+        ),
+        (
+            vec!["fr-FR", "en-GB"],
+            vec!["fr-FR", "fr-CA", "en-GB", "en"],
+            vec!["fr-FR", "en-GB"],
+            vec!["fr-FR"],
+        ),
+        (
+            vec!["sr-Latn-RS"],
+            vec!["sr-Latn-BA"],
+            vec!["sr-Latn-BA"],
+            vec![],
+        ),
+        (vec!["sr-Cyrl"], vec![], vec![], vec![]),
+        (
+            vec!["pl", "de-AT", "en"],
+            vec!["de-DE", "en", "en-GB"],
+            vec!["de-DE", "en"],
+            vec!["en"],
+        ),
+    ] {
+        assert_eq!(
+            negotiated(Filtering, requested, &available),
+            *filtering,
+            "{:?}",
+            requested
+        );
+        assert_eq!(
+            negotiated(Matching, requested, &available),
+            *matching,
+            "{:?}",
+            requested
+        );
+        assert_eq!(
+            negotiated(Lookup, requested, &available),
+            *lookup,
+            "{:?}",
+            requested
+        );
+    }
+}
+
+#[test]
+fn test_custom_strategy() {
+    // Select at most two locales, never the `XA` pseudo-locale.
+    struct Restricted;
+
+    impl NegotiationStrategy for Restricted {
+        fn matches<'a>(&self, requested: &Locale, available: &'a [Locale]) -> Vec<&'a Locale> {
+            let mut result = Filtering.matches(requested, available);
+            result.retain(|locale| !locale.is_pseudo_locale());
+            result
+        }
+
+        fn limit(&self) -> Option<usize> {
+            Some(2)
+        }
+    }
+
+    let available = locales(&["en-XA", "en-US", "en-GB", "en"]);
+    assert_eq!(
+        negotiated(&Restricted, &["en"], &available),
+        vec!["en", "en-US"]
+    );
+
+    let strategy: &dyn NegotiationStrategy = &Restricted;
+    assert_eq!(
+        negotiated(strategy, &["de"], &available),
+        Vec::<String>::new()
+    );
+}
+
+#[test]
+fn test_negotiate_list() {
+    let available = locales(&["en-GB", "en", "fr-CA", "de-DE", "pt-BR"]);
+    let list: LanguagePriorityList =
+        AcceptLanguage::parse("fr;q=0.5, en-US, de;q=0, *;q=0.1").into();
+
+    let negotiated = |strategy: &dyn NegotiationStrategy| -> Vec<String> {
+        strategy
+            .negotiate_list(&list, &available)
+            .iter()
+            .map(|locale| locale.to_string())
+            .collect()
+    };
+
+    // `de-DE` is excluded with `q=0`, even from the wildcard.
+    assert_eq!(
+        negotiated(&Filtering),
+        vec!["en", "en-GB", "fr-CA", "pt-BR"]
+    );
+    assert_eq!(negotiated(&Matching), vec!["en", "fr-CA", "en-GB", "pt-BR"]);
+    assert_eq!(negotiated(&Lookup), vec!["en"]);
+    assert_eq!(
+        negotiated(&ScoreWeighting::default()),
+        vec!["en", "en-GB", "fr-CA", "pt-BR"]
+    );
+}
+
+#[test]
+fn test_score_weighting_strategy() {
+    let available = locales(&["en", "fr-CA", "fr", "de-DE"]);
+
+    for header in &["fr-CA;q=0.6, fr;q=0.7", "de-AT, en;q=0.9", "pl", "*, de"] {
+        let list = AcceptLanguage::parse(header).into();
+        let ranked = ScoreWeighting::default().negotiate_list(&list, &available);
+        assert_eq!(
+            ranked.first().copied().unwrap_or(&available[0]),
+            Locale::best_from_accept_language_weighted(
+                header,
+                &available,
+                &available[0],
+                ScoreWeighting::default()
+            ),
+            "{}",
+            header
+        );
+    }
+
+    assert_eq!(
+        negotiated(ScoreWeighting::default(), &["fr-FR", "de"], &available),
+        vec!["fr", "fr-CA", "de-DE"]
+    );
+}
+
+#[test]
+fn test_negotiate_languages() {
+    let available = locales(&["en-US", "fr", "de-DE", "de-AT"]);
+    let requested = locales(&["de", "fr-CA"]);
+    let defaults = &available[..1];
+
+    let negotiated = |requested: &[Locale], policy, strategy: &dyn NegotiationStrategy| {
+        negotiate_languages(requested, &available, defaults, policy, strategy)
+            .iter()
+            .map(|locale| locale.to_string())
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        negotiated(&requested, DefaultsPolicy::Always, &Filtering),
+        vec!["de-DE", "de-AT", "fr", "en-US"]
+    );
+    assert_eq!(
+        negotiated(&requested, DefaultsPolicy::Always, &Matching),
+        vec!["de-DE", "fr", "en-US"]
+    );
+    // The limit of the strategy leaves no room for the defaults.
+    assert_eq!(
+        negotiated(&requested, DefaultsPolicy::Always, &Lookup),
+        vec!["fr"]
+    );
+    assert_eq!(
+        negotiated(&requested, DefaultsPolicy::OnlyIfEmpty, &Matching),
+        vec!["de-DE", "fr"]
+    );
+    assert_eq!(
+        negotiated(&requested, DefaultsPolicy::Never, &Matching),
+        vec!["de-DE", "fr"]
+    );
+
+    // The defaults are not repeated.
+    let requested = locales(&["en", "pl"]);
+    assert_eq!(
+        negotiated(&requested, DefaultsPolicy::Always, &Filtering),
+        vec!["en-US"]
+    );
+
+    let requested = locales(&["pl"]);
+    assert_eq!(
+        negotiated(&requested, DefaultsPolicy::OnlyIfEmpty, &Lookup),
+        vec!["en-US"]
+    );
+    assert!(negotiated(&requested, DefaultsPolicy::Never, &Lookup).is_empty());
+}
+
+#[cfg(feature = "likelysubtags")]
+#[test]
+fn test_likely_subtags_matching() {
+    let available = locales(&["zh-Hant", "zh-Hans", "sr-Latn", "sr-Cyrl"]);
+
+    assert_eq!(
+        negotiated(Matching, &["zh-TW"], &available),
+        vec!["zh-Hant"]
+    );
+    assert_eq!(
+        negotiated(Matching, &["zh-CN"], &available),
+        vec!["zh-Hans"]
+    );
+    assert_eq!(
+        negotiated(Matching, &["sr-RS"], &available),
+        vec!["sr-Cyrl"]
+    );
+    // The likely script is preferred over other scripts of the language.
+    assert_eq!(
+        negotiated(Filtering, &["zh-HK"], &available),
+        vec!["zh-Hant", "zh-Hans"]
+    );
+}
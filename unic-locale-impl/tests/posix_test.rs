@@ -0,0 +1,88 @@
+use unic_locale_impl::{Locale, PosixOptions};
+
+#[test]
+fn test_to_unix_locale() {
+    for (input, charset, expected) in &[
+        ("en", None, "en"),
+        ("en-US", None, "en_US"),
+        ("en-US", Some("UTF-8"), "en_US.UTF-8"),
+        ("de-DE", Some("ISO-8859-1"), "de_DE.ISO-8859-1"),
+        ("en-Latn-US", Some("UTF-8"), "en_US.UTF-8"),
+        ("sr-Latn-RS", Some("UTF-8"), "sr_RS.UTF-8@latin"),
+        ("sr-Cyrl-RS", Some("UTF-8"), "sr_RS.UTF-8"),
+        ("uz-Cyrl-UZ", None, "uz_UZ@cyrillic"),
+        ("ks-Deva-IN", None, "ks_IN@devanagari"),
+        ("ca-ES-valencia", Some("UTF-8"), "ca_ES.UTF-8@valencia"),
+        ("de-DE-1996", None, "de_DE"),
+        ("zh-Hant-TW", None, "zh_TW"),
+        ("ar-EG-u-nu-latn-x-foo", None, "ar_EG"),
+        ("es-419", None, "es_419"),
+        (
+            "de-DE-u-cu-eur",
+            Some("ISO-8859-15"),
+            "de_DE.ISO-8859-15@euro",
+        ),
+        ("de-AT-u-cu-eur-nu-latn", None, "de_AT@euro"),
+        ("de-DE-u-cu-usd", None, "de_DE"),
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(&loc.to_unix_locale(*charset), expected, "{}", input);
+    }
+}
+
+#[test]
+fn test_from_unix_locale() {
+    for (input, expected) in &[
+        ("en_US", "en-US"),
+        ("en_US.UTF-8", "en-US"),
+        ("de_AT.ISO-8859-15@euro", "de-AT-u-cu-eur"),
+        ("de_DE@euro", "de-DE-u-cu-eur"),
+        ("sr_RS@latin", "sr-Latn-RS"),
+        ("sr_RS.UTF-8@Latin", "sr-Latn-RS"),
+        ("sr_RS.UTF-8", "sr-RS"),
+        ("uz_UZ@cyrillic", "uz-Cyrl-UZ"),
+        ("ca_ES.UTF-8@valencia", "ca-ES-valencia"),
+        ("ca_ES@valencia", "ca-ES-valencia"),
+        // Unknown modifiers and modifiers of other languages are dropped.
+        ("en_US@latin", "en-US"),
+        ("tt_RU@iqtelif", "tt-RU"),
+        ("C@euro", "und"),
+        ("C", "und"),
+        ("C.UTF-8", "und"),
+        ("POSIX", "und"),
+    ] {
+        let loc = Locale::from_unix_locale(input).unwrap();
+        assert_eq!(loc.to_string(), *expected, "{}", input);
+    }
+    assert!(Locale::from_unix_locale("").is_err());
+    assert!(Locale::from_unix_locale("c").is_err());
+    assert!(Locale::from_unix_locale("@latin").is_err());
+
+    // The locales written by `to_unix_locale` are read back.
+    for input in &[
+        "sr-Latn-RS",
+        "uz-Cyrl-UZ",
+        "ks-Deva-IN",
+        "ca-ES-valencia",
+        "de-DE",
+        "de-DE-u-cu-eur",
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        let name = loc.to_unix_locale(Some("UTF-8"));
+        assert_eq!(Locale::from_unix_locale(&name).unwrap(), loc, "{}", name);
+    }
+
+    let options = PosixOptions {
+        c_locale: "en-US-u-va-posix".parse().unwrap(),
+    };
+    for input in &["C", "C.UTF-8", "POSIX"] {
+        let loc = Locale::from_unix_locale_with_options(input, &options).unwrap();
+        assert_eq!(loc, options.c_locale, "{}", input);
+    }
+    assert_eq!(
+        Locale::from_unix_locale_with_options("fr_CA.UTF-8", &options)
+            .unwrap()
+            .to_string(),
+        "fr-CA"
+    );
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use unic_locale_impl::{ExtensionsMap, LanguageIdentifier, Locale};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Settings {
+    locale: Locale,
+    fallback: Option<LanguageIdentifier>,
+    extensions: ExtensionsMap,
+}
+
+#[test]
+fn test_serialize() {
+    let loc: Locale = "en-us-u-hc-h12-ca-buddhist".parse().unwrap();
+    assert_eq!(
+        serde_json::to_string(&loc).unwrap(),
+        r#""en-US-u-ca-buddhist-hc-h12""#
+    );
+
+    let settings = Settings {
+        locale: loc.clone(),
+        fallback: Some("fr".parse().unwrap()),
+        extensions: loc.extensions,
+    };
+    let json = serde_json::to_string(&settings).unwrap();
+    assert_eq!(
+        json,
+        r#"{"locale":"en-US-u-ca-buddhist-hc-h12","fallback":"fr","extensions":"-u-ca-buddhist-hc-h12"}"#
+    );
+    assert_eq!(serde_json::from_str::<Settings>(&json).unwrap(), settings);
+}
+
+#[test]
+fn test_deserialize() {
+    let loc: Locale = serde_json::from_str(r#""de_at-u-co-phonebk""#).unwrap();
+    assert_eq!(loc.to_string(), "de-AT-u-co-phonebk");
+
+    let extensions: ExtensionsMap = serde_json::from_str(r#""-t-en-x-foo""#).unwrap();
+    assert_eq!(extensions.to_string(), "-t-en-x-foo");
+
+    let error = serde_json::from_str::<Locale>(r#""en-u""#).unwrap_err();
+    assert!(error.to_string().starts_with("Parser error: "), "{}", error);
+    assert!(serde_json::from_str::<Locale>("5").is_err());
+    assert!(serde_json::from_str::<ExtensionsMap>(r#""-u-ca-$""#).is_err());
+}
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+use unic_locale_impl::serde_with::{LenientLocale, LocaleString};
+use unic_locale_impl::Locale;
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Strict {
+    #[serde_as(as = "LocaleString")]
+    locale: Locale,
+    #[serde_as(as = "Option<LocaleString>")]
+    fallback: Option<Locale>,
+}
+
+#[serde_as]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Lenient {
+    #[serde_as(as = "Vec<LenientLocale>")]
+    locales: Vec<Locale>,
+}
+
+#[test]
+fn test_locale_string() {
+    let value: Strict =
+        serde_json::from_str(r#"{ "locale": "en_us-u-hc-h12", "fallback": null }"#).unwrap();
+    assert_eq!(value.locale.to_string(), "en-US-u-hc-h12");
+    assert_eq!(value.fallback, None);
+    assert_eq!(
+        serde_json::to_string(&value).unwrap(),
+        r#"{"locale":"en-US-u-hc-h12","fallback":null}"#
+    );
+
+    assert!(serde_json::from_str::<Strict>(r#"{ "locale": " en-US", "fallback": null }"#).is_err());
+    assert!(serde_json::from_str::<Strict>(r#"{ "locale": 5, "fallback": null }"#).is_err());
+}
+
+#[test]
+fn test_lenient_locale() {
+    let value: Lenient =
+        serde_json::from_str(r#"{ "locales": [" de_AT.UTF-8@euro ", "sr_RS@latin", "fr"] }"#)
+            .unwrap();
+    assert_eq!(
+        value
+            .locales
+            .iter()
+            .map(|l| l.to_string())
+            .collect::<Vec<_>>(),
+        &["de-AT", "sr-RS", "fr"]
+    );
+    assert_eq!(
+        serde_json::to_string(&value).unwrap(),
+        r#"{"locales":["de-AT","sr-RS","fr"]}"#
+    );
+
+    assert!(serde_json::from_str::<Lenient>(r#"{ "locales": ["not a locale"] }"#).is_err());
+}
@@ -0,0 +1,35 @@
+use unic_locale_impl::territory::{languages_for_region, OfficialStatus};
+
+#[test]
+fn test_languages_for_region() {
+    for (region, expected) in &[
+        ("CH", vec!["de", "fr", "it", "rm"]),
+        ("lu", vec!["fr", "de", "lb"]),
+        ("FR", vec!["fr"]),
+        ("AQ", vec![]),
+    ] {
+        let languages = languages_for_region(region.parse().unwrap());
+        assert_eq!(
+            languages
+                .iter()
+                .map(|l| l.language.as_str())
+                .collect::<Vec<_>>(),
+            *expected,
+            "{}",
+            region
+        );
+        assert!(languages
+            .windows(2)
+            .all(|w| w[0].population_percent >= w[1].population_percent));
+    }
+
+    let es = languages_for_region("ES".parse().unwrap());
+    assert_eq!(es[0].official_status, Some(OfficialStatus::Official));
+    assert_eq!(
+        es[1].official_status,
+        Some(OfficialStatus::OfficialRegional)
+    );
+
+    let us = languages_for_region("US".parse().unwrap());
+    assert_eq!(us[0].official_status, Some(OfficialStatus::DeFactoOfficial));
+}
@@ -0,0 +1,40 @@
+use unic_locale_impl::test_util::{
+    assert_canonical_idempotent, assert_roundtrip, load_corpus, parse_corpus,
+};
+
+#[test]
+fn test_corpus() {
+    let corpus = load_corpus("./tests/fixtures/corpus.txt").unwrap();
+    assert_eq!(corpus.len(), 8);
+    assert_eq!(corpus[2], "EN_us");
+
+    for tag in &corpus {
+        let loc = assert_roundtrip(tag);
+        assert_eq!(assert_canonical_idempotent(tag), loc.to_string());
+    }
+
+    assert!(load_corpus("./tests/fixtures/missing.txt").is_err());
+    assert_eq!(
+        parse_corpus("\n  en \n#fr\n\nde-AT\n").collect::<Vec<_>>(),
+        vec!["en", "de-AT"]
+    );
+}
+
+#[cfg(feature = "extensions")]
+#[test]
+fn test_extensions() {
+    assert_eq!(
+        assert_roundtrip("en-u-nu-thai-ca-buddhist").to_string(),
+        "en-u-ca-buddhist-nu-thai"
+    );
+    assert_eq!(
+        assert_canonical_idempotent("ja-u-kn-yes-vt-0061"),
+        "ja-u-kn"
+    );
+}
+
+#[test]
+#[should_panic(expected = "Parsing \"e\" failed")]
+fn test_roundtrip_failure() {
+    assert_roundtrip("e");
+}
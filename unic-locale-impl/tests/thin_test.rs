@@ -0,0 +1,28 @@
+#![cfg(not(feature = "extensions"))]
+
+use unic_locale_impl::parser::{parse_langid_only, parse_locale, ParserError};
+use unic_locale_impl::{ExtensionsMap, Locale};
+
+#[test]
+fn test_thin_locale() {
+    let loc: Locale = "sr-Latn-RS-ekavsk".parse().unwrap();
+    assert_eq!(loc.to_string(), "sr-Latn-RS-ekavsk");
+    assert_eq!(loc.serialized_len(), 17);
+    assert!(loc.extensions.is_empty());
+    assert_eq!(loc.to_java_string(), "sr_RS_ekavsk_#Latn");
+
+    let en: Locale = "en".parse().unwrap();
+    let en_us: Locale = "en-US".parse().unwrap();
+    assert!(en.matches(&en_us, true, false));
+}
+
+#[test]
+fn test_thin_rejects_extensions() {
+    for input in &["en-u-hc-h12", "en-US-x-foo", "en-t-es"] {
+        assert_eq!(parse_locale(input), Err(ParserError::InvalidExtension));
+        assert_eq!(parse_langid_only(input), Err(ParserError::InvalidExtension));
+    }
+
+    assert_eq!("".parse::<ExtensionsMap>(), Ok(ExtensionsMap::default()));
+    assert!("u-hc-h12".parse::<ExtensionsMap>().is_err());
+}
@@ -0,0 +1,46 @@
+use unic_locale_impl::timezone::primary_zone_for_region;
+use unic_locale_impl::Locale;
+
+#[test]
+fn test_primary_zone_for_region() {
+    for (region, expected) in &[
+        ("FR", Some("Europe/Paris")),
+        ("jp", Some("Asia/Tokyo")),
+        ("CN", Some("Asia/Shanghai")),
+        ("DE", Some("Europe/Berlin")),
+        ("US", None),
+        ("RU", None),
+        ("AQ", None),
+        ("419", None),
+    ] {
+        assert_eq!(
+            primary_zone_for_region(region.parse().unwrap()),
+            *expected,
+            "{}",
+            region
+        );
+    }
+}
+
+#[test]
+fn test_resolve_time_zone() {
+    for (input, expected) in &[
+        ("fr-CH", Some("Europe/Zurich")),
+        ("en-CA", None),
+        ("en-US-u-rg-iezzzz", Some("Europe/Dublin")),
+        ("en-US-u-tz-jptyo", Some("Asia/Tokyo")),
+        ("en-IE-u-tz-usnyc", None),
+        ("nl-CW-u-tz-ancur", Some("America/Curacao")),
+        ("fr-MF-u-tz-gpmsb", Some("America/Marigot")),
+        ("en-AQ", None),
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(loc.resolve_time_zone(), *expected, "{}", input);
+    }
+
+    let loc: Locale = "ja".parse().unwrap();
+    #[cfg(feature = "likelysubtags")]
+    assert_eq!(loc.resolve_time_zone(), Some("Asia/Tokyo"));
+    #[cfg(not(feature = "likelysubtags"))]
+    assert_eq!(loc.resolve_time_zone(), None);
+}
@@ -0,0 +1,46 @@
+use unic_locale_impl::Locale;
+
+#[test]
+fn test_from_windows_name() {
+    for (input, expected) in &[
+        ("en-US", "en-US"),
+        ("de-DE_phoneb", "de-DE-u-co-phonebk"),
+        ("zh-TW_pronun", "zh-TW-u-co-zhuyin"),
+        ("ja-JP_radstr", "ja-JP-u-co-unihan"),
+        ("zh-CN_STROKE", "zh-CN-u-co-stroke"),
+        ("es-ES_tradnl", "es-ES-u-co-trad"),
+        ("hu-HU_technl", "hu-HU"),
+        ("ka-GE_modern", "ka-GE"),
+    ] {
+        let loc = Locale::from_windows_name(input).unwrap();
+        assert_eq!(loc.to_string(), *expected, "{}", input);
+    }
+
+    assert!(Locale::from_windows_name("de-DE_").is_err());
+    assert!(Locale::from_windows_name("de-DE_foobar").is_err());
+}
+
+#[test]
+fn test_to_windows_name() {
+    for (input, expected) in &[
+        ("en-US", "en-US"),
+        ("de-DE-u-co-phonebk", "de-DE_phoneb"),
+        ("zh-CN-u-co-stroke-nu-hanidec", "zh-CN_stroke"),
+        ("zh-CN-u-co-pinyin", "zh-CN"),
+        ("sr-Latn-RS-x-foo", "sr-Latn-RS"),
+    ] {
+        let loc: Locale = input.parse().unwrap();
+        assert_eq!(loc.to_windows_name(), *expected, "{}", input);
+    }
+
+    for input in &[
+        "de-DE_phoneb",
+        "zh-TW_pronun",
+        "zh-HK_radstr",
+        "zh-SG_stroke",
+        "es-ES_tradnl",
+    ] {
+        let loc = Locale::from_windows_name(input).unwrap();
+        assert_eq!(&loc.to_windows_name(), input);
+    }
+}
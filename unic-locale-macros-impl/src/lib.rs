@@ -4,8 +4,11 @@ use proc_macro::TokenStream;
 
 use proc_macro_hack::proc_macro_hack;
 use quote::quote;
-use syn::{parse_macro_input, LitStr};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, LitStr, Token};
 
+use unic_locale_impl::parser::{parse_locale_with_options, ParserOptions};
 use unic_locale_impl::Locale;
 
 #[proc_macro_hack]
@@ -56,3 +59,69 @@ pub fn locale(input: TokenStream) -> TokenStream {
         ) }
     })
 }
+
+struct Keyword {
+    key: LitStr,
+    value: LitStr,
+}
+
+impl Parse for Keyword {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let key = input.parse()?;
+        input.parse::<Token![=>]>()?;
+        let value = input.parse()?;
+        Ok(Keyword { key, value })
+    }
+}
+
+#[proc_macro_hack]
+pub fn unicode_ext(input: TokenStream) -> TokenStream {
+    let keywords =
+        parse_macro_input!(input with Punctuated::<Keyword, Token![,]>::parse_terminated);
+
+    let mut source = String::from("und");
+    if !keywords.is_empty() {
+        source.push_str("-u");
+    }
+    for keyword in &keywords {
+        source.push('-');
+        source.push_str(&keyword.key.value());
+        let value = keyword.value.value();
+        if !value.is_empty() {
+            source.push('-');
+            source.push_str(&value);
+        }
+    }
+    let options = ParserOptions::default().strict(true);
+    let parsed = parse_locale_with_options(&source, options)
+        .unwrap_or_else(|err| panic!("Malformed unicode extension keywords: {}", err));
+
+    // `ExtensionsMap` stores its keywords in a `BTreeMap`, which can't be built in a
+    // const context, so the keywords validated above are set at runtime, without
+    // parsing the whole extension again.
+    let unicode = &parsed.extensions.unicode;
+    let keywords: Vec<_> = unicode
+        .keyword_keys()
+        .map(|key| {
+            let values: Vec<&str> = unicode
+                .keyword(key)
+                .expect("Keys are well-formed.")
+                .collect();
+            quote! {
+                extensions
+                    .unicode
+                    .set_keyword(#key, &[#(#values,)*] as &[&str])
+                    .expect("Keywords were validated at build time.");
+            }
+        })
+        .collect();
+
+    TokenStream::from(quote! {
+        {
+            #[allow(unused_mut)]
+            let mut extensions = $crate::extensions::ExtensionsMap::default();
+            #(#keywords)*
+            extensions
+        }
+    })
+}
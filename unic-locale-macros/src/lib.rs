@@ -1,5 +1,6 @@
 use proc_macro_hack::proc_macro_hack;
 pub use tinystr::{TinyStr4, TinyStr8};
+pub use unic_locale_impl::extensions;
 pub use unic_locale_impl::subtags;
 pub use unic_locale_impl::Locale;
 
@@ -8,3 +9,8 @@ pub use unic_locale_impl::Locale;
 /// (Documentation goes here on the re-export, not in the other crate.)
 #[proc_macro_hack]
 pub use unic_locale_macros_impl::locale;
+
+/// Constructs an `ExtensionsMap` with the given unicode extension keywords,
+/// validated at build time.
+#[proc_macro_hack]
+pub use unic_locale_macros_impl::unicode_ext;
@@ -67,8 +67,33 @@
 //!
 //! Unlike `langid!` `locale!` can't be used for const variables.
 //!
+//! The `unicode_ext!` macro constructs an `ExtensionsMap` from unicode extension
+//! keywords, validating their keys and values against the values allowed for each
+//! key at build time:
+//!
+//! ``` ignore
+//! use unic_locale::unicode_ext;
+//!
+//! let extensions = unicode_ext!{"ca" => "buddhist", "nu" => "thai"};
+//!
+//! assert_eq!(extensions.to_string(), "-u-ca-buddhist-nu-thai");
+//! ```
+//!
+//! Like `locale!`, it can't be used for const variables: the keywords are validated
+//! at build time, but set on the map at runtime.
+//!
 //! The macros are optional to reduce the dependency chain and compilation time of `unic-locale`.
 //!
+//! ## Extensions
+//!
+//! The `extensions` feature is enabled by default. Without it, `Locale` keeps its API and
+//! parser entry points, but carries no unicode extensions and fails to parse any, which
+//! reduces the binary size for consumers who only need language identifier semantics.
+//!
+//! ```toml
+//! unic-locale = { version = "0.9", default-features = false }
+//! ```
+//!
 //! ## Likely Subtags
 //!
 //! If `feature = "likelysubtags"` is selected, the `Locale` gains two more methods:
@@ -97,12 +122,105 @@
 //! The feature is optional because it increases the binary size of the library by including
 //! a data table for CLDR likelySubtags.
 //!
+//! To reduce the size further, set the `UNIC_LANGID_LIKELYSUBTAGS_LANGUAGES` environment
+//! variable at build time to a comma separated list of languages (e.g. `en,fr,de`), and only
+//! the likelySubtags entries resolving to those languages will be included.
+//!
+//! ## serde
+//!
+//! If `feature = "serde"` is selected, `Locale`, `LanguageIdentifier` and `ExtensionsMap`
+//! implement `Serialize` and `Deserialize`, as their canonical BCP 47 string.
+//!
+//! ## serde_with
+//!
+//! If `feature = "serde_with"` is selected, the `serde_with` module provides adapters
+//! for `serde_with::serde_as`, serializing a `Locale` as a string and deserializing it
+//! either strictly or leniently.
+//!
+//! ## http
+//!
+//! If `feature = "http"` is selected, `AcceptLanguage` can be parsed from and serialized
+//! into an `http::HeaderValue`, and a `Locale` can be serialized into one.
+//!
+//! ## rayon
+//!
+//! If `feature = "rayon"` is selected, `accept_language::AvailableLocales::par_best`
+//! negotiates many `Accept-Language` header values in parallel.
+//!
+//! ## territoryinfo
+//!
+//! If `feature = "territoryinfo"` is selected, the `territory` module lists the languages
+//! spoken in a region, with their population share and official status, from CLDR
+//! `territoryInfo`.
+//!
+//! ## currencydata
+//!
+//! If `feature = "currencydata"` is selected, `Locale::resolve_currency` returns the
+//! `cu` keyword or the legal tender of the region, and the `currency` module maps
+//! regions to their currency, from CLDR `currencyData`.
+//!
+//! ## timezones
+//!
+//! If `feature = "timezones"` is selected, `Locale::resolve_time_zone` returns the
+//! primary time zone of the region, and the `timezone` module maps regions to it.
+//!
+//! ## iso639
+//!
+//! If `feature = "iso639"` is selected, `Locale::iso639_3` returns the ISO 639-3 code
+//! of the language, and `canonicalize` replaces three-letter language codes with their
+//! two-letter equivalents, such as `eng` with `en`.
+//!
+//! ## iso3166
+//!
+//! If `feature = "iso3166"` is selected, the `iso3166` module maps between ISO 3166-1
+//! alpha-2 and alpha-3 region codes, and `ParserOptions::lenient` accepts alpha-3
+//! region codes, such as `USA`, replacing them with the alpha-2 ones.
+//!
+//! ## aliases
+//!
+//! If `feature = "aliases"` is selected, `Locale::canonicalize_aliases` replaces deprecated
+//! subtags with their CLDR aliases, such as `iw` with `he` or `BU` with `MM`, and
+//! `canonicalize_with_mode` also replaces legacy tags, such as `i-klingon` with `tlh`.
+//!
+//! ## runtime_data
+//!
+//! If `feature = "runtime_data"` is selected, `provider::JsonData` loads likely subtags
+//! and aliases from cldr-json files at runtime, so the data can be updated without
+//! recompiling. It is used through `LanguageIdentifier::maximize_with_provider`, as in
+//! `locale.id.maximize_with_provider(&data)`.
+//!
+//! ## test_util
+//!
+//! If `feature = "test_util"` is selected, the `test_util` module provides assertions
+//! such as `assert_roundtrip` and `assert_canonical_idempotent`, and loaders for corpora
+//! of tags, to check the same invariants as this crate in other test suites.
+//!
+//! ## unstable
+//!
+//! If `feature = "unstable"` is selected, the extension lists expose their `tinystr`
+//! storage, such as `UnicodeExtensionList::raw_keywords`, for consumers avoiding
+//! conversions through `&str`. These methods have no stability guarantees and may
+//! change in any release.
+//!
+//! ## long_subtags
+//!
+//! If `feature = "long_subtags"` is selected, `ParserOptions::lenient` also accepts
+//! unicode extension values, transform extension values and private use subtags of
+//! up to 16 characters. Their storage widens from 8 to 16 bytes, so the feature is
+//! opt-in.
+//!
+//! ## character_direction
+//!
+//! The `character_direction` feature is enabled by default and provides
+//! `Locale::character_direction`, `Locale::is_rtl` and `Locale::is_ltr` along with
+//! their data. Disabling it removes both, for consumers that only parse and compare locales.
+//!
 //! [`UTS #35: Unicode LDML 3.1 Unicode Locale Identifier`]: https://unicode.org/reports/tr35/tr35.html#Unicode_locale_identifier
 //! [`Locale`]: ./struct.Locale.html
 pub use unic_locale_impl::*;
 
 #[cfg(feature = "unic-locale-macros")]
-pub use unic_locale_macros::locale;
+pub use unic_locale_macros::{locale, unicode_ext};
 
 #[cfg(feature = "unic-locale-macros")]
 #[macro_export]
@@ -1,6 +1,6 @@
 use unic_locale::Locale;
 #[cfg(feature = "unic-locale-macros")]
-use unic_locale::{locale, locales};
+use unic_locale::{locale, locales, unicode_ext};
 
 #[test]
 fn basic_test() {
@@ -23,7 +23,7 @@ fn locales_macro_test() {
     assert_eq!(locales.get(3).unwrap().id.language.as_str(), "pl");
     assert_eq!(
         locales
-            .get(0)
+            .first()
             .unwrap()
             .extensions
             .unicode
@@ -36,3 +36,17 @@ fn locales_macro_test() {
     // check trailing comma
     locales!["en-US-u-ca-buddhist", "pl",];
 }
+
+#[test]
+#[cfg(feature = "unic-locale-macros")]
+fn unicode_ext_macro_test() {
+    let extensions = unicode_ext! {"nu" => "thai", "ca" => "islamic-civil", "kn" => "true"};
+    assert_eq!(extensions.to_string(), "-u-ca-islamic-civil-kn-nu-thai");
+    assert_eq!(extensions, "u-ca-islamic-civil-kn-nu-thai".parse().unwrap());
+
+    let mut loc = locale!("en-US");
+    loc.extensions = unicode_ext! {"hc" => "h12",};
+    assert_eq!(loc.to_string(), "en-US-u-hc-h12");
+
+    assert!(unicode_ext! {}.is_empty());
+}